@@ -0,0 +1,179 @@
+use crate::cipher::stream::ctr::syncronous::CtrCipher;
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mem;
+use crate::traits::cipher::primitive::BlockCipherPrimitiveEncryption as PrimitiveEncryption;
+use crate::traits::cipher::stream::{
+    StreamCipherDecryption as StreamDecryption,
+    StreamCipherEncryption as StreamEncryption,
+};
+use alloc::{format, vec, vec::Vec};
+
+pub const CCM_BLOCKSIZE: usize = 16;
+pub const CCM_NONCELEN_MIN: usize = 7;
+pub const CCM_NONCELEN_MAX: usize = 13;
+pub const CCM_TAGLEN_MIN: usize = 4;
+pub const CCM_TAGLEN_MAX: usize = 16;
+
+/// CCM AEAD provider
+///
+/// Counter with CBC-MAC as specified in RFC 3610 and NIST SP 800-38C, for a Primitive T with a blocksize of 128 bit
+/// eg. Aes. The tag is a CBC-MAC over a formatted header block B_0, the length encoded associated data and the
+/// plaintext, encrypted with the first CTR keystream block. The nonce length N fixes the size of the length field
+/// to L = 15 - N bytes, which bounds the message length to 2^(8L) - 1 bytes.
+/// A nonce must never be used for more than one message under the same key.
+pub struct Ccm<T: PrimitiveEncryption<CCM_BLOCKSIZE> + Clone> {
+    primitive: T,
+    nonce_len: usize,
+    tag_len: usize,
+}
+
+impl<T: PrimitiveEncryption<CCM_BLOCKSIZE> + Clone> Ccm<T> {
+    /// Create a new instance from a Cipher primitive, the nonce length and the tag length in bytes
+    ///
+    /// Fails with `BlockCipherError::InvalidParameter` unless the nonce is 7 to 13 bytes long and the tag length
+    /// is even and between 4 and 16 bytes
+    pub fn new(primitive: T, nonce_len: usize, tag_len: usize) -> Result<Self, BlockCipherError> {
+        if !(CCM_NONCELEN_MIN..=CCM_NONCELEN_MAX).contains(&nonce_len) {
+            return Err(BlockCipherError::InvalidParameter(format!(
+                "nonce length must be between {} and {} bytes (found {})",
+                CCM_NONCELEN_MIN, CCM_NONCELEN_MAX, nonce_len
+            )));
+        }
+
+        if !(CCM_TAGLEN_MIN..=CCM_TAGLEN_MAX).contains(&tag_len) || tag_len % 2 != 0 {
+            return Err(BlockCipherError::InvalidParameter(format!(
+                "tag length must be even and between {} and {} bytes (found {})",
+                CCM_TAGLEN_MIN, CCM_TAGLEN_MAX, tag_len
+            )));
+        }
+
+        Ok(Self {
+            primitive,
+            nonce_len,
+            tag_len,
+        })
+    }
+
+    /// Encrypts plaintext and authenticates it together with the associated data.
+    /// Returns the ciphertext and the tag of the configured length.
+    ///
+    /// Fails with `BlockCipherError::InvalidIvLength` if the nonce does not have the configured length and
+    /// with `BlockCipherError::InvalidParameter` if the plaintext does not fit the length field
+    pub fn encrypt(&self, nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>), BlockCipherError> {
+        self.validate(nonce, plaintext.len())?;
+
+        let mut tag = self.cbc_mac(nonce, aad, plaintext);
+
+        let mut ctr = self.ctr(nonce);
+        ctr.encrypt(&mut tag);
+
+        let mut ciphertext = plaintext.to_vec();
+        ctr.encrypt(&mut ciphertext);
+
+        Ok((ciphertext, tag[..self.tag_len].to_vec()))
+    }
+
+    /// Decrypts the ciphertext and verifies the tag over the associated data and the plaintext.
+    /// Fails with `BlockCipherError::AuthenticationFailed` if the tag does not match, the plaintext is wiped.
+    pub fn decrypt(&self, nonce: &[u8], aad: &[u8], ciphertext: &[u8], tag: &[u8]) -> Result<Vec<u8>, BlockCipherError> {
+        self.validate(nonce, ciphertext.len())?;
+
+        if tag.len() != self.tag_len {
+            return Err(BlockCipherError::AuthenticationFailed);
+        }
+
+        let mut ctr = self.ctr(nonce);
+
+        let mut mask = [0; CCM_BLOCKSIZE];
+        ctr.encrypt(&mut mask);
+
+        let mut plaintext = ciphertext.to_vec();
+        ctr.decrypt(&mut plaintext);
+
+        let mut expected = self.cbc_mac(nonce, aad, &plaintext);
+        mem::xor_buffers(&mut expected, &mask);
+
+        if !mem::memeq_s(&expected[..self.tag_len], tag) {
+            mem::zeroize(&mut plaintext);
+            return Err(BlockCipherError::AuthenticationFailed);
+        }
+
+        Ok(plaintext)
+    }
+
+    fn validate(&self, nonce: &[u8], len: usize) -> Result<(), BlockCipherError> {
+        if nonce.len() != self.nonce_len {
+            return Err(BlockCipherError::InvalidIvLength {
+                got: nonce.len(),
+                expected: self.nonce_len,
+            });
+        }
+
+        // A length field of 8 bytes holds any usize
+        let l = CCM_BLOCKSIZE - 1 - self.nonce_len;
+        if l < 8 && (len as u64) >> (8 * l) != 0 {
+            return Err(BlockCipherError::InvalidParameter(format!(
+                "message of {} bytes is too long for a {} byte nonce",
+                len, self.nonce_len
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Counter block A_0 holding the flags, the nonce and a zero counter, its keystream block encrypts the tag
+    fn ctr(&self, nonce: &[u8]) -> CtrCipher<CCM_BLOCKSIZE, T> {
+        let l = CCM_BLOCKSIZE - 1 - self.nonce_len;
+
+        let mut a0 = [0; CCM_BLOCKSIZE];
+        a0[0] = (l - 1) as u8;
+        a0[1..=self.nonce_len].copy_from_slice(nonce);
+
+        CtrCipher::new(self.primitive.clone(), a0)
+    }
+
+    /// CBC-MAC over B_0, the length encoded associated data and the plaintext, each zero padded to a full block
+    fn cbc_mac(&self, nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> [u8; CCM_BLOCKSIZE] {
+        let l = CCM_BLOCKSIZE - 1 - self.nonce_len;
+
+        let mut state = [0; CCM_BLOCKSIZE];
+        let adata = if aad.is_empty() { 0 } else { 0x40 };
+        state[0] = adata | (((self.tag_len - 2) / 2) << 3) as u8 | (l - 1) as u8;
+        state[1..=self.nonce_len].copy_from_slice(nonce);
+        state[CCM_BLOCKSIZE - l..].copy_from_slice(&(plaintext.len() as u64).to_be_bytes()[8 - l..]);
+        self.primitive.encrypt(&mut state);
+
+        if !aad.is_empty() {
+            let mut encoded = encode_aad_len(aad.len());
+            encoded.extend_from_slice(aad);
+            self.absorb(&mut state, &encoded);
+        }
+
+        self.absorb(&mut state, plaintext);
+        state
+    }
+
+    fn absorb(&self, state: &mut [u8; CCM_BLOCKSIZE], data: &[u8]) {
+        for chunk in data.chunks(CCM_BLOCKSIZE) {
+            mem::xor_buffers(&mut state[..chunk.len()], chunk);
+            self.primitive.encrypt(state);
+        }
+    }
+}
+
+/// Length of the associated data in 2 bytes below 2^16 - 2^8, otherwise in 4 or 8 bytes after a marker
+fn encode_aad_len(len: usize) -> Vec<u8> {
+    let len = len as u64;
+
+    if len < 0xFF00 {
+        (len as u16).to_be_bytes().to_vec()
+    } else if len <= u32::MAX as u64 {
+        let mut encoded = vec![0xFF, 0xFE];
+        encoded.extend_from_slice(&(len as u32).to_be_bytes());
+        encoded
+    } else {
+        let mut encoded = vec![0xFF, 0xFF];
+        encoded.extend_from_slice(&len.to_be_bytes());
+        encoded
+    }
+}
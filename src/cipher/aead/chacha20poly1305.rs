@@ -0,0 +1,232 @@
+use super::{bind_length, bound_aad, Aead};
+use crate::cipher::stream::chacha20::{ChaCha20, CHACHA20_KEYLEN, CHACHA20_NONCELEN};
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mac::poly1305::{Poly1305, POLY1305_KEYLEN, POLY1305_TAGLEN};
+use crate::mem;
+use crate::traits::cipher::sink::ByteSink;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io;
+use core::iter::FromIterator;
+use crate::traits::cipher::stream::{
+    StreamCipherDecryption as StreamDecryption,
+    StreamCipherEncryption as StreamEncryption,
+};
+
+/// ChaCha20-Poly1305 AEAD
+///
+/// Authenticated encryption with associated data as specified in RFC 8439.
+/// A nonce must never be reused with the same key.
+pub struct ChaCha20Poly1305 {
+    key: [u8; CHACHA20_KEYLEN],
+    bind_length: bool,
+}
+
+impl ChaCha20Poly1305 {
+    /// Create a new instance from a 32 byte key
+    pub fn new(key: &[u8; CHACHA20_KEYLEN]) -> Self {
+        Self { key: *key, bind_length: false }
+    }
+
+    /// Authenticates the associated data bound to the ciphertext length, see [`bind_length`](super::bind_length)
+    pub fn with_bind_length(mut self, bind_length: bool) -> Self {
+        self.bind_length = bind_length;
+        self
+    }
+
+    /// Starts a streaming decryption, the ciphertext is fed via io::Write and verified on `finalize`
+    ///
+    /// Note: No plaintext is released before the tag has been verified, so the whole plaintext is
+    /// buffered in memory until `finalize` is called. With the length bound into the associated data, the
+    /// associated data can only be authenticated once the ciphertext is complete, so the ciphertext is buffered
+    /// instead and decrypted on `finalize`.
+    pub fn streaming_decryption(&self, nonce: &[u8; CHACHA20_NONCELEN], aad: &[u8]) -> ChaCha20Poly1305Decryption {
+        let (cipher, mut mac) = self.setup(nonce);
+
+        let deferred = if self.bind_length {
+            Some(Deferred {
+                aad: aad.to_vec(),
+                ciphertext: Vec::new(),
+            })
+        } else {
+            authenticate_padded(&mut mac, aad);
+            None
+        };
+
+        ChaCha20Poly1305Decryption {
+            cipher,
+            mac,
+            aad_len: aad.len(),
+            plaintext: Vec::new(),
+            deferred,
+        }
+    }
+
+    /// Derives the one-time Poly1305 key from keystream block 0, the cipher continues with block 1
+    fn setup(&self, nonce: &[u8; CHACHA20_NONCELEN]) -> (ChaCha20, Poly1305) {
+        let mut cipher = ChaCha20::new(&self.key, nonce, 0);
+
+        let mut block = cipher.next_block();
+        let mut poly_key = [0; POLY1305_KEYLEN];
+        poly_key.copy_from_slice(&block[..POLY1305_KEYLEN]);
+
+        let mac = Poly1305::new(&poly_key);
+
+        mem::zeroize(&mut block);
+        mem::zeroize(&mut poly_key);
+
+        (cipher, mac)
+    }
+}
+
+impl Aead for ChaCha20Poly1305 {
+    const NONCE_LEN: usize = CHACHA20_NONCELEN;
+    const TAG_LEN: usize = POLY1305_TAGLEN;
+
+    type Nonce = [u8; CHACHA20_NONCELEN];
+    type Tag = [u8; POLY1305_TAGLEN];
+
+    fn encrypt(&self, nonce: &Self::Nonce, aad: &[u8], plaintext: &[u8]) -> (Vec<u8>, Self::Tag) {
+        let (mut cipher, mac) = self.setup(nonce);
+
+        let mut ciphertext = plaintext.to_vec();
+        cipher.encrypt(&mut ciphertext);
+
+        let tag = compute_tag(mac, &bound_aad(aad, ciphertext.len(), self.bind_length), &ciphertext);
+        (ciphertext, tag)
+    }
+
+    fn decrypt(
+        &self,
+        nonce: &Self::Nonce,
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &Self::Tag,
+    ) -> Result<Vec<u8>, BlockCipherError> {
+        let (mut cipher, mac) = self.setup(nonce);
+
+        let aad = bound_aad(aad, ciphertext.len(), self.bind_length);
+
+        if !mem::memeq_s(&compute_tag(mac, &aad, ciphertext), tag) {
+            return Err(BlockCipherError::AuthenticationFailed);
+        }
+
+        let mut plaintext = ciphertext.to_vec();
+        cipher.decrypt(&mut plaintext);
+        Ok(plaintext)
+    }
+}
+
+impl Drop for ChaCha20Poly1305 {
+    fn drop(&mut self) {
+        mem::zeroize(&mut self.key);
+    }
+}
+
+/// Authenticates aad || pad16 || ciphertext || pad16 || len(aad) || len(ciphertext)
+fn compute_tag(mut mac: Poly1305, aad: &[u8], ciphertext: &[u8]) -> [u8; POLY1305_TAGLEN] {
+    authenticate_padded(&mut mac, aad);
+    mac.update(ciphertext);
+    finalize_tag(mac, aad.len(), ciphertext.len())
+}
+
+/// Authenticates data followed by zero padding up to a multiple of 16 bytes
+fn authenticate_padded(mac: &mut Poly1305, data: &[u8]) {
+    mac.update(data);
+    mac.update(&[0; 16][..padding_len(data.len())]);
+}
+
+/// Pads the ciphertext, authenticates both lengths and returns the tag
+fn finalize_tag(mut mac: Poly1305, aad_len: usize, ciphertext_len: usize) -> [u8; POLY1305_TAGLEN] {
+    mac.update(&[0; 16][..padding_len(ciphertext_len)]);
+    mac.update(&(aad_len as u64).to_le_bytes());
+    mac.update(&(ciphertext_len as u64).to_le_bytes());
+
+    mac.finalize()
+}
+
+fn padding_len(len: usize) -> usize {
+    (16 - len % 16) % 16
+}
+
+/// Streaming ChaCha20-Poly1305 decryption
+///
+/// Ciphertext is written in arbitrary chunks, the tag is only checked on `finalize`.
+///
+/// Note: Unauthenticated plaintext must never be used, therefore all decrypted data is held back
+/// in memory and only returned by `finalize` after the tag has been verified successfully.
+/// Memory usage grows with the length of the ciphertext.
+pub struct ChaCha20Poly1305Decryption {
+    cipher: ChaCha20,
+    mac: Poly1305,
+    aad_len: usize,
+    plaintext: Vec<u8>,
+    deferred: Option<Deferred>,
+}
+
+/// Associated data and ciphertext held back until `finalize`, when the ciphertext length is bound into the former
+struct Deferred {
+    aad: Vec<u8>,
+    ciphertext: Vec<u8>,
+}
+
+impl ChaCha20Poly1305Decryption {
+    /// Verifies the tag and returns the plaintext.
+    /// Fails with `BlockCipherError::AuthenticationFailed` if the tag does not match, the buffered plaintext is wiped.
+    pub fn finalize<I>(mut self, tag: &[u8; POLY1305_TAGLEN]) -> Result<I, BlockCipherError>
+    where
+        I: FromIterator<u8>,
+    {
+        if let Some(Deferred { aad, ciphertext }) = self.deferred.take() {
+            let expected = compute_tag(self.mac, &bind_length(&aad, ciphertext.len()), &ciphertext);
+
+            if !mem::memeq_s(&expected, tag) {
+                return Err(BlockCipherError::AuthenticationFailed);
+            }
+
+            let mut plaintext = ciphertext;
+            self.cipher.decrypt(&mut plaintext);
+            return Ok(plaintext.into_iter().collect());
+        }
+
+        let plaintext = core::mem::take(&mut self.plaintext);
+        let expected = finalize_tag(self.mac, self.aad_len, plaintext.len());
+
+        if !mem::memeq_s(&expected, tag) {
+            let mut plaintext = plaintext;
+            mem::zeroize(&mut plaintext);
+            return Err(BlockCipherError::AuthenticationFailed);
+        }
+
+        Ok(plaintext.into_iter().collect())
+    }
+}
+
+impl ByteSink for ChaCha20Poly1305Decryption {
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BlockCipherError> {
+        if let Some(deferred) = &mut self.deferred {
+            deferred.ciphertext.extend_from_slice(data);
+            return Ok(());
+        }
+
+        self.mac.update(data);
+
+        let start = self.plaintext.len();
+        self.plaintext.extend_from_slice(data);
+        self.cipher.decrypt(&mut self.plaintext[start..]);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl io::Write for ChaCha20Poly1305Decryption {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_bytes(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
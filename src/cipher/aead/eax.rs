@@ -0,0 +1,117 @@
+use super::{bound_aad, Aead};
+use crate::cipher::stream::ctr::syncronous::CtrCipher;
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mac::cmac::Cmac;
+use crate::mem;
+use crate::traits::cipher::primitive::BlockCipherPrimitiveEncryption as PrimitiveEncryption;
+use crate::traits::cipher::stream::{
+    StreamCipherDecryption as StreamDecryption,
+    StreamCipherEncryption as StreamEncryption,
+};
+use alloc::vec::Vec;
+
+pub const EAX_NONCELEN: usize = 16;
+pub const EAX_TAGLEN: usize = 16;
+
+// Tweaks separating the OMAC of the nonce, the header and the ciphertext
+const TWEAK_NONCE: u8 = 0;
+const TWEAK_HEADER: u8 = 1;
+const TWEAK_CIPHERTEXT: u8 = 2;
+
+/// EAX AEAD provider
+///
+/// Two pass authenticated encryption with associated data by Bellare, Rogaway and Wagner, built from CTR mode
+/// and three tweaked CMACs (OMAC) over the nonce, the header and the ciphertext with a Primitive T eg. Aes.
+/// The nonce may be of any length, but must never be used for more than one message under the same key.
+pub struct Eax<T: PrimitiveEncryption<BLOCKSIZE> + Clone, const BLOCKSIZE: usize> {
+    primitive: T,
+    bind_length: bool,
+}
+
+impl<T: PrimitiveEncryption<B> + Clone, const B: usize> Eax<T, B> {
+    /// Create a new instance from a Cipher primitive
+    pub fn new(primitive: T) -> Self {
+        Self { primitive, bind_length: false }
+    }
+
+    /// Authenticates the header bound to the ciphertext length, see [`bind_length`](super::bind_length)
+    pub fn with_bind_length(mut self, bind_length: bool) -> Self {
+        self.bind_length = bind_length;
+        self
+    }
+
+    /// Encrypts plaintext and authenticates it together with the nonce and header.
+    /// Returns the ciphertext and the tag.
+    pub fn encrypt_with_nonce(&self, nonce: &[u8], header: &[u8], plaintext: &[u8]) -> (Vec<u8>, [u8; B]) {
+        let n = self.omac(TWEAK_NONCE, nonce);
+
+        let mut ciphertext = plaintext.to_vec();
+        CtrCipher::new(self.primitive.clone(), n).encrypt(&mut ciphertext);
+
+        let tag = self.tag(&n, header, &ciphertext);
+        (ciphertext, tag)
+    }
+
+    /// Verifies the tag over the nonce, header and ciphertext, then decrypts the ciphertext.
+    /// Fails with `BlockCipherError::AuthenticationFailed` without decrypting anything if the tag does not match.
+    pub fn decrypt_with_nonce(
+        &self,
+        nonce: &[u8],
+        header: &[u8],
+        ciphertext: &[u8],
+        tag: &[u8; B],
+    ) -> Result<Vec<u8>, BlockCipherError> {
+        let n = self.omac(TWEAK_NONCE, nonce);
+
+        if !mem::memeq_s(&self.tag(&n, header, ciphertext), tag) {
+            return Err(BlockCipherError::AuthenticationFailed);
+        }
+
+        let mut plaintext = ciphertext.to_vec();
+        CtrCipher::new(self.primitive.clone(), n).decrypt(&mut plaintext);
+
+        Ok(plaintext)
+    }
+
+    /// CMAC over a block holding the tweak in its last byte, followed by data
+    fn omac(&self, tweak: u8, data: &[u8]) -> [u8; B] {
+        let mut prefix = [0; B];
+        prefix[B - 1] = tweak;
+
+        let mut mac = Cmac::new(self.primitive.clone());
+        mac.update(&prefix);
+        mac.update(data);
+        mac.finalize()
+    }
+
+    fn tag(&self, n: &[u8; B], header: &[u8], ciphertext: &[u8]) -> [u8; B] {
+        let header = bound_aad(header, ciphertext.len(), self.bind_length);
+
+        let mut tag = *n;
+        mem::xor_buffers(&mut tag, &self.omac(TWEAK_HEADER, &header));
+        mem::xor_buffers(&mut tag, &self.omac(TWEAK_CIPHERTEXT, ciphertext));
+        tag
+    }
+}
+
+impl<T: PrimitiveEncryption<EAX_NONCELEN> + Clone> Aead for Eax<T, EAX_NONCELEN> {
+    const NONCE_LEN: usize = EAX_NONCELEN;
+    const TAG_LEN: usize = EAX_TAGLEN;
+
+    type Nonce = [u8; EAX_NONCELEN];
+    type Tag = [u8; EAX_TAGLEN];
+
+    fn encrypt(&self, nonce: &Self::Nonce, aad: &[u8], plaintext: &[u8]) -> (Vec<u8>, Self::Tag) {
+        self.encrypt_with_nonce(nonce, aad, plaintext)
+    }
+
+    fn decrypt(
+        &self,
+        nonce: &Self::Nonce,
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &Self::Tag,
+    ) -> Result<Vec<u8>, BlockCipherError> {
+        self.decrypt_with_nonce(nonce, aad, ciphertext, tag)
+    }
+}
@@ -0,0 +1,57 @@
+pub mod ccm;
+pub mod chacha20poly1305;
+pub mod eax;
+pub mod siv;
+
+use crate::errors::blockcipher::BlockCipherError;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+/// Authenticated encryption with associated data
+pub trait Aead {
+    const NONCE_LEN: usize;
+    const TAG_LEN: usize;
+
+    type Nonce: AsRef<[u8]> + AsMut<[u8]> + Default + Copy;
+    type Tag: AsRef<[u8]> + AsMut<[u8]> + Default + Copy;
+
+    /// Encrypts plaintext and authenticates it together with the associated data.
+    /// Returns the ciphertext and the tag.
+    fn encrypt(&self, nonce: &Self::Nonce, aad: &[u8], plaintext: &[u8]) -> (Vec<u8>, Self::Tag);
+
+    /// Verifies the tag over the associated data and ciphertext, then decrypts the ciphertext.
+    /// Fails with `BlockCipherError::AuthenticationFailed` without decrypting anything if the tag does not match.
+    fn decrypt(
+        &self,
+        nonce: &Self::Nonce,
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &Self::Tag,
+    ) -> Result<Vec<u8>, BlockCipherError>;
+}
+
+/// Appends the length of the ciphertext to the associated data, encoded as 64 bit big endian value
+///
+/// Authenticating the returned bytes in place of the plain associated data makes the tag depend on the exact
+/// length of the message. A receiver that checks the tag can then not be handed a truncated or extended
+/// ciphertext which still verifies, even if the underlying construction would accept it, and the border between
+/// associated data and ciphertext can not be shifted without invalidating the tag.
+///
+/// `ChaCha20Poly1305`, `Eax` and `AesSiv` apply it to their associated data when configured `with_bind_length(true)`.
+/// The resulting tags differ from those of the plain constructions, so both sides have to agree on the option.
+/// `Ccm` has no such option, its first block B_0 already encodes the length of the message.
+pub fn bind_length(aad: &[u8], ciphertext_len: usize) -> Vec<u8> {
+    let mut bound = Vec::with_capacity(aad.len() + 8);
+    bound.extend_from_slice(aad);
+    bound.extend_from_slice(&(ciphertext_len as u64).to_be_bytes());
+    bound
+}
+
+/// Returns the associated data to authenticate, bound to the ciphertext length if requested
+pub(crate) fn bound_aad(aad: &[u8], ciphertext_len: usize, bind: bool) -> Cow<'_, [u8]> {
+    if bind {
+        Cow::Owned(bind_length(aad, ciphertext_len))
+    } else {
+        Cow::Borrowed(aad)
+    }
+}
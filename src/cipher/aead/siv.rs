@@ -0,0 +1,154 @@
+use super::{bound_aad, Aead};
+use crate::cipher::block::primitive::aes::{Aes, AES_128_KEYLEN, AES_192_KEYLEN, AES_256_KEYLEN, AES_BLOCKSIZE};
+use crate::cipher::stream::ctr::syncronous::CtrCipher;
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mac::cmac::Cmac;
+use crate::mem;
+use crate::traits::cipher::stream::{
+    StreamCipherDecryption as StreamDecryption,
+    StreamCipherEncryption as StreamEncryption,
+};
+use crate::util::gf128::gf128_double;
+use alloc::vec::Vec;
+
+pub const AES_SIV_NONCELEN: usize = 16;
+pub const AES_SIV_TAGLEN: usize = AES_BLOCKSIZE;
+
+/// AES-SIV deterministic and nonce misuse resistant AEAD
+///
+/// Authenticated encryption with associated data as specified in RFC 5297. The synthetic IV is derived from all
+/// associated data and the plaintext with the CMAC based S2V, it is both the tag and the initial counter block
+/// for encryption in CTR mode. Reusing a nonce only reveals whether the same message was encrypted twice.
+pub struct AesSiv {
+    mac_key: Aes,
+    ctr_key: Aes,
+    bind_length: bool,
+}
+
+impl AesSiv {
+    /// Create a new instance from a 32, 48 or 64 byte key, the first half keys S2V and the second half CTR mode
+    ///
+    /// Fails with `BlockCipherError::InvalidKeyLength` for any other key length
+    pub fn new(key: &[u8]) -> Result<Self, BlockCipherError> {
+        if ![2 * AES_128_KEYLEN, 2 * AES_192_KEYLEN, 2 * AES_256_KEYLEN].contains(&key.len()) {
+            return Err(BlockCipherError::InvalidKeyLength {
+                got: key.len(),
+                min: 2 * AES_128_KEYLEN,
+                max: 2 * AES_256_KEYLEN,
+            });
+        }
+
+        let (mac_key, ctr_key) = key.split_at(key.len() / 2);
+
+        Ok(Self {
+            mac_key: Aes::new(mac_key)?,
+            ctr_key: Aes::new(ctr_key)?,
+            bind_length: false,
+        })
+    }
+
+    /// Binds the ciphertext length into the associated data given to `Aead::encrypt` and `Aead::decrypt`,
+    /// see [`bind_length`](super::bind_length). The component based methods authenticate their components as given.
+    pub fn with_bind_length(mut self, bind_length: bool) -> Self {
+        self.bind_length = bind_length;
+        self
+    }
+
+    /// Encrypts plaintext and authenticates it together with a vector of associated data components.
+    /// Without any components or a nonce among them, encryption is deterministic.
+    /// Returns the ciphertext and the synthetic IV.
+    pub fn encrypt_with_components(&self, components: &[&[u8]], plaintext: &[u8]) -> (Vec<u8>, [u8; AES_SIV_TAGLEN]) {
+        let siv = self.s2v(components, plaintext);
+
+        let mut ciphertext = plaintext.to_vec();
+        self.ctr(&siv).encrypt(&mut ciphertext);
+
+        (ciphertext, siv)
+    }
+
+    /// Decrypts the ciphertext and verifies the synthetic IV over the associated data components and the plaintext.
+    /// Fails with `BlockCipherError::AuthenticationFailed` if the synthetic IV does not match, the plaintext is wiped.
+    pub fn decrypt_with_components(
+        &self,
+        components: &[&[u8]],
+        ciphertext: &[u8],
+        siv: &[u8; AES_SIV_TAGLEN],
+    ) -> Result<Vec<u8>, BlockCipherError> {
+        let mut plaintext = ciphertext.to_vec();
+        self.ctr(siv).decrypt(&mut plaintext);
+
+        if !mem::memeq_s(&self.s2v(components, &plaintext), siv) {
+            mem::zeroize(&mut plaintext);
+            return Err(BlockCipherError::AuthenticationFailed);
+        }
+
+        Ok(plaintext)
+    }
+
+    fn cmac(&self, data: &[u8]) -> [u8; AES_BLOCKSIZE] {
+        let mut mac = Cmac::new(self.mac_key.clone());
+        mac.update(data);
+        mac.finalize()
+    }
+
+    /// Chains the CMAC of every component by doubling, the plaintext is mixed into the final block
+    fn s2v(&self, components: &[&[u8]], plaintext: &[u8]) -> [u8; AES_BLOCKSIZE] {
+        let mut d = self.cmac(&[0; AES_BLOCKSIZE]);
+
+        for component in components {
+            gf128_double(&mut d);
+            mem::xor_buffers(&mut d, &self.cmac(component));
+        }
+
+        if plaintext.len() >= AES_BLOCKSIZE {
+            // XOR d into the last block of the plaintext
+            let mut t = plaintext.to_vec();
+            let start = t.len() - AES_BLOCKSIZE;
+            mem::xor_buffers(&mut t[start..], &d);
+            self.cmac(&t)
+        } else {
+            // Pad with a single one bit followed by zeros
+            let mut t = [0; AES_BLOCKSIZE];
+            t[..plaintext.len()].copy_from_slice(plaintext);
+            t[plaintext.len()] = 0x80;
+
+            gf128_double(&mut d);
+            mem::xor_buffers(&mut t, &d);
+            self.cmac(&t)
+        }
+    }
+
+    /// Clears the 31st and 63rd bit from the right of the synthetic IV for use as the initial counter block
+    fn ctr(&self, siv: &[u8; AES_SIV_TAGLEN]) -> CtrCipher<AES_BLOCKSIZE, Aes> {
+        let mut q = *siv;
+        q[8] &= 0x7F;
+        q[12] &= 0x7F;
+
+        CtrCipher::new(self.ctr_key.clone(), q)
+    }
+}
+
+impl Aead for AesSiv {
+    const NONCE_LEN: usize = AES_SIV_NONCELEN;
+    const TAG_LEN: usize = AES_SIV_TAGLEN;
+
+    type Nonce = [u8; AES_SIV_NONCELEN];
+    type Tag = [u8; AES_SIV_TAGLEN];
+
+    /// Authenticates the associated data followed by the nonce as the last component, as in RFC 5297 section 3
+    fn encrypt(&self, nonce: &Self::Nonce, aad: &[u8], plaintext: &[u8]) -> (Vec<u8>, Self::Tag) {
+        let aad = bound_aad(aad, plaintext.len(), self.bind_length);
+        self.encrypt_with_components(&[&aad, nonce], plaintext)
+    }
+
+    fn decrypt(
+        &self,
+        nonce: &Self::Nonce,
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &Self::Tag,
+    ) -> Result<Vec<u8>, BlockCipherError> {
+        let aad = bound_aad(aad, ciphertext.len(), self.bind_length);
+        self.decrypt_with_components(&[&aad, nonce], ciphertext, tag)
+    }
+}
@@ -1,7 +1,21 @@
-use crate::traits::cipher::block::{BlockCipherDecryption, BlockCipherEncryption};
+use super::reader::{Finish, PullCipher};
+use crate::errors::blockcipher::{BlockCipherError, PaddingErrorKind};
+#[cfg(feature = "zeroize")]
+use crate::mem;
+use crate::traits::cipher::{
+    block::{BlockCipherDecryption, BlockCipherEncryption, StreamMode},
+    finalize::{BlockCipherResult, Finalizable},
+    primitive::BlockCipherRuntimeInfo as RuntimeInfo,
+    sink::ByteSink,
+};
 use crate::util::buffer::ArrayBuffer;
+#[cfg(feature = "std")]
+use crate::util::readable::Readable;
+use alloc::{boxed::Box, vec::Vec};
+use core::convert::TryInto;
+#[cfg(feature = "std")]
 use std::io;
-use std::iter::FromIterator;
+use core::iter::FromIterator;
 
 pub trait BufferedCipherEncryptionProvider<const BLOCKSIZE: usize> {
     type Cipher: BlockCipherEncryption<BLOCKSIZE>;
@@ -27,11 +41,16 @@ impl<const B: usize, T: BlockCipherDecryption<B>> BufferedCipherDecryptionProvid
     }
 }
 
+/// Progress callback receiving the cumulative number of blocks processed.
+/// It is `Send` and `Sync`, so registering one keeps the buffered ciphers `Send` and `Sync`.
+type BlockCallback = Box<dyn FnMut(u64) + Send + Sync>;
 
 pub struct BufferedCipherEncryption<const BLOCKSIZE: usize, T: BlockCipherEncryption<BLOCKSIZE>> {
     cipher: T,
     buffer: ArrayBuffer<u8, BLOCKSIZE>,
     out: Vec<u8>,
+    blocks: u64,
+    on_block: Option<BlockCallback>,
 }
 
 impl<const B: usize, T: BlockCipherEncryption<B>> BufferedCipherEncryption<B, T> {
@@ -40,21 +59,114 @@ impl<const B: usize, T: BlockCipherEncryption<B>> BufferedCipherEncryption<B, T>
             cipher,
             buffer: ArrayBuffer::new(),
             out: Vec::new(),
+            blocks: 0,
+            on_block: None,
         }
     }
 
     pub fn missing(&self) -> Option<usize> {
         if !self.buffer.is_full() && !self.buffer.is_empty() {
-            return Some(self.buffer.capacity());
+            return Some(self.buffer.remaining());
         }
 
         None
     }
 
-    fn process_buffer(&mut self) {
-        let mut buf = self.buffer.extract();
-        self.cipher.encrypt(&mut buf);
-        self.out.extend(buf)
+    /// Processes the full buffer, which is only cleared if processing succeeds
+    fn process_buffer(&mut self) -> Result<(), BlockCipherError> {
+        let block = self.buffer.as_slice().try_into().expect("buffer holds one block");
+        self.process_block(block)?;
+        clear_buffer(&mut self.buffer);
+        Ok(())
+    }
+
+    fn process_block(&mut self, mut block: [u8; B]) -> Result<(), BlockCipherError> {
+        self.cipher.try_encrypt(&mut block)?;
+        self.out.extend(block);
+        self.count_block();
+        Ok(())
+    }
+
+    /// Buffers data and processes every completed block, returns the number of bytes consumed.
+    /// A block that fails to process is not consumed, the buffer keeps the bytes of previous writes.
+    fn consume(&mut self, data: &[u8]) -> (usize, Result<(), BlockCipherError>) {
+        let mut consumed = 0;
+
+        while data.len() - consumed >= self.buffer.remaining() {
+            let (filled, take) = (self.buffer.len(), self.buffer.remaining());
+
+            let mut block = [0; B];
+            block[..filled].copy_from_slice(self.buffer.filled());
+            block[filled..].copy_from_slice(&data[consumed..consumed + take]);
+
+            if let Err(err) = self.process_block(block) {
+                return (consumed, Err(err));
+            }
+
+            clear_buffer(&mut self.buffer);
+            consumed += take;
+        }
+
+        consumed += self.buffer.push_slice(&data[consumed..]);
+        (consumed, Ok(()))
+    }
+
+    fn count_block(&mut self) {
+        self.blocks += 1;
+
+        if let Some(f) = self.on_block.as_mut() {
+            f(self.blocks);
+        }
+    }
+
+    /// Registers a callback invoked with the cumulative number of blocks processed after every full block,
+    /// eg. for progress reporting. Finalizing only invokes it if a final block is processed, as with padding.
+    /// Replaces any previously registered callback.
+    pub fn on_block(&mut self, f: impl FnMut(u64) + Send + Sync + 'static) {
+        self.on_block = Some(Box::new(f));
+    }
+
+    /// Returns the number of full blocks processed since creation or the last reset
+    pub fn blocks_processed(&self) -> u64 {
+        self.blocks
+    }
+
+    /// Returns the number of bytes waiting in the buffer for the block to be completed
+    pub fn bytes_buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns and clears the encrypted contents so far without finalizing, eg. to flush them periodically.
+    /// The chaining state of the mode and any buffered bytes of an incomplete block are kept,
+    /// `finalize` returns what was processed after the last drain.
+    pub fn drain(&mut self) -> Vec<u8> {
+        core::mem::take(&mut self.out)
+    }
+
+    /// Encrypts block aligned data directly, skipping the byte buffer used by io::Write.
+    /// The output is appended to the contents returned by `finalize`.
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` if data is not a multiple of the blocksize
+    /// or an incomplete block is already buffered, in which case nothing is encrypted.
+    pub fn encrypt_blocks(&mut self, data: &[u8]) -> Result<(), BlockCipherError> {
+        if let Some(missing) = self.missing() {
+            return Err(BlockCipherError::IncompleteBlock(missing));
+        }
+
+        if data.len() % B != 0 {
+            return Err(BlockCipherError::IncompleteBlock(B - data.len() % B));
+        }
+
+        self.out.reserve(data.len());
+
+        for chunk in data.chunks_exact(B) {
+            let mut block: [u8; B] = chunk.try_into().expect("chunk is exactly one block");
+            self.cipher.try_encrypt(&mut block)?;
+            self.out.extend(block);
+            self.count_block();
+        }
+
+        Ok(())
     }
 
     pub fn finalize<I>(self) -> I
@@ -64,28 +176,113 @@ impl<const B: usize, T: BlockCipherEncryption<B>> BufferedCipherEncryption<B, T>
         self.out.into_iter().collect()
     }
 
+    /// Consumes the cipher and appends the encrypted contents to out, without collecting into a new container
+    pub fn finalize_into(mut self, out: &mut Vec<u8>) {
+        out.append(&mut self.out);
+    }
+
+    /// Consumes the cipher, copies the encrypted contents to the start of out and returns their length.
+    ///
+    /// Fails with `BlockCipherError::OutputTooSmall` if out can not hold the contents, in which case out is untouched
+    pub fn finalize_into_slice(self, out: &mut [u8]) -> Result<usize, BlockCipherError> {
+        copy_into_slice(&self.out, out)
+    }
+
+    /// Encrypts the buffered bytes of an incomplete final block as they are and returns the encrypted contents,
+    /// for stream-like modes such as CTR, CFB or OFB where the output has the length of the input.
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` if the cipher does not support a partial block
+    pub fn finalize_partial<I>(mut self) -> Result<I, BlockCipherError>
+    where
+        I: FromIterator<u8>,
+    {
+        if !self.buffer.is_empty() {
+            let mut partial = self.buffer.filled().to_vec();
+            self.cipher.encrypt_partial(&mut partial)?;
+            self.out.extend(partial);
+        }
+
+        Ok(self.out.into_iter().collect())
+    }
+
+    /// Processes the buffered bytes of an incomplete final block and returns the encrypted contents,
+    /// which have the length of the input. Only available for stream-like modes, see `StreamMode`.
+    pub fn finalize_stream<I>(self) -> I
+    where
+        I: FromIterator<u8>,
+        T: StreamMode,
+    {
+        self.finalize_partial().expect("stream modes process partial blocks")
+    }
+
+    /// Applies PKCS#7 padding to the buffered bytes, encrypts the final block and returns the encrypted contents.
+    /// A full block of padding is added if the input is block aligned.
+    pub fn finalize_padded<I>(mut self) -> Result<I, BlockCipherError>
+    where
+        I: FromIterator<u8>,
+    {
+        let pad = (B - self.bytes_buffered()) as u8;
+        while self.buffer.push(pad) {}
+        self.process_buffer()?;
+
+        Ok(self.out.into_iter().collect())
+    }
+
+    /// Returns the processed contents and resets the cipher for reuse, discarding any buffered bytes.
+    /// With the `zeroize` feature the internal buffers are wiped, the returned data is the caller's responsibility.
     pub fn finalize_and_reset<I>(&mut self) -> I
     where
         I: FromIterator<u8>
     {
-        self.buffer = ArrayBuffer::new();
-        std::mem::replace(&mut self.out, Vec::new()).into_iter().collect()
+        clear_buffer(&mut self.buffer);
+        self.blocks = 0;
+        take_output(&mut self.out)
     }
 }
 
-impl<const B: usize, T: BlockCipherEncryption<B>> io::Write for BufferedCipherEncryption<B, T> {
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let mut written = 0;
+impl<const B: usize, T: BlockCipherEncryption<B>> Finalizable for BufferedCipherEncryption<B, T> {
+    fn finalize_readable(self) -> BlockCipherResult {
+        if let Some(missing) = self.missing() {
+            return Err(BlockCipherError::IncompleteBlock(missing));
+        }
+
+        Ok(self.finalize())
+    }
+}
 
-        while written != buf.len() {
-            written += self.buffer.push_slice(&buf[written..]);
+impl<const B: usize, T: BlockCipherEncryption<B>> ByteSink for BufferedCipherEncryption<B, T> {
+    /// On failure the data is consumed up to the block that failed to process, see `io::Write::write`
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BlockCipherError> {
+        self.consume(data).1
+    }
+}
 
-            if self.buffer.is_full() {
-                self.process_buffer()
-            }
+impl<const B: usize, T: BlockCipherEncryption<B>> PullCipher for BufferedCipherEncryption<B, T> {
+    fn take_processed(&mut self, _: Finish) -> Vec<u8> {
+        self.drain()
+    }
+
+    fn finish(self, finish: Finish) -> Result<Vec<u8>, BlockCipherError> {
+        match finish {
+            Finish::Exact => match self.missing() {
+                Some(missing) => Err(BlockCipherError::IncompleteBlock(missing)),
+                None => Ok(self.finalize()),
+            },
+            Finish::Partial => self.finalize_partial(),
+            Finish::Padded => self.finalize_padded(),
         }
+    }
+}
 
-        Ok(written)
+#[cfg(feature = "std")]
+impl<const B: usize, T: BlockCipherEncryption<B>> io::Write for BufferedCipherEncryption<B, T> {
+    /// Consumes buf up to a block that fails to process. The error is only returned if nothing was consumed,
+    /// so the bytes of the failed block can be written again.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.consume(buf) {
+            (0, Err(err)) => Err(err.into()),
+            (consumed, _) => Ok(consumed),
+        }
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -93,10 +290,22 @@ impl<const B: usize, T: BlockCipherEncryption<B>> io::Write for BufferedCipherEn
     }
 }
 
+impl<const B: usize, T: BlockCipherEncryption<B> + RuntimeInfo> RuntimeInfo for BufferedCipherEncryption<B, T> {
+    fn block_size(&self) -> usize {
+        self.cipher.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.cipher.key_bounds()
+    }
+}
+
 pub struct BufferedCipherDecryption<const BLOCKSIZE: usize, T: BlockCipherDecryption<BLOCKSIZE>> {
     cipher: T,
     buffer: ArrayBuffer<u8, BLOCKSIZE>,
     out: Vec<u8>,
+    blocks: u64,
+    on_block: Option<BlockCallback>,
 }
 
 impl<const B: usize, T: BlockCipherDecryption<B>> BufferedCipherDecryption<B, T> {
@@ -105,55 +314,457 @@ impl<const B: usize, T: BlockCipherDecryption<B>> BufferedCipherDecryption<B, T>
             cipher,
             buffer: ArrayBuffer::new(),
             out: Vec::new(),
+            blocks: 0,
+            on_block: None,
         }
     }
 
     pub fn missing(&self) -> Option<usize> {
         if !self.buffer.is_full() && !self.buffer.is_empty() {
-            return Some(self.buffer.capacity());
+            return Some(self.buffer.remaining());
         }
 
         None
     }
 
-    fn process_buffer(&mut self) {
-        let mut buf = self.buffer.extract();
-        self.cipher.decrypt(&mut buf);
-        self.out.extend(buf)
+    /// Processes the full buffer, which is only cleared if processing succeeds
+    #[cfg(feature = "std")]
+    fn process_buffer(&mut self) -> Result<(), BlockCipherError> {
+        let block = self.buffer.as_slice().try_into().expect("buffer holds one block");
+        self.process_block(block)?;
+        clear_buffer(&mut self.buffer);
+        Ok(())
     }
 
-    pub fn finalize<I>(self) -> I
+    fn process_block(&mut self, mut block: [u8; B]) -> Result<(), BlockCipherError> {
+        self.cipher.try_decrypt(&mut block)?;
+        self.out.extend(block);
+        self.count_block();
+        Ok(())
+    }
+
+    /// Buffers data and processes every completed block, returns the number of bytes consumed.
+    /// A block that fails to process is not consumed, the buffer keeps the bytes of previous writes.
+    fn consume(&mut self, data: &[u8]) -> (usize, Result<(), BlockCipherError>) {
+        let mut consumed = 0;
+
+        while data.len() - consumed >= self.buffer.remaining() {
+            let (filled, take) = (self.buffer.len(), self.buffer.remaining());
+
+            let mut block = [0; B];
+            block[..filled].copy_from_slice(self.buffer.filled());
+            block[filled..].copy_from_slice(&data[consumed..consumed + take]);
+
+            if let Err(err) = self.process_block(block) {
+                return (consumed, Err(err));
+            }
+
+            clear_buffer(&mut self.buffer);
+            consumed += take;
+        }
+
+        consumed += self.buffer.push_slice(&data[consumed..]);
+        (consumed, Ok(()))
+    }
+
+    fn count_block(&mut self) {
+        self.blocks += 1;
+
+        if let Some(f) = self.on_block.as_mut() {
+            f(self.blocks);
+        }
+    }
+
+    /// Registers a callback invoked with the cumulative number of blocks processed after every full block,
+    /// eg. for progress reporting. Finalizing only invokes it if a final block is processed, as with padding.
+    /// Replaces any previously registered callback.
+    pub fn on_block(&mut self, f: impl FnMut(u64) + Send + Sync + 'static) {
+        self.on_block = Some(Box::new(f));
+    }
+
+    /// Returns the number of full blocks processed since creation or the last reset
+    pub fn blocks_processed(&self) -> u64 {
+        self.blocks
+    }
+
+    /// Returns the number of bytes waiting in the buffer for the block to be completed
+    pub fn bytes_buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns and clears the decrypted contents so far without finalizing, see `BufferedCipherEncryption::drain`.
+    ///
+    /// Note: `finalize_unpadded` needs the final block, which must not have been drained
+    pub fn drain(&mut self) -> Vec<u8> {
+        core::mem::take(&mut self.out)
+    }
+
+    /// Decrypts block aligned data directly, skipping the byte buffer used by io::Write.
+    /// The output is appended to the contents returned by `finalize`.
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` if data is not a multiple of the blocksize
+    /// or an incomplete block is already buffered, in which case nothing is decrypted.
+    pub fn decrypt_blocks(&mut self, data: &[u8]) -> Result<(), BlockCipherError> {
+        if let Some(missing) = self.missing() {
+            return Err(BlockCipherError::IncompleteBlock(missing));
+        }
+
+        if data.len() % B != 0 {
+            return Err(BlockCipherError::IncompleteBlock(B - data.len() % B));
+        }
+
+        self.out.reserve(data.len());
+
+        for chunk in data.chunks_exact(B) {
+            let mut block: [u8; B] = chunk.try_into().expect("chunk is exactly one block");
+            self.cipher.try_decrypt(&mut block)?;
+            self.out.extend(block);
+            self.count_block();
+        }
+
+        Ok(())
+    }
+
+    /// Decrypts the block aligned part of buf in place and returns its length, avoiding the internal output buffer.
+    /// A trailing incomplete block is buffered, so it is reported by `finalize` like with io::Write.
+    ///
+    /// Since decrypted blocks can only be written back to where their ciphertext was, the input has to stay
+    /// block aligned: Fails with `BlockCipherError::IncompleteBlock` if an incomplete block is already buffered.
+    /// Output of in place decryption is not part of the contents returned by `finalize`.
+    ///
+    /// Like `write`, a block that fails to decrypt is left untouched: Only the length of the blocks decrypted
+    /// before it is returned and the error is reported once no block could be decrypted at all.
+    pub fn decrypt_in_place(&mut self, buf: &mut [u8]) -> Result<usize, BlockCipherError> {
+        if let Some(missing) = self.missing() {
+            return Err(BlockCipherError::IncompleteBlock(missing));
+        }
+
+        let aligned = buf.len() - buf.len() % B;
+        let (blocks, tail) = buf.split_at_mut(aligned);
+
+        for (i, chunk) in blocks.chunks_exact_mut(B).enumerate() {
+            let mut block: [u8; B] = (&*chunk).try_into().expect("chunk is exactly one block");
+
+            match self.cipher.try_decrypt(&mut block) {
+                Ok(()) => chunk.copy_from_slice(&block),
+                Err(e) if i == 0 => return Err(e),
+                Err(_) => return Ok(i * B),
+            }
+
+            self.count_block();
+        }
+
+        self.buffer.push_slice(tail);
+        Ok(aligned)
+    }
+
+    /// Consumes the cipher and returns the decrypted contents.
+    /// Fails if the ciphertext ended with an incomplete block.
+    pub fn finalize<I>(self) -> Result<I, BlockCipherError>
+    where
+        I: FromIterator<u8>,
+    {
+        if let Some(missing) = self.missing() {
+            return Err(BlockCipherError::IncompleteBlock(missing));
+        }
+
+        Ok(self.out.into_iter().collect())
+    }
+
+    /// Consumes the cipher and appends the decrypted contents to out, without collecting into a new container.
+    /// Fails if the ciphertext ended with an incomplete block, in which case out is untouched.
+    pub fn finalize_into(mut self, out: &mut Vec<u8>) -> Result<(), BlockCipherError> {
+        if let Some(missing) = self.missing() {
+            return Err(BlockCipherError::IncompleteBlock(missing));
+        }
+
+        out.append(&mut self.out);
+        Ok(())
+    }
+
+    /// Consumes the cipher, copies the decrypted contents to the start of out and returns their length.
+    ///
+    /// Fails if the ciphertext ended with an incomplete block, or with `BlockCipherError::OutputTooSmall`
+    /// if out can not hold the contents. In both cases out is untouched.
+    pub fn finalize_into_slice(self, out: &mut [u8]) -> Result<usize, BlockCipherError> {
+        if let Some(missing) = self.missing() {
+            return Err(BlockCipherError::IncompleteBlock(missing));
+        }
+
+        copy_into_slice(&self.out, out)
+    }
+
+    /// Consumes the cipher and returns the decrypted contents with their PKCS#7 padding removed.
+    ///
+    /// Fails with `BlockCipherError::BadPadding` of kind `Length` if the ciphertext was empty or ended with an
+    /// incomplete block, which points at truncation, and of kind `Content` if the final block does not end
+    /// in valid padding, which points at a corrupted or tampered ciphertext or the wrong key.
+    pub fn finalize_unpadded<I>(self) -> Result<I, BlockCipherError>
+    where
+        I: FromIterator<u8>,
+    {
+        if self.missing().is_some() || self.out.is_empty() {
+            return Err(BlockCipherError::BadPadding { kind: PaddingErrorKind::Length });
+        }
+
+        let pad = match pkcs7_padding_len(&self.out[self.out.len() - B..]) {
+            Some(pad) => pad,
+            None => return Err(BlockCipherError::BadPadding { kind: PaddingErrorKind::Content }),
+        };
+
+        let len = self.out.len() - pad;
+        Ok(self.out.into_iter().take(len).collect())
+    }
+
+    /// Decrypts the buffered bytes of an incomplete final block as they are and returns the decrypted contents,
+    /// see `BufferedCipherEncryption::finalize_partial`
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` if the cipher does not support a partial block
+    pub fn finalize_partial<I>(mut self) -> Result<I, BlockCipherError>
+    where
+        I: FromIterator<u8>,
+    {
+        if !self.buffer.is_empty() {
+            let mut partial = self.buffer.filled().to_vec();
+            self.cipher.decrypt_partial(&mut partial)?;
+            self.out.extend(partial);
+        }
+
+        Ok(self.out.into_iter().collect())
+    }
+
+    /// Processes the buffered bytes of an incomplete final block and returns the decrypted contents,
+    /// which have the length of the input. Only available for stream-like modes, see `StreamMode`.
+    pub fn finalize_stream<I>(self) -> I
+    where
+        I: FromIterator<u8>,
+        T: StreamMode,
+    {
+        self.finalize_partial().expect("stream modes process partial blocks")
+    }
+
+    /// Consumes the cipher, ignoring any buffered bytes of an incomplete block and returns the decrypted contents
+    pub fn finalize_ignore_partial<I>(self) -> I
     where
         I: FromIterator<u8>,
     {
         self.out.into_iter().collect()
     }
 
+    /// Returns the processed contents and resets the cipher for reuse, discarding any buffered bytes.
+    /// With the `zeroize` feature the internal buffers are wiped, the returned data is the caller's responsibility.
     pub fn finalize_and_reset<I>(&mut self) -> I
     where
         I: FromIterator<u8>
     {
-        self.buffer = ArrayBuffer::new();
-        std::mem::replace(&mut self.out, Vec::new()).into_iter().collect()
+        clear_buffer(&mut self.buffer);
+        self.blocks = 0;
+        take_output(&mut self.out)
     }
 }
 
-impl<const B: usize, T: BlockCipherDecryption<B>> io::Write for BufferedCipherDecryption<B, T> {
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let mut written = 0;
+impl<const B: usize, T: BlockCipherDecryption<B>> Finalizable for BufferedCipherDecryption<B, T> {
+    fn finalize_readable(self) -> BlockCipherResult {
+        self.finalize()
+    }
+}
+
+impl<const B: usize, T: BlockCipherDecryption<B>> ByteSink for BufferedCipherDecryption<B, T> {
+    /// On failure the data is consumed up to the block that failed to process, see `io::Write::write`
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BlockCipherError> {
+        self.consume(data).1
+    }
+}
 
-        while written != buf.len() {
-            written += self.buffer.push_slice(&buf[written..]);
+impl<const B: usize, T: BlockCipherDecryption<B>> PullCipher for BufferedCipherDecryption<B, T> {
+    fn take_processed(&mut self, finish: Finish) -> Vec<u8> {
+        // The last block holds the padding, which can only be removed once the ciphertext ended
+        let keep = match finish {
+            Finish::Padded => self.out.len().min(B),
+            _ => 0,
+        };
 
-            if self.buffer.is_full() {
-                self.process_buffer()
-            }
+        let kept = self.out.split_off(self.out.len() - keep);
+        core::mem::replace(&mut self.out, kept)
+    }
+
+    fn finish(self, finish: Finish) -> Result<Vec<u8>, BlockCipherError> {
+        match finish {
+            Finish::Exact => self.finalize(),
+            Finish::Partial => self.finalize_partial(),
+            Finish::Padded => self.finalize_unpadded(),
         }
+    }
+}
 
-        Ok(written)
+#[cfg(feature = "std")]
+impl<const B: usize, T: BlockCipherDecryption<B>> io::Write for BufferedCipherDecryption<B, T> {
+    /// Consumes buf up to a block that fails to process. The error is only returned if nothing was consumed,
+    /// so the bytes of the failed block can be written again.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.consume(buf) {
+            (0, Err(err)) => Err(err.into()),
+            (consumed, _) => Ok(consumed),
+        }
     }
 
     fn flush(&mut self) -> io::Result<()> {
         Ok(())
     }
 }
+
+impl<const B: usize, T: BlockCipherDecryption<B> + RuntimeInfo> RuntimeInfo for BufferedCipherDecryption<B, T> {
+    fn block_size(&self) -> usize {
+        self.cipher.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.cipher.key_bounds()
+    }
+}
+
+/// Discards the buffered bytes, wiping them first with the `zeroize` feature
+fn clear_buffer<const B: usize>(buffer: &mut ArrayBuffer<u8, B>) {
+    #[cfg(feature = "zeroize")]
+    mem::zeroize(buffer.as_slice_mut());
+
+    *buffer = ArrayBuffer::new();
+}
+
+/// Hands out the processed output. With the `zeroize` feature the allocation is wiped and kept for reuse,
+/// otherwise it is handed over and the cipher starts with a new one.
+fn take_output<I: FromIterator<u8>>(out: &mut Vec<u8>) -> I {
+    #[cfg(feature = "zeroize")]
+    {
+        let taken = out.iter().copied().collect();
+        mem::zeroize(out);
+        out.clear();
+        taken
+    }
+
+    #[cfg(not(feature = "zeroize"))]
+    core::mem::take(out).into_iter().collect()
+}
+
+/// Pumps everything r yields block by block through a mode provider and returns the encrypted contents.
+///
+/// Fails with the error of the reader, or with `BlockCipherError::IncompleteBlock` as io::Error
+/// if the data read is not a multiple of the blocksize
+#[cfg(feature = "std")]
+pub fn encrypt_reader<R: io::Read, C: BlockCipherEncryption<B>, const B: usize>(
+    cipher: C,
+    mut r: R,
+) -> io::Result<Readable<Vec<u8>>> {
+    let mut cipher = BufferedCipherEncryption::new(cipher);
+
+    loop {
+        cipher.buffer.fill_from(&mut r)?;
+
+        if !cipher.buffer.is_full() {
+            break;
+        }
+
+        cipher.process_buffer()?;
+    }
+
+    Ok(cipher.finalize_readable()?)
+}
+
+/// Pumps everything r yields block by block through a mode provider and returns the decrypted contents.
+///
+/// Fails with the error of the reader, or with `BlockCipherError::IncompleteBlock` as io::Error
+/// if the data read is not a multiple of the blocksize
+#[cfg(feature = "std")]
+pub fn decrypt_reader<R: io::Read, C: BlockCipherDecryption<B>, const B: usize>(
+    cipher: C,
+    mut r: R,
+) -> io::Result<Readable<Vec<u8>>> {
+    let mut cipher = BufferedCipherDecryption::new(cipher);
+
+    loop {
+        cipher.buffer.fill_from(&mut r)?;
+
+        if !cipher.buffer.is_full() {
+            break;
+        }
+
+        cipher.process_buffer()?;
+    }
+
+    Ok(cipher.finalize_readable()?)
+}
+
+/// Returns the length of the PKCS#7 padding at the end of the final block, or None if it is invalid.
+/// The differences of all bytes of the block are accumulated without an early exit,
+/// so the time taken does not depend on where the padding is broken.
+fn pkcs7_padding_len(block: &[u8]) -> Option<usize> {
+    let pad = *block.last()? as u32;
+
+    // Set if the padding length is zero or longer than the block
+    let mut diff = (pad.wrapping_sub(1) >> 31) | ((block.len() as u32).wrapping_sub(pad) >> 31);
+
+    for (i, &byte) in block.iter().rev().enumerate() {
+        // All ones for the bytes covered by the padding
+        let mask = 0u32.wrapping_sub((i as u32).wrapping_sub(pad) >> 31);
+        diff |= (byte as u32 ^ pad) & mask;
+    }
+
+    if diff == 0 {
+        Some(pad as usize)
+    } else {
+        None
+    }
+}
+
+fn copy_into_slice(contents: &[u8], out: &mut [u8]) -> Result<usize, BlockCipherError> {
+    if out.len() < contents.len() {
+        return Err(BlockCipherError::OutputTooSmall { got: out.len(), expected: contents.len() });
+    }
+
+    out[..contents.len()].copy_from_slice(contents);
+    Ok(contents.len())
+}
+
+#[cfg(all(test, feature = "zeroize"))]
+mod tests {
+
+    use super::*;
+    use crate::cipher::block::ecb::{EcbDecryptionProvider, EcbEncryptionProvider};
+    use crate::cipher::block::primitive::aes::Aes;
+    use std::io::Write;
+
+    // The partial block of plaintext left in the buffer is wiped
+    #[test]
+    fn test_clear_buffer_wipes_partial_block() {
+        let mut enc = Aes::new(&[0; 16]).unwrap().with_ecb_encryption().buffered();
+        enc.write_all(&[0xAA; 21]).unwrap();
+        assert_eq!([0xAA; 5], enc.buffer.filled());
+
+        clear_buffer(&mut enc.buffer);
+
+        assert!(enc.buffer.is_empty());
+        assert!(enc.buffer.as_slice().iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_finalize_and_reset_clears_internal_state() {
+        let aes = Aes::new(&[0; 16]).unwrap();
+
+        let mut enc = aes.clone().with_ecb_encryption().buffered();
+        enc.write_all(&[0xAA; 21]).unwrap();
+        let ciphertext: Vec<u8> = enc.finalize_and_reset();
+
+        assert_eq!(16, ciphertext.len());
+        assert_eq!(0, enc.bytes_buffered());
+        assert!(enc.out.is_empty() && enc.out.capacity() != 0);
+
+        let mut dec = aes.with_ecb_decryption().buffered();
+        dec.write_all(&ciphertext).unwrap();
+        dec.write_all(&[0xBB; 5]).unwrap();
+        let plaintext: Vec<u8> = dec.finalize_and_reset();
+
+        assert_eq!(vec![0xAA; 16], plaintext);
+        assert!(dec.out.is_empty());
+        assert_eq!(0, dec.bytes_buffered());
+    }
+}
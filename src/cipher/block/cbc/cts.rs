@@ -0,0 +1,154 @@
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mem;
+use crate::traits::cipher::primitive::{
+    BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+    BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+    BlockCipherRuntimeInfo as RuntimeInfo
+};
+
+/// CBC-CTS Encryption Provider
+///
+/// CBC with ciphertext stealing in the CS3 variant of the NIST SP 800-38A addendum, as used by Kerberos (RFC 3962).
+/// A message of any length of at least one block is encrypted without padding, the ciphertext has the same length.
+/// The last two ciphertext blocks are always swapped and the final one is truncated to the length of the last
+/// plaintext block. A message of exactly one block is plain CBC.
+pub struct CbcCtsEncryption<T: PrimitiveEncryption<BLOCKSIZE>, const BLOCKSIZE: usize> {
+    primitive: T,
+    iv: [u8; BLOCKSIZE],
+}
+
+impl<T: PrimitiveEncryption<B>, const B: usize> CbcCtsEncryption<T, B> {
+    /// Create a new CBC-CTS Encryption instance from a primitive and an IV.
+    pub fn new(primitive: T, iv: [u8; B]) -> Self {
+        Self { primitive, iv }
+    }
+
+    /// Encrypts a whole message in place, every message starts from the IV
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` if the message is shorter than a single block
+    pub fn encrypt(&self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        let (regular, last) = split_message::<B>(data.len())?;
+        let mut chain = self.iv;
+
+        for block in data[..regular * B].chunks_exact_mut(B) {
+            mem::xor_buffers(&mut chain, block);
+            self.primitive.encrypt(&mut chain);
+            block.copy_from_slice(&chain);
+        }
+
+        if last != 0 {
+            // The zero padded last block is chained to the previous ciphertext block and takes its place,
+            // the previous ciphertext block is truncated to replace the last block
+            let (head, tail) = data[(regular - 1) * B..].split_at_mut(B);
+
+            let mut padded = [0; B];
+            padded[..last].copy_from_slice(tail);
+            mem::xor_buffers(&mut padded, head);
+            self.primitive.encrypt(&mut padded);
+
+            tail.copy_from_slice(&head[..last]);
+            head.copy_from_slice(&padded);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: PrimitiveEncryption<B>, const B: usize> RuntimeInfo for CbcCtsEncryption<T, B> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
+}
+
+/// CBC-CTS Decryption Provider
+///
+/// Counterpart to `CbcCtsEncryption`.
+pub struct CbcCtsDecryption<T: PrimitiveDecryption<BLOCKSIZE>, const BLOCKSIZE: usize> {
+    primitive: T,
+    iv: [u8; BLOCKSIZE],
+}
+
+impl<T: PrimitiveDecryption<B>, const B: usize> CbcCtsDecryption<T, B> {
+    /// Create a new CBC-CTS Decryption instance from a primitive and an IV.
+    pub fn new(primitive: T, iv: [u8; B]) -> Self {
+        Self { primitive, iv }
+    }
+
+    /// Decrypts a whole message in place, every message starts from the IV
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` if the message is shorter than a single block
+    pub fn decrypt(&self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        let (regular, last) = split_message::<B>(data.len())?;
+        let mut chain = self.iv;
+
+        // With ciphertext stealing the second to last block has to be recovered from the last one first
+        let regular = if last != 0 { regular - 1 } else { regular };
+
+        for block in data[..regular * B].chunks_exact_mut(B) {
+            let mut buf = [0; B];
+            buf.copy_from_slice(block);
+            let next = buf;
+
+            self.primitive.decrypt(&mut buf);
+            mem::xor_buffers(&mut buf, &chain);
+            block.copy_from_slice(&buf);
+
+            chain = next;
+        }
+
+        if last != 0 {
+            let (head, tail) = data[regular * B..].split_at_mut(B);
+
+            // Decrypting the swapped block gives the padded last block XOR the previous ciphertext block,
+            // whose stolen tail completes the truncated ciphertext
+            let mut stolen = [0; B];
+            stolen.copy_from_slice(head);
+            self.primitive.decrypt(&mut stolen);
+
+            let mut previous = [0; B];
+            previous[..last].copy_from_slice(tail);
+            previous[last..].copy_from_slice(&stolen[last..]);
+            mem::xor_buffers(&mut stolen[..last], &previous[..last]);
+            tail.copy_from_slice(&stolen[..last]);
+
+            self.primitive.decrypt(&mut previous);
+            mem::xor_buffers(&mut previous, &chain);
+            head.copy_from_slice(&previous);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: PrimitiveDecryption<B>, const B: usize> RuntimeInfo for CbcCtsDecryption<T, B> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
+}
+
+/// Returns the number of leading blocks processed as plain CBC and the length of the stolen last block.
+/// CS3 swaps the last two blocks even if the message is block aligned, only a single block is left alone.
+fn split_message<const B: usize>(len: usize) -> Result<(usize, usize), BlockCipherError> {
+    if len < B {
+        return Err(BlockCipherError::IncompleteBlock(B - len));
+    }
+
+    if len == B {
+        return Ok((1, 0));
+    }
+
+    let last = match len % B {
+        0 => B,
+        partial => partial,
+    };
+
+    Ok(((len - last) / B, last))
+}
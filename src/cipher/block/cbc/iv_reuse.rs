@@ -0,0 +1,93 @@
+//! Detection of key and IV pairs that are used for more than one CBC encryption
+//!
+//! Once enabled with `enable`, every `CbcEncryption` created on the thread records a fingerprint of its key and IV
+//! in a thread local set. The key is identified by the encryption of the zero block, so it never has to be stored.
+//! Recording is off by default: the set grows with every encryption and each fingerprint costs a block encryption.
+//! A repeated pair sets a flag that can be queried with `reuse_detected`, or panics if enabled with `panic_on_reuse`.
+//! This is a developer safety aid only: in release builds nothing is recorded and no reuse is ever reported.
+
+use crate::traits::cipher::primitive::BlockCipherPrimitiveEncryption as PrimitiveEncryption;
+
+#[cfg(debug_assertions)]
+use std::{
+    cell::{Cell, RefCell},
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::Hasher,
+};
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+    static SEEN: RefCell<HashSet<u64>> = RefCell::new(HashSet::new());
+    static DETECTED: Cell<bool> = const { Cell::new(false) };
+    static PANIC: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Start or stop recording key and IV pairs on the current thread, pairs recorded so far are kept until `reset`
+pub fn enable(enabled: bool) {
+    #[cfg(debug_assertions)]
+    ENABLED.with(|flag| flag.set(enabled));
+
+    #[cfg(not(debug_assertions))]
+    let _ = enabled;
+}
+
+/// Panic on the current thread as soon as a key and IV pair is used twice, instead of only setting the flag
+pub fn panic_on_reuse(enabled: bool) {
+    #[cfg(debug_assertions)]
+    PANIC.with(|panic| panic.set(enabled));
+
+    #[cfg(not(debug_assertions))]
+    let _ = enabled;
+}
+
+/// Returns true if a key and IV pair has been used twice on the current thread since the last `reset`
+pub fn reuse_detected() -> bool {
+    #[cfg(debug_assertions)]
+    return DETECTED.with(|detected| detected.get());
+
+    #[cfg(not(debug_assertions))]
+    false
+}
+
+/// Forgets all recorded pairs of the current thread and clears the flag
+pub fn reset() {
+    #[cfg(debug_assertions)]
+    {
+        SEEN.with(|seen| seen.borrow_mut().clear());
+        DETECTED.with(|detected| detected.set(false));
+    }
+}
+
+/// Records the key of the primitive together with the IV, if recording is enabled on the current thread
+///
+/// Panics if the pair was seen before and `panic_on_reuse` is enabled
+pub(crate) fn record<T: PrimitiveEncryption<B>, const B: usize>(primitive: &T, iv: &[u8; B]) {
+    #[cfg(debug_assertions)]
+    {
+        if !ENABLED.with(|flag| flag.get()) {
+            return;
+        }
+
+        // A backend that fails to fingerprint the key fails again once the mode is used
+        let mut fingerprint = [0; B];
+        if primitive.try_encrypt_block(&mut fingerprint).is_err() {
+            return;
+        }
+
+        let mut hasher = DefaultHasher::new();
+        hasher.write(&fingerprint);
+        hasher.write(iv);
+
+        if !SEEN.with(|seen| seen.borrow_mut().insert(hasher.finish())) {
+            DETECTED.with(|detected| detected.set(true));
+
+            if PANIC.with(|panic| panic.get()) {
+                panic!("cbc key and iv pair used for more than one encryption");
+            }
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    let _ = (primitive, iv);
+}
@@ -1,7 +1,13 @@
+pub mod cts;
+#[cfg(feature = "std")]
+pub mod iv_reuse;
 pub mod syncronous;
+#[cfg(feature = "std")]
 pub mod threaded;
 
+use cts::*;
 use syncronous::*;
+#[cfg(feature = "std")]
 use threaded::*;
 use crate::traits::cipher::primitive::{
     BlockCipherPrimitiveDecryption as PrimitiveDecryption,
@@ -32,6 +38,51 @@ impl<T: PrimitiveDecryption<B>, const B: usize> CbcDecryptionProvider<B> for T {
     }
 }
 
+pub trait CbcCtsEncryptionProvider<const BLOCKSIZE: usize> {
+    type Primitive: PrimitiveEncryption<BLOCKSIZE>;
+    fn with_cbc_cts_encryption(self, iv: [u8; BLOCKSIZE]) -> CbcCtsEncryption<Self::Primitive, BLOCKSIZE>;
+}
+
+impl<T: PrimitiveEncryption<B>, const B: usize> CbcCtsEncryptionProvider<B> for T {
+    type Primitive = Self;
+    fn with_cbc_cts_encryption(self, iv: [u8; B]) -> CbcCtsEncryption<Self::Primitive, B> {
+        CbcCtsEncryption::new(self, iv)
+    }
+}
+
+pub trait CbcCtsDecryptionProvider<const BLOCKSIZE: usize> {
+    type Primitive: PrimitiveDecryption<BLOCKSIZE>;
+    fn with_cbc_cts_decryption(self, iv: [u8; BLOCKSIZE]) -> CbcCtsDecryption<Self::Primitive, BLOCKSIZE>;
+}
+
+impl<T: PrimitiveDecryption<B>, const B: usize> CbcCtsDecryptionProvider<B> for T {
+    type Primitive = Self;
+    fn with_cbc_cts_decryption(self, iv: [u8; B]) -> CbcCtsDecryption<Self::Primitive, B> {
+        CbcCtsDecryption::new(self, iv)
+    }
+}
+
+#[cfg(feature = "std")]
+pub trait ThreadedCbcEncryptionProvider<const BLOCKSIZE: usize> {
+    type Primitive: PrimitiveEncryption<BLOCKSIZE> + Send + 'static;
+    fn with_threaded_cbc_encryption(
+        self,
+        iv: [u8; BLOCKSIZE],
+    ) -> ThreadedCbcEncryption<Self::Primitive, BLOCKSIZE>;
+}
+
+#[cfg(feature = "std")]
+impl<T, const B: usize> ThreadedCbcEncryptionProvider<B> for T
+where
+    T: PrimitiveEncryption<B> + Send + 'static,
+{
+    type Primitive = Self;
+    fn with_threaded_cbc_encryption(self, iv: [u8; B]) -> ThreadedCbcEncryption<Self::Primitive, B> {
+        ThreadedCbcEncryption::new(self, iv)
+    }
+}
+
+#[cfg(feature = "std")]
 pub trait ThreadedCbcDecryptionProvider<const BLOCKSIZE: usize> {
     fn with_threaded_cbc_decryption(
         self,
@@ -40,6 +91,7 @@ pub trait ThreadedCbcDecryptionProvider<const BLOCKSIZE: usize> {
     ) -> ThreadedCbcDecryption<BLOCKSIZE>;
 }
 
+#[cfg(feature = "std")]
 impl<T, const B: usize> ThreadedCbcDecryptionProvider<B> for T
 where
     T: PrimitiveDecryption<B> + Send + Sync + 'static,
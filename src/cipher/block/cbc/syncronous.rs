@@ -1,4 +1,9 @@
+#[cfg(feature = "std")]
+use super::iv_reuse;
+use crate::errors::blockcipher::BlockCipherError;
 use crate::mem;
+#[cfg(feature = "std")]
+use crate::util::random::{self, XorShift64};
 use crate::traits::cipher::{
     block::{
         BlockCipherDecryption,
@@ -6,9 +11,12 @@ use crate::traits::cipher::{
     },
     primitive::{
         BlockCipherPrimitiveDecryption as PrimitiveDecryption,
-        BlockCipherPrimitiveEncryption as PrimitiveEncryption
+        BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+        BlockCipherRuntimeInfo as RuntimeInfo
     }
 };
+#[cfg(feature = "std")]
+use std::io;
 
 /// CBC Encryption Provider
 pub struct CbcEncryption<T: PrimitiveEncryption<BLOCKSIZE>, const BLOCKSIZE: usize> {
@@ -18,10 +26,61 @@ pub struct CbcEncryption<T: PrimitiveEncryption<BLOCKSIZE>, const BLOCKSIZE: usi
 
 impl<T: PrimitiveEncryption<B>, const B: usize> CbcEncryption<T, B> {
     /// Create a new CBC Encryption instance from a primitive and an IV.
-    /// Up to the primitives blocksize of IV contents will be used.
+    /// In debug builds the key and IV pair can be checked for reuse, see `iv_reuse`.
     pub fn new(primitive: T, iv: [u8; B]) -> Self {
+        #[cfg(feature = "std")]
+        iv_reuse::record(&primitive, &iv);
+
         Self { primitive, iv }
     }
+
+    /// Create a new CBC Encryption instance from a primitive and an IV slice.
+    /// Fails if the IV is not exactly one block long.
+    pub fn try_new(primitive: T, iv: &[u8]) -> Result<Self, BlockCipherError> {
+        Ok(Self::new(primitive, iv_from_slice(iv)?))
+    }
+
+    /// Create a new CBC Encryption instance from a primitive and an IV slice.
+    /// Up to the primitives blocksize of IV contents will be used, a shorter IV is padded with zeros.
+    ///
+    /// Note: A wrong IV length is almost always a bug, prefer `try_new`
+    pub fn new_truncating(primitive: T, iv: &[u8]) -> Self {
+        Self::new(primitive, iv_truncating(iv))
+    }
+
+    /// Resets the chaining state to a new IV, keeping the primitive and its key schedule.
+    /// Fails if the IV is not exactly one block long.
+    pub fn reset_iv(&mut self, iv: &[u8]) -> Result<(), BlockCipherError> {
+        self.iv = iv_from_slice(iv)?;
+        #[cfg(feature = "std")]
+        iv_reuse::record(&self.primitive, &self.iv);
+
+        Ok(())
+    }
+
+    /// Create a new CBC Encryption instance with a random IV from the operating system's entropy source.
+    /// Returns the instance and the generated IV, which has to be transmitted alongside the ciphertext.
+    ///
+    /// Fails if the entropy source is not available
+    #[cfg(feature = "std")]
+    pub fn with_random_iv(primitive: T) -> io::Result<(Self, [u8; B])> {
+        let mut iv = [0; B];
+        random::fill_random(&mut iv)?;
+
+        Ok((Self::new(primitive, iv), iv))
+    }
+
+    /// Create a new CBC Encryption instance with an IV derived deterministically from a seed.
+    /// Returns the instance and the generated IV.
+    ///
+    /// Note: The IV is NOT random, use this for reproducible tests only
+    #[cfg(feature = "std")]
+    pub fn with_seeded_iv(primitive: T, seed: u64) -> (Self, [u8; B]) {
+        let mut iv = [0; B];
+        XorShift64::new(seed).fill_bytes(&mut iv);
+
+        (Self::new(primitive, iv), iv)
+    }
 }
 
 impl<T: PrimitiveEncryption<B>, const B: usize> BlockCipherEncryption<B> for CbcEncryption<T, B> {
@@ -30,6 +89,26 @@ impl<T: PrimitiveEncryption<B>, const B: usize> BlockCipherEncryption<B> for Cbc
         self.primitive.encrypt(&mut self.iv);
         data.copy_from_slice(&self.iv);
     }
+
+    fn try_encrypt(&mut self, data: &mut [u8; B]) -> Result<(), BlockCipherError> {
+        let mut block = self.iv;
+        mem::xor_buffers(&mut block, data);
+        self.primitive.try_encrypt_block(&mut block)?;
+
+        self.iv = block;
+        data.copy_from_slice(&self.iv);
+        Ok(())
+    }
+}
+
+impl<T: PrimitiveEncryption<B>, const B: usize> RuntimeInfo for CbcEncryption<T, B> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
 }
 
 pub struct CbcDecryption<T: PrimitiveDecryption<BLOCKSIZE>, const BLOCKSIZE: usize> {
@@ -41,6 +120,27 @@ impl<T: PrimitiveDecryption<B>, const B: usize> CbcDecryption<T, B> {
     pub fn new(primitive: T, iv: [u8; B]) -> Self {
         Self { primitive, iv }
     }
+
+    /// Create a new CBC Decryption instance from a primitive and an IV slice.
+    /// Fails if the IV is not exactly one block long.
+    pub fn try_new(primitive: T, iv: &[u8]) -> Result<Self, BlockCipherError> {
+        Ok(Self::new(primitive, iv_from_slice(iv)?))
+    }
+
+    /// Create a new CBC Decryption instance from a primitive and an IV slice.
+    /// Up to the primitives blocksize of IV contents will be used, a shorter IV is padded with zeros.
+    ///
+    /// Note: A wrong IV length is almost always a bug, prefer `try_new`
+    pub fn new_truncating(primitive: T, iv: &[u8]) -> Self {
+        Self::new(primitive, iv_truncating(iv))
+    }
+
+    /// Resets the chaining state to a new IV, keeping the primitive and its key schedule.
+    /// Fails if the IV is not exactly one block long.
+    pub fn reset_iv(&mut self, iv: &[u8]) -> Result<(), BlockCipherError> {
+        self.iv = iv_from_slice(iv)?;
+        Ok(())
+    }
 }
 
 impl<T: PrimitiveDecryption<B>, const B: usize> BlockCipherDecryption<B> for CbcDecryption<T, B> {
@@ -53,4 +153,40 @@ impl<T: PrimitiveDecryption<B>, const B: usize> BlockCipherDecryption<B> for Cbc
 
         self.iv = new_iv;
     }
+
+    fn try_decrypt(&mut self, data: &mut [u8; B]) -> Result<(), BlockCipherError> {
+        let new_iv = *data;
+
+        self.primitive.try_decrypt_block(data)?;
+        mem::xor_buffers(data, &self.iv);
+
+        self.iv = new_iv;
+        Ok(())
+    }
+}
+
+impl<T: PrimitiveDecryption<B>, const B: usize> RuntimeInfo for CbcDecryption<T, B> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
+}
+
+fn iv_from_slice<const B: usize>(iv: &[u8]) -> Result<[u8; B], BlockCipherError> {
+    if iv.len() != B {
+        return Err(BlockCipherError::InvalidIvLength { got: iv.len(), expected: B });
+    }
+
+    Ok(iv_truncating(iv))
+}
+
+fn iv_truncating<const B: usize>(iv: &[u8]) -> [u8; B] {
+    let mut block = [0; B];
+    let len = core::cmp::min(B, iv.len());
+    block[..len].copy_from_slice(&iv[..len]);
+
+    block
 }
@@ -1,8 +1,19 @@
+use crate::errors::blockcipher::BlockCipherError;
 use crate::mem;
-use crate::traits::cipher::primitive::BlockCipherPrimitiveDecryption as PrimitiveDecryption;
+use crate::traits::cipher::{
+    finalize::{BlockCipherResult, Finalizable},
+    sink::ByteSink,
+    primitive::{
+        BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+        BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+    },
+};
 use crate::util::{buffer::ArrayBuffer, iopool::IoPool};
+#[cfg(feature = "std")]
 use std::io;
-use std::iter::FromIterator;
+use core::iter::FromIterator;
+use std::sync::mpsc;
+use std::thread;
 
 struct Transmission<const BLOCKSIZE: usize> {
     block: [u8; BLOCKSIZE],
@@ -41,17 +52,17 @@ impl<const B: usize> ThreadedCbcDecryption<B> {
     }
 
     fn process_buffer(&mut self) {
-        let new_iv = ArrayBuffer::from(self.buffer).into();
+        let new_iv = self.buffer.into();
 
         let block = self.buffer.extract();
-        let iv = std::mem::replace(&mut self.iv, new_iv);
+        let iv = core::mem::replace(&mut self.iv, new_iv);
 
         self.mutator.push(Transmission { block, iv });
     }
 
     pub fn missing(&self) -> Option<usize> {
         if !self.buffer.is_full() && !self.buffer.is_empty() {
-            return Some(self.buffer.capacity());
+            return Some(self.buffer.remaining());
         }
 
         None
@@ -75,20 +86,158 @@ impl<const B: usize> ThreadedCbcDecryption<B> {
     }
 }
 
+impl<const B: usize> Finalizable for ThreadedCbcDecryption<B> {
+    fn finalize_readable(self) -> BlockCipherResult {
+        if let Some(missing) = self.missing() {
+            return Err(BlockCipherError::IncompleteBlock(missing));
+        }
+
+        Ok(self.finalize())
+    }
+}
+
+impl<const B: usize> ByteSink for ThreadedCbcDecryption<B> {
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BlockCipherError> {
+        let mut written = 0;
+
+        // Push data until all contents have been written, if necessary, then encrypt buffer
+        while written < data.len() {
+            written += self.buffer.push_slice(&data[written..]);
+
+            if self.buffer.is_full() {
+                self.process_buffer();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
 impl<const B: usize> io::Write for ThreadedCbcDecryption<B> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_bytes(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// CBC Encryption with the primitive running on a dedicated worker thread
+///
+/// CBC encryption is inherently serial, so blocks are handed to the worker one at a time,
+/// while chaining and buffering happen on the calling side.
+pub struct ThreadedCbcEncryption<T: PrimitiveEncryption<BLOCKSIZE> + Send + 'static, const BLOCKSIZE: usize> {
+    sender: mpsc::Sender<[u8; BLOCKSIZE]>,
+    receiver: mpsc::Receiver<[u8; BLOCKSIZE]>,
+    worker: thread::JoinHandle<T>,
+    buffer: ArrayBuffer<u8, BLOCKSIZE>,
+    iv: [u8; BLOCKSIZE],
+    output: Vec<u8>,
+}
+
+impl<T: PrimitiveEncryption<B> + Send + 'static, const B: usize> ThreadedCbcEncryption<T, B> {
+    pub fn new(primitive: T, iv: [u8; B]) -> Self {
+        let (sender, worker_receiver) = mpsc::channel::<[u8; B]>();
+        let (worker_sender, receiver) = mpsc::channel();
+
+        let worker = thread::spawn(move || {
+            for mut block in worker_receiver {
+                primitive.encrypt(&mut block);
+
+                if worker_sender.send(block).is_err() {
+                    break;
+                }
+            }
+
+            primitive
+        });
+
+        Self {
+            sender,
+            receiver,
+            worker,
+            buffer: ArrayBuffer::new(),
+            iv,
+            output: Vec::new(),
+        }
+    }
+
+    fn process_buffer(&mut self) -> Result<(), BlockCipherError> {
+        // The buffer is only cleared once the worker returned the block, a failure leaves it untouched
+        let mut block: [u8; B] = self.buffer.into();
+        mem::xor_buffers(&mut block, &self.iv);
+
+        let disconnected = || BlockCipherError::BackendFailure("cbc worker thread disconnected".to_string());
+
+        self.sender.send(block).map_err(|_| disconnected())?;
+        self.iv = self.receiver.recv().map_err(|_| disconnected())?;
+        self.output.extend_from_slice(&self.iv);
+
+        self.buffer.extract();
+
+        Ok(())
+    }
+
+    pub fn missing(&self) -> Option<usize> {
+        if !self.buffer.is_full() && !self.buffer.is_empty() {
+            return Some(self.buffer.remaining());
+        }
+
+        None
+    }
+
+    /// Stops the worker thread and returns the ciphertext of all blocks written
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` if an incomplete block is still buffered
+    /// and with `BlockCipherError::BackendFailure` if the worker thread panicked.
+    pub fn finalize<I>(self) -> Result<I, BlockCipherError>
+    where
+        I: FromIterator<u8>,
+    {
+        if let Some(missing) = self.missing() {
+            return Err(BlockCipherError::IncompleteBlock(missing));
+        }
+
+        drop(self.sender);
+        self.worker
+            .join()
+            .map_err(|_| BlockCipherError::BackendFailure("cbc worker thread panicked".to_string()))?;
+
+        Ok(self.output.into_iter().collect())
+    }
+}
+
+impl<T: PrimitiveEncryption<B> + Send + 'static, const B: usize> Finalizable for ThreadedCbcEncryption<T, B> {
+    fn finalize_readable(self) -> BlockCipherResult {
+        self.finalize()
+    }
+}
+
+impl<T: PrimitiveEncryption<B> + Send + 'static, const B: usize> ByteSink for ThreadedCbcEncryption<T, B> {
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BlockCipherError> {
         let mut written = 0;
 
-        // Push buf until all contents have been written, if necessary, then encrypt buffer
-        while written < buf.len() {
-            written += self.buffer.push_slice(&buf[written..]);
+        // Push data until all contents have been written, if necessary, then encrypt buffer
+        while written < data.len() {
+            written += self.buffer.push_slice(&data[written..]);
 
             if self.buffer.is_full() {
-                self.process_buffer();
+                self.process_buffer()?;
             }
         }
 
-        Ok(written)
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: PrimitiveEncryption<B> + Send + 'static, const B: usize> io::Write for ThreadedCbcEncryption<T, B> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_bytes(buf)?;
+        Ok(buf.len())
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -1,7 +1,9 @@
 pub mod syncronous;
+#[cfg(feature = "std")]
 pub mod threaded;
 
 use syncronous::*;
+#[cfg(feature = "std")]
 use threaded::*;
 use crate::traits::cipher::primitive::{
     BlockCipherPrimitiveDecryption as PrimitiveDecryption,
@@ -32,10 +34,12 @@ impl<T: PrimitiveDecryption<B>, const B: usize> EcbDecryptionProvider<B> for T {
     }
 }
 
+#[cfg(feature = "std")]
 pub trait ThreadedEcbEncryptionProvider<const BLOCKSIZE: usize> {
     fn with_threaded_ecb_encryption(self, threads: usize) -> ThreadedEcb<BLOCKSIZE>;
 }
 
+#[cfg(feature = "std")]
 impl<T, const B: usize> ThreadedEcbEncryptionProvider<B> for T
 where
     T: PrimitiveEncryption<B> + Send + Sync + 'static,
@@ -45,10 +49,12 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 pub trait ThreadedEcbDecryptionProvider<const BLOCKSIZE: usize> {
     fn with_threaded_ecb_decryption(self, threads: usize) -> ThreadedEcb<BLOCKSIZE>;
 }
 
+#[cfg(feature = "std")]
 impl<T, const B: usize> ThreadedEcbDecryptionProvider<B> for T
 where
     T: PrimitiveDecryption<B> + Send + Sync + 'static,
@@ -1,3 +1,4 @@
+use crate::errors::blockcipher::BlockCipherError;
 use crate::traits::cipher::{
     block::{
         BlockCipherDecryption, 
@@ -5,7 +6,8 @@ use crate::traits::cipher::{
     },
     primitive::{
         BlockCipherPrimitiveDecryption as PrimitiveDecryption,
-        BlockCipherPrimitiveEncryption as PrimitiveEncryption
+        BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+        BlockCipherRuntimeInfo as RuntimeInfo
     }
 };
 
@@ -27,6 +29,20 @@ impl<T: PrimitiveEncryption<B>, const B: usize> BlockCipherEncryption<B> for Ecb
     fn encrypt(&mut self, data: &mut [u8; B]) {
         self.primitive.encrypt(data)
     }
+
+    fn try_encrypt(&mut self, data: &mut [u8; B]) -> Result<(), BlockCipherError> {
+        self.primitive.try_encrypt_block(data)
+    }
+}
+
+impl<T: PrimitiveEncryption<B>, const B: usize> RuntimeInfo for EcbEncryption<T, B> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
 }
 
 /// ECB decryption provider
@@ -47,4 +63,18 @@ impl<T: PrimitiveDecryption<B>, const B: usize> BlockCipherDecryption<B> for Ecb
     fn decrypt(&mut self, data: &mut [u8; B]) {
         self.primitive.decrypt(data)
     }
+
+    fn try_decrypt(&mut self, data: &mut [u8; B]) -> Result<(), BlockCipherError> {
+        self.primitive.try_decrypt_block(data)
+    }
+}
+
+impl<T: PrimitiveDecryption<B>, const B: usize> RuntimeInfo for EcbDecryption<T, B> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
 }
@@ -1,10 +1,16 @@
-use crate::traits::cipher::primitive::{
-    BlockCipherPrimitiveDecryption as PrimitiveDecryption,
-    BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+use crate::errors::blockcipher::BlockCipherError;
+use crate::traits::cipher::{
+    finalize::{BlockCipherResult, Finalizable},
+    sink::ByteSink,
+    primitive::{
+        BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+        BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+    },
 };
 use crate::util::{buffer::ArrayBuffer, iopool::IoPool};
+#[cfg(feature = "std")]
 use std::io;
-use std::iter::FromIterator;
+use core::iter::FromIterator;
 
 type Mutator<const BLOCKSIZE: usize> = IoPool<[u8; BLOCKSIZE], [u8; BLOCKSIZE]>;
 
@@ -65,7 +71,7 @@ impl<const B: usize> ThreadedEcb<B> {
 
     pub fn missing(&self) -> Option<usize> {
         if !self.buffer.is_full() && !self.buffer.is_empty() {
-            return Some(self.buffer.capacity());
+            return Some(self.buffer.remaining());
         }
 
         None
@@ -87,20 +93,38 @@ impl<const B: usize> ThreadedEcb<B> {
     }
 }
 
-impl<const B: usize> io::Write for ThreadedEcb<B> {
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+impl<const B: usize> Finalizable for ThreadedEcb<B> {
+    fn finalize_readable(self) -> BlockCipherResult {
+        if let Some(missing) = self.missing() {
+            return Err(BlockCipherError::IncompleteBlock(missing));
+        }
+
+        Ok(self.finalize())
+    }
+}
+
+impl<const B: usize> ByteSink for ThreadedEcb<B> {
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BlockCipherError> {
         let mut written = 0;
 
-        // Push buf until all contents have been written, if necessary, then encrypt buffer
-        while written < buf.len() {
-            written += self.buffer.push_slice(&buf[written..]);
+        // Push data until all contents have been written, if necessary, then encrypt buffer
+        while written < data.len() {
+            written += self.buffer.push_slice(&data[written..]);
 
             if self.buffer.is_full() {
                 self.process_buffer();
             }
         }
 
-        Ok(written)
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const B: usize> io::Write for ThreadedEcb<B> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_bytes(buf)?;
+        Ok(buf.len())
     }
 
     fn flush(&mut self) -> io::Result<()> {
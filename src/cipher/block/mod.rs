@@ -2,3 +2,5 @@ pub mod buffered;
 pub mod cbc;
 pub mod ecb;
 pub mod primitive;
+pub mod reader;
+pub mod xts;
@@ -1,12 +1,14 @@
+use crate::errors::blockcipher::BlockCipherError;
 use crate::traits::cipher::primitive::{
     BlockCipherPrimitiveDecryption as PrimitiveDecryption,
     BlockCipherPrimitiveEncryption as PrimitiveEncryption,
     BlockCipherPrimitiveInfo as PrimitiveInfo,
 };
+use alloc::vec::Vec;
 
 use crate::mem;
 
-const S_BOX: [u8; 256] = [
+pub(crate) const S_BOX: [u8; 256] = [
     0x63, 0x7C, 0x77, 0x7B, 0xF2, 0x6B, 0x6F, 0xC5, 0x30, 0x01, 0x67, 0x2B, 0xFE, 0xD7, 0xAB, 0x76,
     0xCA, 0x82, 0xC9, 0x7D, 0xFA, 0x59, 0x47, 0xF0, 0xAD, 0xD4, 0xA2, 0xAF, 0x9C, 0xA4, 0x72, 0xC0,
     0xB7, 0xFD, 0x93, 0x26, 0x36, 0x3F, 0xF7, 0xCC, 0x34, 0xA5, 0xE5, 0xF1, 0x71, 0xD8, 0x31, 0x15,
@@ -25,7 +27,7 @@ const S_BOX: [u8; 256] = [
     0x8C, 0xA1, 0x89, 0x0D, 0xBF, 0xE6, 0x42, 0x68, 0x41, 0x99, 0x2D, 0x0F, 0xB0, 0x54, 0xBB, 0x16,
 ];
 
-const S_BOX_INV: [u8; 256] = [
+pub(crate) const S_BOX_INV: [u8; 256] = [
     0x52, 0x09, 0x6A, 0xD5, 0x30, 0x36, 0xA5, 0x38, 0xBF, 0x40, 0xA3, 0x9E, 0x81, 0xF3, 0xD7, 0xFB,
     0x7C, 0xE3, 0x39, 0x82, 0x9B, 0x2F, 0xFF, 0x87, 0x34, 0x8E, 0x43, 0x44, 0xC4, 0xDE, 0xE9, 0xCB,
     0x54, 0x7B, 0x94, 0x32, 0xA6, 0xC2, 0x23, 0x3D, 0xEE, 0x4C, 0x95, 0x0B, 0x42, 0xFA, 0xC3, 0x4E,
@@ -44,6 +46,7 @@ const S_BOX_INV: [u8; 256] = [
     0x17, 0x2B, 0x04, 0x7E, 0xBA, 0x77, 0xD6, 0x26, 0xE1, 0x69, 0x14, 0x63, 0x55, 0x21, 0x0C, 0x7D,
 ];
 
+#[cfg_attr(feature = "ct-aes", allow(dead_code))]
 const MUL2: [u8; 256] = [
     0x00, 0x02, 0x04, 0x06, 0x08, 0x0A, 0x0C, 0x0E, 0x10, 0x12, 0x14, 0x16, 0x18, 0x1A, 0x1C, 0x1E,
     0x20, 0x22, 0x24, 0x26, 0x28, 0x2A, 0x2C, 0x2E, 0x30, 0x32, 0x34, 0x36, 0x38, 0x3A, 0x3C, 0x3E,
@@ -63,6 +66,7 @@ const MUL2: [u8; 256] = [
     0xFB, 0xF9, 0xFF, 0xFD, 0xF3, 0xF1, 0xF7, 0xF5, 0xEB, 0xE9, 0xEF, 0xED, 0xE3, 0xE1, 0xE7, 0xE5,
 ];
 
+#[cfg_attr(feature = "ct-aes", allow(dead_code))]
 const MUL3: [u8; 256] = [
     0x00, 0x03, 0x06, 0x05, 0x0C, 0x0F, 0x0A, 0x09, 0x18, 0x1B, 0x1E, 0x1D, 0x14, 0x17, 0x12, 0x11,
     0x30, 0x33, 0x36, 0x35, 0x3C, 0x3F, 0x3A, 0x39, 0x28, 0x2B, 0x2E, 0x2D, 0x24, 0x27, 0x22, 0x21,
@@ -82,6 +86,7 @@ const MUL3: [u8; 256] = [
     0x0B, 0x08, 0x0D, 0x0E, 0x07, 0x04, 0x01, 0x02, 0x13, 0x10, 0x15, 0x16, 0x1F, 0x1C, 0x19, 0x1A,
 ];
 
+#[cfg_attr(feature = "ct-aes", allow(dead_code))]
 const MUL9: [u8; 256] = [
     0x00, 0x09, 0x12, 0x1B, 0x24, 0x2D, 0x36, 0x3F, 0x48, 0x41, 0x5A, 0x53, 0x6C, 0x65, 0x7E, 0x77,
     0x90, 0x99, 0x82, 0x8B, 0xB4, 0xBD, 0xA6, 0xAF, 0xD8, 0xD1, 0xCA, 0xC3, 0xFC, 0xF5, 0xEE, 0xE7,
@@ -101,6 +106,7 @@ const MUL9: [u8; 256] = [
     0x31, 0x38, 0x23, 0x2A, 0x15, 0x1C, 0x07, 0x0E, 0x79, 0x70, 0x6B, 0x62, 0x5D, 0x54, 0x4F, 0x46,
 ];
 
+#[cfg_attr(feature = "ct-aes", allow(dead_code))]
 const MUL11: [u8; 256] = [
     0x00, 0x0B, 0x16, 0x1D, 0x2C, 0x27, 0x3A, 0x31, 0x58, 0x53, 0x4E, 0x45, 0x74, 0x7F, 0x62, 0x69,
     0xB0, 0xBB, 0xA6, 0xAD, 0x9C, 0x97, 0x8A, 0x81, 0xE8, 0xE3, 0xFE, 0xF5, 0xC4, 0xCF, 0xD2, 0xD9,
@@ -120,6 +126,7 @@ const MUL11: [u8; 256] = [
     0xCA, 0xC1, 0xDC, 0xD7, 0xE6, 0xED, 0xF0, 0xFB, 0x92, 0x99, 0x84, 0x8F, 0xBE, 0xB5, 0xA8, 0xA3,
 ];
 
+#[cfg_attr(feature = "ct-aes", allow(dead_code))]
 const MUL13: [u8; 256] = [
     0x00, 0x0D, 0x1A, 0x17, 0x34, 0x39, 0x2E, 0x23, 0x68, 0x65, 0x72, 0x7F, 0x5C, 0x51, 0x46, 0x4B,
     0xD0, 0xDD, 0xCA, 0xC7, 0xE4, 0xE9, 0xFE, 0xF3, 0xB8, 0xB5, 0xA2, 0xAF, 0x8C, 0x81, 0x96, 0x9B,
@@ -139,6 +146,7 @@ const MUL13: [u8; 256] = [
     0xDC, 0xD1, 0xC6, 0xCB, 0xE8, 0xE5, 0xF2, 0xFF, 0xB4, 0xB9, 0xAE, 0xA3, 0x80, 0x8D, 0x9A, 0x97,
 ];
 
+#[cfg_attr(feature = "ct-aes", allow(dead_code))]
 const MUL14: [u8; 256] = [
     0x00, 0x0e, 0x1c, 0x12, 0x38, 0x36, 0x24, 0x2a, 0x70, 0x7e, 0x6c, 0x62, 0x48, 0x46, 0x54, 0x5a,
     0xe0, 0xee, 0xfc, 0xf2, 0xd8, 0xd6, 0xc4, 0xca, 0x90, 0x9e, 0x8c, 0x82, 0xa8, 0xa6, 0xb4, 0xba,
@@ -181,19 +189,72 @@ pub const AES_BLOCKSIZE: usize = 16;
 pub const AES_128_KEYLEN: usize = 16;
 pub const AES_192_KEYLEN: usize = 24;
 pub const AES_256_KEYLEN: usize = 32;
+/// The state is kept as bytes in the input order of FIPS-197, s[r, c] = in[r + 4c], so column c is bytes 4c..4c + 4.
+/// The software implementation never loads words, which keeps it independent of the target endianness.
 pub type AesBlock = [u8; AES_BLOCKSIZE];
 
 /// Aes Encryption and Decryption provider
+#[derive(Clone)]
 pub struct Aes {
     cfg: AesCfg,
 }
 
 impl Aes {
     /// Create a new Aes instance
-    pub fn new(key: &[u8]) -> Self {
-        Self {
+    ///
+    /// Fails with `BlockCipherError::InvalidKeyLength` unless the key is exactly 16, 24 or 32 bytes long
+    pub fn new(key: &[u8]) -> Result<Self, BlockCipherError> {
+        validate_key_len(key)?;
+
+        Ok(Self {
             cfg: aes_configuration(key),
+        })
+    }
+
+    /// Replaces the key, reusing the allocation of the key schedule.
+    /// The key schedule is only recomputed if the key differs from the current one.
+    ///
+    /// Fails with `BlockCipherError::InvalidKeyLength` unless the key is exactly 16, 24 or 32 bytes long,
+    /// the current key is kept in that case
+    pub fn rekey(&mut self, key: &[u8]) -> Result<(), BlockCipherError> {
+        validate_key_len(key)?;
+
+        // The key schedule starts with the key itself
+        let expanded_key = &mut self.cfg.expanded_key;
+        if expanded_key.len() == (rounds(key.len()) + 1) * AES_BLOCKSIZE
+            && mem::memeq_s(&expanded_key[..key.len()], key)
+        {
+            return Ok(());
         }
+
+        mem::zeroize(expanded_key);
+        self.cfg.rounds = key_expansion_into(key, expanded_key);
+        Ok(())
+    }
+
+    /// Encrypts a single block and returns the result, leaving the input untouched.
+    /// Convenient for building modes, eg. generating a CTR keystream block from a counter.
+    pub fn encrypt_block(&self, mut block: AesBlock) -> AesBlock {
+        PrimitiveEncryption::encrypt(self, &mut block);
+        block
+    }
+
+    /// Decrypts a single block and returns the result, leaving the input untouched
+    pub fn decrypt_block(&self, mut block: AesBlock) -> AesBlock {
+        PrimitiveDecryption::decrypt(self, &mut block);
+        block
+    }
+}
+
+fn validate_key_len(key: &[u8]) -> Result<(), BlockCipherError> {
+    match key.len() {
+        AES_128_KEYLEN | AES_192_KEYLEN | AES_256_KEYLEN => Ok(()),
+
+        got => Err(BlockCipherError::InvalidKeyLength {
+            got,
+            min: Aes::KEYLEN_MIN,
+            max: Aes::KEYLEN_MAX,
+        }),
     }
 }
 
@@ -205,47 +266,134 @@ impl PrimitiveInfo for Aes {
 
 impl PrimitiveEncryption<AES_BLOCKSIZE> for Aes {
     fn encrypt(&self, state: &mut AesBlock) {
-        add_roundkey(state.as_mut(), &self.cfg.expanded_key[0..16]);
+        #[cfg(all(feature = "aes-ni", target_arch = "x86_64"))]
+        if ni::available() {
+            // SAFETY: The aes target feature was detected at runtime
+            unsafe { ni::encrypt(&self.cfg, state) };
+            return;
+        }
+
+        encrypt_soft(&self.cfg, state);
+    }
+}
 
-        for i in 0..self.cfg.rounds - 1 {
-            sub_bytes_enc(state.as_mut());
-            shift_rows_enc(state.as_mut());
-            mix_columns_enc(state.as_mut());
+impl PrimitiveDecryption<AES_BLOCKSIZE> for Aes {
+    fn decrypt(&self, state: &mut AesBlock) {
+        #[cfg(all(feature = "aes-ni", target_arch = "x86_64"))]
+        if ni::available() {
+            // SAFETY: The aes target feature was detected at runtime
+            unsafe { ni::decrypt(&self.cfg, state) };
+            return;
+        }
+
+        decrypt_soft(&self.cfg, state);
+    }
+}
+
+/// Defines an Aes variant whose key length is fixed by the type of the key
+macro_rules! aes_variant {
+    ($name:ident, $keylen:ident, $bits:literal, $wrong_key:literal) => {
+        #[doc = concat!("Aes-", $bits, " Encryption and Decryption provider")]
+        ///
+        /// Takes a key of exactly the right length, so a wrong key length is rejected at compile time.
+        /// Dereferences to the generic `Aes`, except for `rekey` which could change the variant.
+        ///
+        /// ```compile_fail
+        #[doc = concat!("use himitsu::cipher::block::primitive::aes::", stringify!($name), ";")]
+        ///
+        #[doc = concat!("let aes = ", stringify!($name), "::new(&[0u8; ", $wrong_key, "]);")]
+        /// ```
+        #[derive(Clone)]
+        pub struct $name(Aes);
+
+        impl $name {
+            pub fn new(key: &[u8; $keylen]) -> Self {
+                Self(Aes {
+                    cfg: aes_configuration(key),
+                })
+            }
+        }
+
+        impl core::ops::Deref for $name {
+            type Target = Aes;
+
+            fn deref(&self) -> &Aes {
+                &self.0
+            }
+        }
+
+        impl From<$name> for Aes {
+            fn from(variant: $name) -> Aes {
+                variant.0
+            }
+        }
+
+        impl PrimitiveInfo for $name {
+            const BLOCKSIZE: usize = AES_BLOCKSIZE;
+            const KEYLEN_MIN: usize = $keylen;
+            const KEYLEN_MAX: usize = $keylen;
+        }
+
+        impl PrimitiveEncryption<AES_BLOCKSIZE> for $name {
+            #[inline(always)]
+            fn encrypt(&self, state: &mut AesBlock) {
+                self.0.encrypt(state)
+            }
+        }
 
-            let start = 16 * (i + 1);
-            let end = start + 16;
-            add_roundkey(state.as_mut(), &self.cfg.expanded_key[start..end]);
+        impl PrimitiveDecryption<AES_BLOCKSIZE> for $name {
+            #[inline(always)]
+            fn decrypt(&self, state: &mut AesBlock) {
+                self.0.decrypt(state)
+            }
         }
+    };
+}
+
+aes_variant!(Aes128, AES_128_KEYLEN, "128", "24");
+aes_variant!(Aes192, AES_192_KEYLEN, "192", "16");
+aes_variant!(Aes256, AES_256_KEYLEN, "256", "24");
 
+fn encrypt_soft(cfg: &AesCfg, state: &mut AesBlock) {
+    add_roundkey(state.as_mut(), &cfg.expanded_key[0..16]);
+
+    for i in 0..cfg.rounds - 1 {
         sub_bytes_enc(state.as_mut());
         shift_rows_enc(state.as_mut());
+        mix_columns_enc(state.as_mut());
 
-        let index = self.cfg.expanded_key.len() - 16;
-        add_roundkey(state.as_mut(), &self.cfg.expanded_key[index..]);
+        let start = 16 * (i + 1);
+        let end = start + 16;
+        add_roundkey(state.as_mut(), &cfg.expanded_key[start..end]);
     }
-}
 
-impl PrimitiveDecryption<AES_BLOCKSIZE> for Aes {
-    fn decrypt(&self, state: &mut AesBlock) {
-        let index = self.cfg.expanded_key.len() - 16;
-        add_roundkey(state.as_mut(), &self.cfg.expanded_key[index..]);
-        sub_bytes_dec(state.as_mut());
-        shift_rows_dec(state.as_mut());
+    sub_bytes_enc(state.as_mut());
+    shift_rows_enc(state.as_mut());
 
-        for i in (0..self.cfg.rounds - 1).rev() {
-            let start = 16 * (i + 1);
-            let end = start + 16;
-            add_roundkey(state.as_mut(), &self.cfg.expanded_key[start..end]);
+    let index = cfg.expanded_key.len() - 16;
+    add_roundkey(state.as_mut(), &cfg.expanded_key[index..]);
+}
 
-            mix_columns_dec(state.as_mut());
-            sub_bytes_dec(state.as_mut());
-            shift_rows_dec(state.as_mut());
-        }
+fn decrypt_soft(cfg: &AesCfg, state: &mut AesBlock) {
+    let index = cfg.expanded_key.len() - 16;
+    add_roundkey(state.as_mut(), &cfg.expanded_key[index..]);
+    sub_bytes_dec(state.as_mut());
+    shift_rows_dec(state.as_mut());
 
-        add_roundkey(state.as_mut(), &self.cfg.expanded_key[0..16]);
+    for i in (0..cfg.rounds - 1).rev() {
+        let start = 16 * (i + 1);
+        let end = start + 16;
+        add_roundkey(state.as_mut(), &cfg.expanded_key[start..end]);
+
+        mix_columns_dec(state.as_mut());
+        sub_bytes_dec(state.as_mut());
+        shift_rows_dec(state.as_mut());
     }
+
+    add_roundkey(state.as_mut(), &cfg.expanded_key[0..16]);
 }
 
+#[derive(Clone)]
 struct AesCfg {
     expanded_key: Vec<u8>,
     rounds: usize,
@@ -261,10 +409,10 @@ fn aes_configuration(key: &[u8]) -> AesCfg {
 
 fn key_expansion_gcon(k: &mut [u8; 4]) {
     // Apply S_BOX
-    k[0] = S_BOX[k[0] as usize];
-    k[1] = S_BOX[k[1] as usize];
-    k[2] = S_BOX[k[2] as usize];
-    k[3] = S_BOX[k[3] as usize];
+    k[0] = sub_byte(k[0]);
+    k[1] = sub_byte(k[1]);
+    k[2] = sub_byte(k[2]);
+    k[3] = sub_byte(k[3]);
 }
 
 fn key_expansion_rcon(k: &mut [u8; 4], iteration: usize) {
@@ -275,23 +423,39 @@ fn key_expansion_rcon(k: &mut [u8; 4], iteration: usize) {
 
 /// Returns the expanded key and the number of rounds
 fn key_expansion(key: &[u8]) -> (Vec<u8>, usize) {
-    let (rounds, copy, acc_key_len) = match key.len() {
-        0..=AES_128_KEYLEN => (10, key.len(), AES_128_KEYLEN),
+    let mut expanded_key = Vec::new();
+    let rounds = key_expansion_into(key, &mut expanded_key);
 
-        17..=AES_192_KEYLEN => (12, key.len(), AES_192_KEYLEN),
+    (expanded_key, rounds)
+}
 
-        25..=AES_256_KEYLEN => (14, key.len(), AES_256_KEYLEN),
+/// Returns the number of rounds for a valid key length
+///
+/// FIPS-197 counts in 32 bit words: the block is Nb = 4 words, the key Nk = 4, 6 or 8 words and there are
+/// Nr = Nk + 6 rounds. The key schedule holds Nb * (Nr + 1) words, one round key per round plus the initial one.
+/// For AES-192 a round key does not line up with the Nk words produced per RotWord/SubWord/Rcon step,
+/// so round keys straddle those steps.
+fn rounds(key_len: usize) -> usize {
+    match key_len {
+        AES_128_KEYLEN => 10,
+        AES_192_KEYLEN => 12,
+        AES_256_KEYLEN => 14,
+        _ => unreachable!("key length is validated by Aes::new (found {})", key_len),
+    }
+}
 
-        _ => (14, AES_256_KEYLEN, AES_256_KEYLEN),
-    };
+/// Writes the expanded key into the given buffer, replacing its contents. Returns the number of rounds
+///
+/// Word w[i] is derived from w[i - Nk] and w[i - 1], which is rotated, substituted and mixed with Rcon
+/// whenever i is a multiple of Nk. For Nk = 8 the word at i mod Nk = 4 is only substituted.
+fn key_expansion_into(key: &[u8], expanded_key: &mut Vec<u8>) -> usize {
+    let acc_key_len = key.len();
+    let rounds = rounds(acc_key_len);
 
     let capacity = (rounds + 1) * AES_BLOCKSIZE;
-    let mut expanded_key = Vec::with_capacity(capacity);
-    expanded_key.extend(&key[0..copy]);
-
-    while expanded_key.len() != acc_key_len {
-        expanded_key.push(0);
-    }
+    expanded_key.clear();
+    expanded_key.reserve(capacity);
+    expanded_key.extend(key);
 
     let mut rcon_iteration = 1;
     let mut bytes_generated = acc_key_len;
@@ -318,7 +482,7 @@ fn key_expansion(key: &[u8]) -> (Vec<u8>, usize) {
         bytes_generated += 4;
     }
 
-    (expanded_key, rounds)
+    rounds
 }
 
 /// Xor round key into state
@@ -326,10 +490,33 @@ fn add_roundkey(state: &mut [u8], key: &[u8]) {
     mem::xor_buffers_unchecked(state, key);
 }
 
+/// Defines a byte substitution that reads a table, or computes the same value in constant time with `ct-aes`
+macro_rules! table_or_ct {
+    ($name:ident, $table:ident, $ct:expr) => {
+        #[inline(always)]
+        fn $name(x: u8) -> u8 {
+            #[cfg(not(feature = "ct-aes"))]
+            return $table[x as usize];
+
+            #[cfg(feature = "ct-aes")]
+            return $ct(x);
+        }
+    };
+}
+
+table_or_ct!(sub_byte, S_BOX, ct::sub_byte);
+table_or_ct!(inv_sub_byte, S_BOX_INV, ct::inv_sub_byte);
+table_or_ct!(mul2, MUL2, |x| ct::mul(x, 2));
+table_or_ct!(mul3, MUL3, |x| ct::mul(x, 3));
+table_or_ct!(mul9, MUL9, |x| ct::mul(x, 9));
+table_or_ct!(mul11, MUL11, |x| ct::mul(x, 11));
+table_or_ct!(mul13, MUL13, |x| ct::mul(x, 13));
+table_or_ct!(mul14, MUL14, |x| ct::mul(x, 14));
+
 /// Substitute with SBOX
 fn sub_bytes_enc(state: &mut [u8]) {
-    for i in 0..16 {
-        state[i] = S_BOX[state[i] as usize];
+    for byte in state.iter_mut() {
+        *byte = sub_byte(*byte);
     }
 }
 
@@ -352,32 +539,32 @@ fn shift_rows_enc(state: &mut [u8]) {
 fn mix_columns_enc(state: &mut [u8]) {
     let mut tmp = [0u8; 16];
 
-    tmp[0] = MUL2[state[0] as usize] ^ MUL3[state[1] as usize] ^ state[2] ^ state[3];
-    tmp[1] = state[0] ^ MUL2[state[1] as usize] ^ MUL3[state[2] as usize] ^ state[3];
-    tmp[2] = state[0] ^ state[1] ^ MUL2[state[2] as usize] ^ MUL3[state[3] as usize];
-    tmp[3] = MUL3[state[0] as usize] ^ state[1] ^ state[2] ^ MUL2[state[3] as usize];
+    tmp[0] = mul2(state[0]) ^ mul3(state[1]) ^ state[2] ^ state[3];
+    tmp[1] = state[0] ^ mul2(state[1]) ^ mul3(state[2]) ^ state[3];
+    tmp[2] = state[0] ^ state[1] ^ mul2(state[2]) ^ mul3(state[3]);
+    tmp[3] = mul3(state[0]) ^ state[1] ^ state[2] ^ mul2(state[3]);
 
-    tmp[4] = MUL2[state[4] as usize] ^ MUL3[state[5] as usize] ^ state[6] ^ state[7];
-    tmp[5] = state[4] ^ MUL2[state[5] as usize] ^ MUL3[state[6] as usize] ^ state[7];
-    tmp[6] = state[4] ^ state[5] ^ MUL2[state[6] as usize] ^ MUL3[state[7] as usize];
-    tmp[7] = MUL3[state[4] as usize] ^ state[5] ^ state[6] ^ MUL2[state[7] as usize];
+    tmp[4] = mul2(state[4]) ^ mul3(state[5]) ^ state[6] ^ state[7];
+    tmp[5] = state[4] ^ mul2(state[5]) ^ mul3(state[6]) ^ state[7];
+    tmp[6] = state[4] ^ state[5] ^ mul2(state[6]) ^ mul3(state[7]);
+    tmp[7] = mul3(state[4]) ^ state[5] ^ state[6] ^ mul2(state[7]);
 
-    tmp[8] = MUL2[state[8] as usize] ^ MUL3[state[9] as usize] ^ state[10] ^ state[11];
-    tmp[9] = state[8] ^ MUL2[state[9] as usize] ^ MUL3[state[10] as usize] ^ state[11];
-    tmp[10] = state[8] ^ state[9] ^ MUL2[state[10] as usize] ^ MUL3[state[11] as usize];
-    tmp[11] = MUL3[state[8] as usize] ^ state[9] ^ state[10] ^ MUL2[state[11] as usize];
+    tmp[8] = mul2(state[8]) ^ mul3(state[9]) ^ state[10] ^ state[11];
+    tmp[9] = state[8] ^ mul2(state[9]) ^ mul3(state[10]) ^ state[11];
+    tmp[10] = state[8] ^ state[9] ^ mul2(state[10]) ^ mul3(state[11]);
+    tmp[11] = mul3(state[8]) ^ state[9] ^ state[10] ^ mul2(state[11]);
 
-    tmp[12] = MUL2[state[12] as usize] ^ MUL3[state[13] as usize] ^ state[14] ^ state[15];
-    tmp[13] = state[12] ^ MUL2[state[13] as usize] ^ MUL3[state[14] as usize] ^ state[15];
-    tmp[14] = state[12] ^ state[13] ^ MUL2[state[14] as usize] ^ MUL3[state[15] as usize];
-    tmp[15] = MUL3[state[12] as usize] ^ state[13] ^ state[14] ^ MUL2[state[15] as usize];
+    tmp[12] = mul2(state[12]) ^ mul3(state[13]) ^ state[14] ^ state[15];
+    tmp[13] = state[12] ^ mul2(state[13]) ^ mul3(state[14]) ^ state[15];
+    tmp[14] = state[12] ^ state[13] ^ mul2(state[14]) ^ mul3(state[15]);
+    tmp[15] = mul3(state[12]) ^ state[13] ^ state[14] ^ mul2(state[15]);
 
     state.copy_from_slice(&tmp);
 }
 
 fn sub_bytes_dec(state: &mut [u8]) {
-    for i in 0..16 {
-        state[i] = S_BOX_INV[state[i] as usize];
+    for byte in state.iter_mut() {
+        *byte = inv_sub_byte(*byte);
     }
 }
 
@@ -400,77 +587,177 @@ fn shift_rows_dec(state: &mut [u8]) {
 fn mix_columns_dec(state: &mut [u8]) {
     let mut tmp = [0u8; 16];
 
-    tmp[0] = MUL14[state[0] as usize]
-        ^ MUL11[state[1] as usize]
-        ^ MUL13[state[2] as usize]
-        ^ MUL9[state[3] as usize];
-    tmp[1] = MUL9[state[0] as usize]
-        ^ MUL14[state[1] as usize]
-        ^ MUL11[state[2] as usize]
-        ^ MUL13[state[3] as usize];
-    tmp[2] = MUL13[state[0] as usize]
-        ^ MUL9[state[1] as usize]
-        ^ MUL14[state[2] as usize]
-        ^ MUL11[state[3] as usize];
-    tmp[3] = MUL11[state[0] as usize]
-        ^ MUL13[state[1] as usize]
-        ^ MUL9[state[2] as usize]
-        ^ MUL14[state[3] as usize];
-
-    tmp[4] = MUL14[state[4] as usize]
-        ^ MUL11[state[5] as usize]
-        ^ MUL13[state[6] as usize]
-        ^ MUL9[state[7] as usize];
-    tmp[5] = MUL9[state[4] as usize]
-        ^ MUL14[state[5] as usize]
-        ^ MUL11[state[6] as usize]
-        ^ MUL13[state[7] as usize];
-    tmp[6] = MUL13[state[4] as usize]
-        ^ MUL9[state[5] as usize]
-        ^ MUL14[state[6] as usize]
-        ^ MUL11[state[7] as usize];
-    tmp[7] = MUL11[state[4] as usize]
-        ^ MUL13[state[5] as usize]
-        ^ MUL9[state[6] as usize]
-        ^ MUL14[state[7] as usize];
-
-    tmp[8] = MUL14[state[8] as usize]
-        ^ MUL11[state[9] as usize]
-        ^ MUL13[state[10] as usize]
-        ^ MUL9[state[11] as usize];
-    tmp[9] = MUL9[state[8] as usize]
-        ^ MUL14[state[9] as usize]
-        ^ MUL11[state[10] as usize]
-        ^ MUL13[state[11] as usize];
-    tmp[10] = MUL13[state[8] as usize]
-        ^ MUL9[state[9] as usize]
-        ^ MUL14[state[10] as usize]
-        ^ MUL11[state[11] as usize];
-    tmp[11] = MUL11[state[8] as usize]
-        ^ MUL13[state[9] as usize]
-        ^ MUL9[state[10] as usize]
-        ^ MUL14[state[11] as usize];
-
-    tmp[12] = MUL14[state[12] as usize]
-        ^ MUL11[state[13] as usize]
-        ^ MUL13[state[14] as usize]
-        ^ MUL9[state[15] as usize];
-    tmp[13] = MUL9[state[12] as usize]
-        ^ MUL14[state[13] as usize]
-        ^ MUL11[state[14] as usize]
-        ^ MUL13[state[15] as usize];
-    tmp[14] = MUL13[state[12] as usize]
-        ^ MUL9[state[13] as usize]
-        ^ MUL14[state[14] as usize]
-        ^ MUL11[state[15] as usize];
-    tmp[15] = MUL11[state[12] as usize]
-        ^ MUL13[state[13] as usize]
-        ^ MUL9[state[14] as usize]
-        ^ MUL14[state[15] as usize];
+    tmp[0] = mul14(state[0])
+        ^ mul11(state[1])
+        ^ mul13(state[2])
+        ^ mul9(state[3]);
+    tmp[1] = mul9(state[0])
+        ^ mul14(state[1])
+        ^ mul11(state[2])
+        ^ mul13(state[3]);
+    tmp[2] = mul13(state[0])
+        ^ mul9(state[1])
+        ^ mul14(state[2])
+        ^ mul11(state[3]);
+    tmp[3] = mul11(state[0])
+        ^ mul13(state[1])
+        ^ mul9(state[2])
+        ^ mul14(state[3]);
+
+    tmp[4] = mul14(state[4])
+        ^ mul11(state[5])
+        ^ mul13(state[6])
+        ^ mul9(state[7]);
+    tmp[5] = mul9(state[4])
+        ^ mul14(state[5])
+        ^ mul11(state[6])
+        ^ mul13(state[7]);
+    tmp[6] = mul13(state[4])
+        ^ mul9(state[5])
+        ^ mul14(state[6])
+        ^ mul11(state[7]);
+    tmp[7] = mul11(state[4])
+        ^ mul13(state[5])
+        ^ mul9(state[6])
+        ^ mul14(state[7]);
+
+    tmp[8] = mul14(state[8])
+        ^ mul11(state[9])
+        ^ mul13(state[10])
+        ^ mul9(state[11]);
+    tmp[9] = mul9(state[8])
+        ^ mul14(state[9])
+        ^ mul11(state[10])
+        ^ mul13(state[11]);
+    tmp[10] = mul13(state[8])
+        ^ mul9(state[9])
+        ^ mul14(state[10])
+        ^ mul11(state[11]);
+    tmp[11] = mul11(state[8])
+        ^ mul13(state[9])
+        ^ mul9(state[10])
+        ^ mul14(state[11]);
+
+    tmp[12] = mul14(state[12])
+        ^ mul11(state[13])
+        ^ mul13(state[14])
+        ^ mul9(state[15]);
+    tmp[13] = mul9(state[12])
+        ^ mul14(state[13])
+        ^ mul11(state[14])
+        ^ mul13(state[15]);
+    tmp[14] = mul13(state[12])
+        ^ mul9(state[13])
+        ^ mul14(state[14])
+        ^ mul11(state[15]);
+    tmp[15] = mul11(state[12])
+        ^ mul13(state[13])
+        ^ mul9(state[14])
+        ^ mul14(state[15]);
 
     state.copy_from_slice(&tmp);
 }
 
+/// Constant time replacements for the lookup tables, selected with the `ct-aes` feature.
+///
+/// Table lookups leak the looked up index through the cache, which allows recovering the key
+/// from timing measurements. Here the S-box is computed as the inverse in GF(2^8) followed by the affine
+/// transformation, and all field multiplications use masks instead of branches or indexing.
+/// This is roughly an order of magnitude slower than the table based path, so it should only be
+/// preferred where an attacker can observe timings and AES-NI is not available.
+#[cfg(any(feature = "ct-aes", test))]
+mod ct {
+    /// Multiplies in GF(2^8) modulo x^8 + x^4 + x^3 + x + 1
+    pub(super) fn mul(mut a: u8, mut b: u8) -> u8 {
+        let mut product = 0;
+
+        for _ in 0..8 {
+            product ^= a & 0u8.wrapping_sub(b & 1);
+            let carry = 0u8.wrapping_sub(a >> 7);
+            a = (a << 1) ^ (0x1B & carry);
+            b >>= 1;
+        }
+
+        product
+    }
+
+    fn square(a: u8) -> u8 {
+        mul(a, a)
+    }
+
+    /// Returns the multiplicative inverse as a^254, mapping 0 to 0
+    fn inverse(a: u8) -> u8 {
+        let a2 = square(a);
+        let a3 = mul(a2, a);
+        let a12 = square(square(a3));
+        let a15 = mul(a12, a3);
+        let a240 = square(square(square(square(a15))));
+        mul(mul(a240, a12), a2)
+    }
+
+    pub(super) fn sub_byte(x: u8) -> u8 {
+        let b = inverse(x);
+        b ^ b.rotate_left(1) ^ b.rotate_left(2) ^ b.rotate_left(3) ^ b.rotate_left(4) ^ 0x63
+    }
+
+    pub(super) fn inv_sub_byte(x: u8) -> u8 {
+        inverse(x.rotate_left(1) ^ x.rotate_left(3) ^ x.rotate_left(6) ^ 0x05)
+    }
+}
+
+/// AES-NI backend, operating on the same key schedule as the software implementation
+#[cfg(all(feature = "aes-ni", target_arch = "x86_64"))]
+mod ni {
+    use super::{AesBlock, AesCfg};
+    use core::arch::x86_64::*;
+
+    /// Returns whether the cpu supports the AES instructions, without std only if they are enabled at compile time
+    pub(super) fn available() -> bool {
+        #[cfg(feature = "std")]
+        return std::is_x86_feature_detected!("aes");
+
+        #[cfg(not(feature = "std"))]
+        cfg!(target_feature = "aes")
+    }
+
+    #[target_feature(enable = "aes")]
+    unsafe fn round_key(cfg: &AesCfg, round: usize) -> __m128i {
+        _mm_loadu_si128(cfg.expanded_key[16 * round..16 * (round + 1)].as_ptr() as *const __m128i)
+    }
+
+    /// # Safety
+    /// The cpu must support the aes target feature
+    #[target_feature(enable = "aes")]
+    pub(super) unsafe fn encrypt(cfg: &AesCfg, state: &mut AesBlock) {
+        let mut block = _mm_loadu_si128(state.as_ptr() as *const __m128i);
+        block = _mm_xor_si128(block, round_key(cfg, 0));
+
+        for round in 1..cfg.rounds {
+            block = _mm_aesenc_si128(block, round_key(cfg, round));
+        }
+
+        block = _mm_aesenclast_si128(block, round_key(cfg, cfg.rounds));
+        _mm_storeu_si128(state.as_mut_ptr() as *mut __m128i, block);
+    }
+
+    /// Uses the equivalent inverse cipher, the inner round keys are run through InvMixColumns on the fly
+    ///
+    /// # Safety
+    /// The cpu must support the aes target feature
+    #[target_feature(enable = "aes")]
+    pub(super) unsafe fn decrypt(cfg: &AesCfg, state: &mut AesBlock) {
+        let mut block = _mm_loadu_si128(state.as_ptr() as *const __m128i);
+        block = _mm_xor_si128(block, round_key(cfg, cfg.rounds));
+
+        for round in (1..cfg.rounds).rev() {
+            block = _mm_aesdec_si128(block, _mm_aesimc_si128(round_key(cfg, round)));
+        }
+
+        block = _mm_aesdeclast_si128(block, round_key(cfg, 0));
+        _mm_storeu_si128(state.as_mut_ptr() as *mut __m128i, block);
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -525,6 +812,45 @@ mod tests {
         assert_eq!(expanded, expected);
     }
 
+    // FIPS-197 Appendix A.2, Nk = 6 words per Rcon step but Nb = 4 words per round key
+    #[test]
+    fn test_key_expansion_24byte_fips197() {
+        let key = decode("8e73b0f7 da0e6452 c810f32b 809079e5 62f8ead2 522c6b7b");
+        let (expanded, rounds) = key_expansion(&key);
+
+        assert_eq!(12, rounds);
+        assert_eq!(4 * (12 + 1), expanded.len() / 4);
+
+        let expected = [
+            (0, "8e73b0f7"),
+            (5, "522c6b7b"),
+            // First Rcon step, in the middle of round key 1
+            (6, "fe0c91f7"),
+            (7, "2402f5a5"),
+            (8, "ec12068e"),
+            (11, "5c56fec2"),
+            (12, "4db7b4bd"),
+            (13, "69b54118"),
+            (23, "113b30e6"),
+            // Rcon step coinciding with the start of round key 6
+            (24, "a25e7ed5"),
+            (29, "d19da4e1"),
+            (30, "ec1786eb"),
+            (35, "33f0b7b3"),
+            (36, "40beeb28"),
+            (47, "bc3ce7b5"),
+            // Last round key, which is only partially covered by the final Rcon step
+            (48, "e98ba06f"),
+            (49, "448c773c"),
+            (50, "8ecc7204"),
+            (51, "01002202"),
+        ];
+
+        for (i, w) in expected.iter() {
+            assert_eq!(decode(w), expanded[4 * i..4 * i + 4], "word {}", i);
+        }
+    }
+
     #[test]
     fn test_key_expansion_32byte() {
         let key_str = "00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00";
@@ -551,28 +877,13 @@ mod tests {
     }
 
     #[test]
-    fn test_key_expansion_bigger_32byte() {
-        let key_str = "00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00";
-        let expected_str = "00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 
-		00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 
-		62 63 63 63 62 63 63 63 62 63 63 63 62 63 63 63 
-		aa fb fb fb aa fb fb fb aa fb fb fb aa fb fb fb 
-		6f 6c 6c cf 0d 0f 0f ac 6f 6c 6c cf 0d 0f 0f ac 
-		7d 8d 8d 6a d7 76 76 91 7d 8d 8d 6a d7 76 76 91 
-		53 54 ed c1 5e 5b e2 6d 31 37 8e a2 3c 38 81 0e 
-		96 8a 81 c1 41 fc f7 50 3c 71 7a 3a eb 07 0c ab 
-		9e aa 8f 28 c0 f1 6d 45 f1 c6 e3 e7 cd fe 62 e9 
-		2b 31 2b df 6a cd dc 8f 56 bc a6 b5 bd bb aa 1e 
-		64 06 fd 52 a4 f7 90 17 55 31 73 f0 98 cf 11 19 
-		6d bb a9 0b 07 76 75 84 51 ca d3 31 ec 71 79 2f 
-		e7 b0 e8 9c 43 47 78 8b 16 76 0b 7b 8e b9 1a 62 
-		74 ed 0b a1 73 9b 7e 25 22 51 ad 14 ce 20 d4 3b 
-		10 f8 0a 17 53 bf 72 9c 45 c9 79 e7 cb 70 63 85 ";
+    fn test_key_bigger_32byte_rejected() {
+        let key = decode("00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00");
 
-        let (key, expected) = (decode(key_str), decode(expected_str));
-        let (expanded, _) = key_expansion(&key);
-
-        assert_eq!(expanded, expected);
+        match Aes::new(&key) {
+            Err(BlockCipherError::InvalidKeyLength { got: 35, .. }) => {}
+            _ => panic!("a 35 byte key must not be truncated"),
+        }
     }
 
     #[test]
@@ -627,6 +938,29 @@ mod tests {
         assert_eq!(expected, state);
     }
 
+    // With distinct bytes every position is traceable: row r of column c comes from column c + r
+    #[test]
+    fn test_state_layout_column_major() {
+        let original: Vec<u8> = (0..16).collect();
+        let mut state = original.clone();
+
+        shift_rows_enc(&mut state);
+        assert_eq!(vec![0, 5, 10, 15, 4, 9, 14, 3, 8, 13, 2, 7, 12, 1, 6, 11], state);
+
+        shift_rows_dec(&mut state);
+        assert_eq!(original, state);
+
+        // Mixing only combines bytes within a column, so a single set column leaves the others zero
+        let mut state = [0u8; 16];
+        state[4..8].copy_from_slice(&[0xdb, 0x13, 0x53, 0x45]);
+        mix_columns_enc(&mut state);
+        assert_eq!([0x8e, 0x4d, 0xa1, 0xbc], state[4..8]);
+        assert!(state[..4].iter().chain(&state[8..]).all(|&b| b == 0));
+
+        mix_columns_dec(&mut state);
+        assert_eq!([0xdb, 0x13, 0x53, 0x45], state[4..8]);
+    }
+
     #[test]
     fn test_mix_columns_dec() {
         let mut state = decode("bd6e7c3df2b5779e0b61216e8b10b689");
@@ -634,4 +968,50 @@ mod tests {
         mix_columns_dec(&mut state);
         assert_eq!(expected, state);
     }
+
+    // FIPS-197 appendix C vectors, run through whichever backend is selected and compared to the software path
+    #[test]
+    fn test_selected_backend_matches_software() {
+        let plaintext = decode("00112233445566778899aabbccddeeff");
+        let vectors = [
+            ("000102030405060708090a0b0c0d0e0f", "69c4e0d86a7b0430d8cdb78070b4c55a"),
+            ("000102030405060708090a0b0c0d0e0f1011121314151617", "dda97ca4864cdfe06eaf70a0ec0d7191"),
+            ("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f", "8ea2b7ca516745bfeafc49904b496089"),
+        ];
+
+        for (key, expected) in vectors {
+            let aes = Aes::new(&decode(key)).unwrap();
+
+            let mut selected = [0; AES_BLOCKSIZE];
+            selected.copy_from_slice(&plaintext);
+            let mut software = selected;
+
+            aes.encrypt(&mut selected);
+            encrypt_soft(&aes.cfg, &mut software);
+            assert_eq!(decode(expected), selected.as_ref());
+            assert_eq!(software, selected);
+
+            aes.decrypt(&mut selected);
+            decrypt_soft(&aes.cfg, &mut software);
+            assert_eq!(plaintext, selected.as_ref());
+            assert_eq!(software, selected);
+        }
+    }
+
+    // The constant time substitutions must match the tables byte for byte
+    #[test]
+    fn test_ct_matches_tables() {
+        for x in 0..=255u8 {
+            let i = x as usize;
+            assert_eq!(S_BOX[i], ct::sub_byte(x));
+            assert_eq!(S_BOX_INV[i], ct::inv_sub_byte(x));
+
+            assert_eq!(MUL2[i], ct::mul(x, 2));
+            assert_eq!(MUL3[i], ct::mul(x, 3));
+            assert_eq!(MUL9[i], ct::mul(x, 9));
+            assert_eq!(MUL11[i], ct::mul(x, 11));
+            assert_eq!(MUL13[i], ct::mul(x, 13));
+            assert_eq!(MUL14[i], ct::mul(x, 14));
+        }
+    }
 }
@@ -0,0 +1,236 @@
+use super::aes::{S_BOX as SB1, S_BOX_INV as SB3};
+use crate::errors::blockcipher::BlockCipherError;
+use crate::traits::cipher::primitive::{
+    BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+    BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+    BlockCipherPrimitiveInfo as PrimitiveInfo,
+};
+
+use crate::mem;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+const SB2: [u8; 256] = [
+    0xE2, 0x4E, 0x54, 0xFC, 0x94, 0xC2, 0x4A, 0xCC, 0x62, 0x0D, 0x6A, 0x46, 0x3C, 0x4D, 0x8B, 0xD1,
+    0x5E, 0xFA, 0x64, 0xCB, 0xB4, 0x97, 0xBE, 0x2B, 0xBC, 0x77, 0x2E, 0x03, 0xD3, 0x19, 0x59, 0xC1,
+    0x1D, 0x06, 0x41, 0x6B, 0x55, 0xF0, 0x99, 0x69, 0xEA, 0x9C, 0x18, 0xAE, 0x63, 0xDF, 0xE7, 0xBB,
+    0x00, 0x73, 0x66, 0xFB, 0x96, 0x4C, 0x85, 0xE4, 0x3A, 0x09, 0x45, 0xAA, 0x0F, 0xEE, 0x10, 0xEB,
+    0x2D, 0x7F, 0xF4, 0x29, 0xAC, 0xCF, 0xAD, 0x91, 0x8D, 0x78, 0xC8, 0x95, 0xF9, 0x2F, 0xCE, 0xCD,
+    0x08, 0x7A, 0x88, 0x38, 0x5C, 0x83, 0x2A, 0x28, 0x47, 0xDB, 0xB8, 0xC7, 0x93, 0xA4, 0x12, 0x53,
+    0xFF, 0x87, 0x0E, 0x31, 0x36, 0x21, 0x58, 0x48, 0x01, 0x8E, 0x37, 0x74, 0x32, 0xCA, 0xE9, 0xB1,
+    0xB7, 0xAB, 0x0C, 0xD7, 0xC4, 0x56, 0x42, 0x26, 0x07, 0x98, 0x60, 0xD9, 0xB6, 0xB9, 0x11, 0x40,
+    0xEC, 0x20, 0x8C, 0xBD, 0xA0, 0xC9, 0x84, 0x04, 0x49, 0x23, 0xF1, 0x4F, 0x50, 0x1F, 0x13, 0xDC,
+    0xD8, 0xC0, 0x9E, 0x57, 0xE3, 0xC3, 0x7B, 0x65, 0x3B, 0x02, 0x8F, 0x3E, 0xE8, 0x25, 0x92, 0xE5,
+    0x15, 0xDD, 0xFD, 0x17, 0xA9, 0xBF, 0xD4, 0x9A, 0x7E, 0xC5, 0x39, 0x67, 0xFE, 0x76, 0x9D, 0x43,
+    0xA7, 0xE1, 0xD0, 0xF5, 0x68, 0xF2, 0x1B, 0x34, 0x70, 0x05, 0xA3, 0x8A, 0xD5, 0x79, 0x86, 0xA8,
+    0x30, 0xC6, 0x51, 0x4B, 0x1E, 0xA6, 0x27, 0xF6, 0x35, 0xD2, 0x6E, 0x24, 0x16, 0x82, 0x5F, 0xDA,
+    0xE6, 0x75, 0xA2, 0xEF, 0x2C, 0xB2, 0x1C, 0x9F, 0x5D, 0x6F, 0x80, 0x0A, 0x72, 0x44, 0x9B, 0x6C,
+    0x90, 0x0B, 0x5B, 0x33, 0x7D, 0x5A, 0x52, 0xF3, 0x61, 0xA1, 0xF7, 0xB0, 0xD6, 0x3F, 0x7C, 0x6D,
+    0xED, 0x14, 0xE0, 0xA5, 0x3D, 0x22, 0xB3, 0xF8, 0x89, 0xDE, 0x71, 0x1A, 0xAF, 0xBA, 0xB5, 0x81,
+];
+
+const SB4: [u8; 256] = [
+    0x30, 0x68, 0x99, 0x1B, 0x87, 0xB9, 0x21, 0x78, 0x50, 0x39, 0xDB, 0xE1, 0x72, 0x09, 0x62, 0x3C,
+    0x3E, 0x7E, 0x5E, 0x8E, 0xF1, 0xA0, 0xCC, 0xA3, 0x2A, 0x1D, 0xFB, 0xB6, 0xD6, 0x20, 0xC4, 0x8D,
+    0x81, 0x65, 0xF5, 0x89, 0xCB, 0x9D, 0x77, 0xC6, 0x57, 0x43, 0x56, 0x17, 0xD4, 0x40, 0x1A, 0x4D,
+    0xC0, 0x63, 0x6C, 0xE3, 0xB7, 0xC8, 0x64, 0x6A, 0x53, 0xAA, 0x38, 0x98, 0x0C, 0xF4, 0x9B, 0xED,
+    0x7F, 0x22, 0x76, 0xAF, 0xDD, 0x3A, 0x0B, 0x58, 0x67, 0x88, 0x06, 0xC3, 0x35, 0x0D, 0x01, 0x8B,
+    0x8C, 0xC2, 0xE6, 0x5F, 0x02, 0x24, 0x75, 0x93, 0x66, 0x1E, 0xE5, 0xE2, 0x54, 0xD8, 0x10, 0xCE,
+    0x7A, 0xE8, 0x08, 0x2C, 0x12, 0x97, 0x32, 0xAB, 0xB4, 0x27, 0x0A, 0x23, 0xDF, 0xEF, 0xCA, 0xD9,
+    0xB8, 0xFA, 0xDC, 0x31, 0x6B, 0xD1, 0xAD, 0x19, 0x49, 0xBD, 0x51, 0x96, 0xEE, 0xE4, 0xA8, 0x41,
+    0xDA, 0xFF, 0xCD, 0x55, 0x86, 0x36, 0xBE, 0x61, 0x52, 0xF8, 0xBB, 0x0E, 0x82, 0x48, 0x69, 0x9A,
+    0xE0, 0x47, 0x9E, 0x5C, 0x04, 0x4B, 0x34, 0x15, 0x79, 0x26, 0xA7, 0xDE, 0x29, 0xAE, 0x92, 0xD7,
+    0x84, 0xE9, 0xD2, 0xBA, 0x5D, 0xF3, 0xC5, 0xB0, 0xBF, 0xA4, 0x3B, 0x71, 0x44, 0x46, 0x2B, 0xFC,
+    0xEB, 0x6F, 0xD5, 0xF6, 0x14, 0xFE, 0x7C, 0x70, 0x5A, 0x7D, 0xFD, 0x2F, 0x18, 0x83, 0x16, 0xA5,
+    0x91, 0x1F, 0x05, 0x95, 0x74, 0xA9, 0xC1, 0x5B, 0x4A, 0x85, 0x6D, 0x13, 0x07, 0x4F, 0x4E, 0x45,
+    0xB2, 0x0F, 0xC9, 0x1C, 0xA6, 0xBC, 0xEC, 0x73, 0x90, 0x7B, 0xCF, 0x59, 0x8F, 0xA1, 0xF9, 0x2D,
+    0xF2, 0xB1, 0x00, 0x94, 0x37, 0x9F, 0xD0, 0x2E, 0x9C, 0x6E, 0x28, 0x3F, 0x80, 0xF0, 0x3D, 0xD3,
+    0x25, 0x8A, 0xB5, 0xE7, 0x42, 0xB3, 0xC7, 0xEA, 0xF7, 0x4C, 0x11, 0x33, 0x03, 0xA2, 0xAC, 0x60,
+];
+
+/// Key schedule constants, the fractional part of 1/pi
+const C: [u128; 3] = [
+    0x517cc1b727220a94fe13abe8fa9a6ee0,
+    0x6db14acc9e21c820ff28b1d5ef5de2b0,
+    0xdb92371d2126e9700324977504e8c90e,
+];
+
+pub const ARIA_BLOCKSIZE: usize = 16;
+pub const ARIA_128_KEYLEN: usize = 16;
+pub const ARIA_192_KEYLEN: usize = 24;
+pub const ARIA_256_KEYLEN: usize = 32;
+pub type AriaBlock = [u8; ARIA_BLOCKSIZE];
+
+/// Aria Encryption and Decryption provider, as specified in RFC 5794
+#[derive(Clone)]
+pub struct Aria {
+    cfg: AriaCfg,
+}
+
+impl Aria {
+    /// Create a new Aria instance
+    ///
+    /// Fails with `BlockCipherError::InvalidKeyLength` unless the key is exactly 16, 24 or 32 bytes long
+    pub fn new(key: &[u8]) -> Result<Self, BlockCipherError> {
+        match key.len() {
+            ARIA_128_KEYLEN | ARIA_192_KEYLEN | ARIA_256_KEYLEN => Ok(Self {
+                cfg: aria_configuration(key),
+            }),
+
+            got => Err(BlockCipherError::InvalidKeyLength {
+                got,
+                min: Self::KEYLEN_MIN,
+                max: Self::KEYLEN_MAX,
+            }),
+        }
+    }
+}
+
+impl PrimitiveInfo for Aria {
+    const BLOCKSIZE: usize = ARIA_BLOCKSIZE;
+    const KEYLEN_MIN: usize = ARIA_128_KEYLEN;
+    const KEYLEN_MAX: usize = ARIA_256_KEYLEN;
+}
+
+impl PrimitiveEncryption<ARIA_BLOCKSIZE> for Aria {
+    fn encrypt(&self, block: &mut AriaBlock) {
+        crypt(block, &self.cfg.enc_keys);
+    }
+}
+
+impl PrimitiveDecryption<ARIA_BLOCKSIZE> for Aria {
+    fn decrypt(&self, block: &mut AriaBlock) {
+        crypt(block, &self.cfg.dec_keys);
+    }
+}
+
+#[derive(Clone)]
+struct AriaCfg {
+    enc_keys: Vec<AriaBlock>,
+    dec_keys: Vec<AriaBlock>,
+}
+
+/// Encryption and decryption only differ in the round keys
+fn crypt(block: &mut AriaBlock, round_keys: &[AriaBlock]) {
+    let rounds = round_keys.len() - 1;
+
+    for (i, rk) in round_keys[..rounds - 1].iter().enumerate() {
+        mem::xor_buffers_unchecked(block, rk);
+
+        if i % 2 == 0 {
+            substitution_odd(block);
+        } else {
+            substitution_even(block);
+        }
+
+        diffusion(block);
+    }
+
+    mem::xor_buffers_unchecked(block, &round_keys[rounds - 1]);
+    substitution_even(block);
+    mem::xor_buffers_unchecked(block, &round_keys[rounds]);
+}
+
+fn aria_configuration(key: &[u8]) -> AriaCfg {
+    let kl = u128::from_be_bytes(key[..16].try_into().unwrap());
+
+    let mut right = [0u8; 16];
+    right[..key.len() - 16].copy_from_slice(&key[16..]);
+    let kr = u128::from_be_bytes(right);
+
+    let (rounds, ck) = match key.len() {
+        ARIA_128_KEYLEN => (12, [C[0], C[1], C[2]]),
+        ARIA_192_KEYLEN => (14, [C[1], C[2], C[0]]),
+        _ => (16, [C[2], C[0], C[1]]),
+    };
+
+    let w0 = kl;
+    let w1 = fo(w0, ck[0]) ^ kr;
+    let w2 = fe(w1, ck[1]) ^ w0;
+    let w3 = fo(w2, ck[2]) ^ w1;
+    let w = [w0, w1, w2, w3];
+
+    // Every group of four round keys combines each word with its successor rotated by the same amount
+    let mut enc_keys = Vec::with_capacity(rounds + 1);
+    for rotation in [-19i32, -31, 61, 31, 19] {
+        for i in 0..4 {
+            let rotated = if rotation < 0 {
+                w[(i + 1) % 4].rotate_right(-rotation as u32)
+            } else {
+                w[(i + 1) % 4].rotate_left(rotation as u32)
+            };
+
+            enc_keys.push((w[i] ^ rotated).to_be_bytes());
+        }
+    }
+    enc_keys.truncate(rounds + 1);
+
+    let mut dec_keys = Vec::with_capacity(rounds + 1);
+    dec_keys.push(enc_keys[rounds]);
+    for rk in enc_keys[1..rounds].iter().rev() {
+        let mut rk = *rk;
+        diffusion(&mut rk);
+        dec_keys.push(rk);
+    }
+    dec_keys.push(enc_keys[0]);
+
+    AriaCfg { enc_keys, dec_keys }
+}
+
+/// Round function of the odd rounds
+fn fo(d: u128, rk: u128) -> u128 {
+    let mut block = (d ^ rk).to_be_bytes();
+    substitution_odd(&mut block);
+    diffusion(&mut block);
+    u128::from_be_bytes(block)
+}
+
+/// Round function of the even rounds
+fn fe(d: u128, rk: u128) -> u128 {
+    let mut block = (d ^ rk).to_be_bytes();
+    substitution_even(&mut block);
+    diffusion(&mut block);
+    u128::from_be_bytes(block)
+}
+
+/// Substitution layer of type 1
+fn substitution_odd(block: &mut AriaBlock) {
+    for chunk in block.chunks_exact_mut(4) {
+        chunk[0] = SB1[chunk[0] as usize];
+        chunk[1] = SB2[chunk[1] as usize];
+        chunk[2] = SB3[chunk[2] as usize];
+        chunk[3] = SB4[chunk[3] as usize];
+    }
+}
+
+/// Substitution layer of type 2, the inverse of type 1
+fn substitution_even(block: &mut AriaBlock) {
+    for chunk in block.chunks_exact_mut(4) {
+        chunk[0] = SB3[chunk[0] as usize];
+        chunk[1] = SB4[chunk[1] as usize];
+        chunk[2] = SB1[chunk[2] as usize];
+        chunk[3] = SB2[chunk[3] as usize];
+    }
+}
+
+/// Diffusion layer, an involutive 16x16 binary matrix
+fn diffusion(block: &mut AriaBlock) {
+    let x = *block;
+
+    block[0] = x[3] ^ x[4] ^ x[6] ^ x[8] ^ x[9] ^ x[13] ^ x[14];
+    block[1] = x[2] ^ x[5] ^ x[7] ^ x[8] ^ x[9] ^ x[12] ^ x[15];
+    block[2] = x[1] ^ x[4] ^ x[6] ^ x[10] ^ x[11] ^ x[12] ^ x[15];
+    block[3] = x[0] ^ x[5] ^ x[7] ^ x[10] ^ x[11] ^ x[13] ^ x[14];
+    block[4] = x[0] ^ x[2] ^ x[5] ^ x[8] ^ x[11] ^ x[14] ^ x[15];
+    block[5] = x[1] ^ x[3] ^ x[4] ^ x[9] ^ x[10] ^ x[14] ^ x[15];
+    block[6] = x[0] ^ x[2] ^ x[7] ^ x[9] ^ x[10] ^ x[12] ^ x[13];
+    block[7] = x[1] ^ x[3] ^ x[6] ^ x[8] ^ x[11] ^ x[12] ^ x[13];
+    block[8] = x[0] ^ x[1] ^ x[4] ^ x[7] ^ x[10] ^ x[13] ^ x[15];
+    block[9] = x[0] ^ x[1] ^ x[5] ^ x[6] ^ x[11] ^ x[12] ^ x[14];
+    block[10] = x[2] ^ x[3] ^ x[5] ^ x[6] ^ x[8] ^ x[13] ^ x[15];
+    block[11] = x[2] ^ x[3] ^ x[4] ^ x[7] ^ x[9] ^ x[12] ^ x[14];
+    block[12] = x[1] ^ x[2] ^ x[6] ^ x[7] ^ x[9] ^ x[11] ^ x[12];
+    block[13] = x[0] ^ x[3] ^ x[6] ^ x[7] ^ x[8] ^ x[10] ^ x[13];
+    block[14] = x[0] ^ x[3] ^ x[4] ^ x[5] ^ x[9] ^ x[11] ^ x[14];
+    block[15] = x[1] ^ x[2] ^ x[4] ^ x[5] ^ x[8] ^ x[10] ^ x[15];
+}
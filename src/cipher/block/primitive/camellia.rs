@@ -0,0 +1,290 @@
+use crate::errors::blockcipher::BlockCipherError;
+use crate::traits::cipher::primitive::{
+    BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+    BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+    BlockCipherPrimitiveInfo as PrimitiveInfo,
+};
+
+use core::convert::TryInto;
+
+const SBOX1: [u8; 256] = [
+    0x70, 0x82, 0x2C, 0xEC, 0xB3, 0x27, 0xC0, 0xE5, 0xE4, 0x85, 0x57, 0x35, 0xEA, 0x0C, 0xAE, 0x41,
+    0x23, 0xEF, 0x6B, 0x93, 0x45, 0x19, 0xA5, 0x21, 0xED, 0x0E, 0x4F, 0x4E, 0x1D, 0x65, 0x92, 0xBD,
+    0x86, 0xB8, 0xAF, 0x8F, 0x7C, 0xEB, 0x1F, 0xCE, 0x3E, 0x30, 0xDC, 0x5F, 0x5E, 0xC5, 0x0B, 0x1A,
+    0xA6, 0xE1, 0x39, 0xCA, 0xD5, 0x47, 0x5D, 0x3D, 0xD9, 0x01, 0x5A, 0xD6, 0x51, 0x56, 0x6C, 0x4D,
+    0x8B, 0x0D, 0x9A, 0x66, 0xFB, 0xCC, 0xB0, 0x2D, 0x74, 0x12, 0x2B, 0x20, 0xF0, 0xB1, 0x84, 0x99,
+    0xDF, 0x4C, 0xCB, 0xC2, 0x34, 0x7E, 0x76, 0x05, 0x6D, 0xB7, 0xA9, 0x31, 0xD1, 0x17, 0x04, 0xD7,
+    0x14, 0x58, 0x3A, 0x61, 0xDE, 0x1B, 0x11, 0x1C, 0x32, 0x0F, 0x9C, 0x16, 0x53, 0x18, 0xF2, 0x22,
+    0xFE, 0x44, 0xCF, 0xB2, 0xC3, 0xB5, 0x7A, 0x91, 0x24, 0x08, 0xE8, 0xA8, 0x60, 0xFC, 0x69, 0x50,
+    0xAA, 0xD0, 0xA0, 0x7D, 0xA1, 0x89, 0x62, 0x97, 0x54, 0x5B, 0x1E, 0x95, 0xE0, 0xFF, 0x64, 0xD2,
+    0x10, 0xC4, 0x00, 0x48, 0xA3, 0xF7, 0x75, 0xDB, 0x8A, 0x03, 0xE6, 0xDA, 0x09, 0x3F, 0xDD, 0x94,
+    0x87, 0x5C, 0x83, 0x02, 0xCD, 0x4A, 0x90, 0x33, 0x73, 0x67, 0xF6, 0xF3, 0x9D, 0x7F, 0xBF, 0xE2,
+    0x52, 0x9B, 0xD8, 0x26, 0xC8, 0x37, 0xC6, 0x3B, 0x81, 0x96, 0x6F, 0x4B, 0x13, 0xBE, 0x63, 0x2E,
+    0xE9, 0x79, 0xA7, 0x8C, 0x9F, 0x6E, 0xBC, 0x8E, 0x29, 0xF5, 0xF9, 0xB6, 0x2F, 0xFD, 0xB4, 0x59,
+    0x78, 0x98, 0x06, 0x6A, 0xE7, 0x46, 0x71, 0xBA, 0xD4, 0x25, 0xAB, 0x42, 0x88, 0xA2, 0x8D, 0xFA,
+    0x72, 0x07, 0xB9, 0x55, 0xF8, 0xEE, 0xAC, 0x0A, 0x36, 0x49, 0x2A, 0x68, 0x3C, 0x38, 0xF1, 0xA4,
+    0x40, 0x28, 0xD3, 0x7B, 0xBB, 0xC9, 0x43, 0xC1, 0x15, 0xE3, 0xAD, 0xF4, 0x77, 0xC7, 0x80, 0x9E,
+];
+
+const SIGMA: [u64; 6] = [
+    0xA09E667F3BCC908B,
+    0xB67AE8584CAA73B2,
+    0xC6EF372FE94F82BE,
+    0x54FF53A5F1D36F1C,
+    0x10E527FADE682D1D,
+    0xB05688C2B3E6C1FD,
+];
+
+pub const CAMELLIA_BLOCKSIZE: usize = 16;
+pub const CAMELLIA_128_KEYLEN: usize = 16;
+pub const CAMELLIA_192_KEYLEN: usize = 24;
+pub const CAMELLIA_256_KEYLEN: usize = 32;
+pub type CamelliaBlock = [u8; CAMELLIA_BLOCKSIZE];
+
+/// Camellia Encryption and Decryption provider, as specified in RFC 3713
+#[derive(Clone)]
+pub struct Camellia {
+    cfg: CamelliaCfg,
+}
+
+impl Camellia {
+    /// Create a new Camellia instance
+    ///
+    /// Fails with `BlockCipherError::InvalidKeyLength` unless the key is exactly 16, 24 or 32 bytes long
+    pub fn new(key: &[u8]) -> Result<Self, BlockCipherError> {
+        match key.len() {
+            CAMELLIA_128_KEYLEN | CAMELLIA_192_KEYLEN | CAMELLIA_256_KEYLEN => Ok(Self {
+                cfg: camellia_configuration(key),
+            }),
+
+            got => Err(BlockCipherError::InvalidKeyLength {
+                got,
+                min: Self::KEYLEN_MIN,
+                max: Self::KEYLEN_MAX,
+            }),
+        }
+    }
+}
+
+impl PrimitiveInfo for Camellia {
+    const BLOCKSIZE: usize = CAMELLIA_BLOCKSIZE;
+    const KEYLEN_MIN: usize = CAMELLIA_128_KEYLEN;
+    const KEYLEN_MAX: usize = CAMELLIA_256_KEYLEN;
+}
+
+impl PrimitiveEncryption<CAMELLIA_BLOCKSIZE> for Camellia {
+    fn encrypt(&self, block: &mut CamelliaBlock) {
+        let cfg = &self.cfg;
+        let (mut d1, mut d2) = split_block(block);
+
+        d1 ^= cfg.kw[0];
+        d2 ^= cfg.kw[1];
+
+        for i in 0..cfg.rounds / 6 {
+            if i != 0 {
+                d1 = fl(d1, cfg.ke[2 * i - 2]);
+                d2 = fl_inv(d2, cfg.ke[2 * i - 1]);
+            }
+
+            for j in 0..3 {
+                d2 ^= f(d1, cfg.k[6 * i + 2 * j]);
+                d1 ^= f(d2, cfg.k[6 * i + 2 * j + 1]);
+            }
+        }
+
+        d2 ^= cfg.kw[2];
+        d1 ^= cfg.kw[3];
+
+        join_block(block, d2, d1);
+    }
+}
+
+impl PrimitiveDecryption<CAMELLIA_BLOCKSIZE> for Camellia {
+    fn decrypt(&self, block: &mut CamelliaBlock) {
+        let cfg = &self.cfg;
+        let (mut d1, mut d2) = split_block(block);
+
+        d1 ^= cfg.kw[2];
+        d2 ^= cfg.kw[3];
+
+        for i in (0..cfg.rounds / 6).rev() {
+            for j in (0..3).rev() {
+                d2 ^= f(d1, cfg.k[6 * i + 2 * j + 1]);
+                d1 ^= f(d2, cfg.k[6 * i + 2 * j]);
+            }
+
+            if i != 0 {
+                d1 = fl(d1, cfg.ke[2 * i - 1]);
+                d2 = fl_inv(d2, cfg.ke[2 * i - 2]);
+            }
+        }
+
+        d2 ^= cfg.kw[0];
+        d1 ^= cfg.kw[1];
+
+        join_block(block, d2, d1);
+    }
+}
+
+#[derive(Clone)]
+struct CamelliaCfg {
+    kw: [u64; 4],
+    k: [u64; 24],
+    ke: [u64; 6],
+    rounds: usize,
+}
+
+fn split_block(block: &CamelliaBlock) -> (u64, u64) {
+    (
+        u64::from_be_bytes(block[..8].try_into().unwrap()),
+        u64::from_be_bytes(block[8..].try_into().unwrap()),
+    )
+}
+
+fn join_block(block: &mut CamelliaBlock, hi: u64, lo: u64) {
+    block[..8].copy_from_slice(&hi.to_be_bytes());
+    block[8..].copy_from_slice(&lo.to_be_bytes());
+}
+
+/// Returns the left and right half of x rotated left by n bits
+fn halves(x: u128, n: u32) -> (u64, u64) {
+    let x = x.rotate_left(n);
+    ((x >> 64) as u64, x as u64)
+}
+
+fn camellia_configuration(key: &[u8]) -> CamelliaCfg {
+    let kl = u128::from_be_bytes(key[..16].try_into().unwrap());
+    let kr = match key.len() {
+        CAMELLIA_128_KEYLEN => 0,
+        CAMELLIA_192_KEYLEN => {
+            let right = u64::from_be_bytes(key[16..24].try_into().unwrap());
+            ((right as u128) << 64) | (!right as u128)
+        }
+        _ => u128::from_be_bytes(key[16..32].try_into().unwrap()),
+    };
+
+    let (mut d1, mut d2) = halves(kl ^ kr, 0);
+    d2 ^= f(d1, SIGMA[0]);
+    d1 ^= f(d2, SIGMA[1]);
+    d1 ^= (kl >> 64) as u64;
+    d2 ^= kl as u64;
+    d2 ^= f(d1, SIGMA[2]);
+    d1 ^= f(d2, SIGMA[3]);
+    let ka = ((d1 as u128) << 64) | d2 as u128;
+
+    let mut cfg = CamelliaCfg {
+        kw: [0; 4],
+        k: [0; 24],
+        ke: [0; 6],
+        rounds: 0,
+    };
+
+    if key.len() == CAMELLIA_128_KEYLEN {
+        cfg.rounds = 18;
+
+        (cfg.kw[0], cfg.kw[1]) = halves(kl, 0);
+        (cfg.k[0], cfg.k[1]) = halves(ka, 0);
+        (cfg.k[2], cfg.k[3]) = halves(kl, 15);
+        (cfg.k[4], cfg.k[5]) = halves(ka, 15);
+        (cfg.ke[0], cfg.ke[1]) = halves(ka, 30);
+        (cfg.k[6], cfg.k[7]) = halves(kl, 45);
+        cfg.k[8] = halves(ka, 45).0;
+        cfg.k[9] = halves(kl, 60).1;
+        (cfg.k[10], cfg.k[11]) = halves(ka, 60);
+        (cfg.ke[2], cfg.ke[3]) = halves(kl, 77);
+        (cfg.k[12], cfg.k[13]) = halves(kl, 94);
+        (cfg.k[14], cfg.k[15]) = halves(ka, 94);
+        (cfg.k[16], cfg.k[17]) = halves(kl, 111);
+        (cfg.kw[2], cfg.kw[3]) = halves(ka, 111);
+
+        return cfg;
+    }
+
+    let (mut d1, mut d2) = halves(ka ^ kr, 0);
+    d2 ^= f(d1, SIGMA[4]);
+    d1 ^= f(d2, SIGMA[5]);
+    let kb = ((d1 as u128) << 64) | d2 as u128;
+
+    cfg.rounds = 24;
+
+    (cfg.kw[0], cfg.kw[1]) = halves(kl, 0);
+    (cfg.k[0], cfg.k[1]) = halves(kb, 0);
+    (cfg.k[2], cfg.k[3]) = halves(kr, 15);
+    (cfg.k[4], cfg.k[5]) = halves(ka, 15);
+    (cfg.ke[0], cfg.ke[1]) = halves(kr, 30);
+    (cfg.k[6], cfg.k[7]) = halves(kb, 30);
+    (cfg.k[8], cfg.k[9]) = halves(kl, 45);
+    (cfg.k[10], cfg.k[11]) = halves(ka, 45);
+    (cfg.ke[2], cfg.ke[3]) = halves(kl, 60);
+    (cfg.k[12], cfg.k[13]) = halves(kr, 60);
+    (cfg.k[14], cfg.k[15]) = halves(kb, 60);
+    (cfg.k[16], cfg.k[17]) = halves(kl, 77);
+    (cfg.ke[4], cfg.ke[5]) = halves(ka, 77);
+    (cfg.k[18], cfg.k[19]) = halves(kr, 94);
+    (cfg.k[20], cfg.k[21]) = halves(ka, 94);
+    (cfg.k[22], cfg.k[23]) = halves(kl, 111);
+    (cfg.kw[2], cfg.kw[3]) = halves(kb, 111);
+
+    cfg
+}
+
+fn sbox1(x: u8) -> u8 {
+    SBOX1[x as usize]
+}
+
+fn sbox2(x: u8) -> u8 {
+    SBOX1[x as usize].rotate_left(1)
+}
+
+fn sbox3(x: u8) -> u8 {
+    SBOX1[x as usize].rotate_left(7)
+}
+
+fn sbox4(x: u8) -> u8 {
+    SBOX1[x.rotate_left(1) as usize]
+}
+
+/// The round function, S-function followed by the P-function
+fn f(input: u64, subkey: u64) -> u64 {
+    let x = (input ^ subkey).to_be_bytes();
+
+    let t1 = sbox1(x[0]);
+    let t2 = sbox2(x[1]);
+    let t3 = sbox3(x[2]);
+    let t4 = sbox4(x[3]);
+    let t5 = sbox2(x[4]);
+    let t6 = sbox3(x[5]);
+    let t7 = sbox4(x[6]);
+    let t8 = sbox1(x[7]);
+
+    u64::from_be_bytes([
+        t1 ^ t3 ^ t4 ^ t6 ^ t7 ^ t8,
+        t1 ^ t2 ^ t4 ^ t5 ^ t7 ^ t8,
+        t1 ^ t2 ^ t3 ^ t5 ^ t6 ^ t8,
+        t2 ^ t3 ^ t4 ^ t5 ^ t6 ^ t7,
+        t1 ^ t2 ^ t6 ^ t7 ^ t8,
+        t2 ^ t3 ^ t5 ^ t7 ^ t8,
+        t3 ^ t4 ^ t5 ^ t6 ^ t8,
+        t1 ^ t4 ^ t5 ^ t6 ^ t7,
+    ])
+}
+
+fn fl(input: u64, subkey: u64) -> u64 {
+    let (mut x1, mut x2) = ((input >> 32) as u32, input as u32);
+    let (k1, k2) = ((subkey >> 32) as u32, subkey as u32);
+
+    x2 ^= (x1 & k1).rotate_left(1);
+    x1 ^= x2 | k2;
+
+    ((x1 as u64) << 32) | x2 as u64
+}
+
+fn fl_inv(input: u64, subkey: u64) -> u64 {
+    let (mut y1, mut y2) = ((input >> 32) as u32, input as u32);
+    let (k1, k2) = ((subkey >> 32) as u32, subkey as u32);
+
+    y1 ^= y2 | k2;
+    y2 ^= (y1 & k1).rotate_left(1);
+
+    ((y1 as u64) << 32) | y2 as u64
+}
@@ -0,0 +1,240 @@
+use super::feistel::feistel_rounds;
+use crate::errors::blockcipher::BlockCipherError;
+use crate::traits::cipher::primitive::{
+    BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+    BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+    BlockCipherPrimitiveInfo as PrimitiveInfo,
+};
+
+// Permutation tables list the 1-based source bit for every output bit, counted from the most significant bit
+
+const IP: [u8; 64] = [
+    58, 50, 42, 34, 26, 18, 10, 2, 60, 52, 44, 36, 28, 20, 12, 4,
+    62, 54, 46, 38, 30, 22, 14, 6, 64, 56, 48, 40, 32, 24, 16, 8,
+    57, 49, 41, 33, 25, 17, 9, 1, 59, 51, 43, 35, 27, 19, 11, 3,
+    61, 53, 45, 37, 29, 21, 13, 5, 63, 55, 47, 39, 31, 23, 15, 7,
+];
+
+const FP: [u8; 64] = [
+    40, 8, 48, 16, 56, 24, 64, 32, 39, 7, 47, 15, 55, 23, 63, 31,
+    38, 6, 46, 14, 54, 22, 62, 30, 37, 5, 45, 13, 53, 21, 61, 29,
+    36, 4, 44, 12, 52, 20, 60, 28, 35, 3, 43, 11, 51, 19, 59, 27,
+    34, 2, 42, 10, 50, 18, 58, 26, 33, 1, 41, 9, 49, 17, 57, 25,
+];
+
+const E: [u8; 48] = [
+    32, 1, 2, 3, 4, 5, 4, 5, 6, 7, 8, 9,
+    8, 9, 10, 11, 12, 13, 12, 13, 14, 15, 16, 17,
+    16, 17, 18, 19, 20, 21, 20, 21, 22, 23, 24, 25,
+    24, 25, 26, 27, 28, 29, 28, 29, 30, 31, 32, 1,
+];
+
+const P: [u8; 32] = [
+    16, 7, 20, 21, 29, 12, 28, 17, 1, 15, 23, 26, 5, 18, 31, 10,
+    2, 8, 24, 14, 32, 27, 3, 9, 19, 13, 30, 6, 22, 11, 4, 25,
+];
+
+const PC1: [u8; 56] = [
+    57, 49, 41, 33, 25, 17, 9, 1, 58, 50, 42, 34, 26, 18,
+    10, 2, 59, 51, 43, 35, 27, 19, 11, 3, 60, 52, 44, 36,
+    63, 55, 47, 39, 31, 23, 15, 7, 62, 54, 46, 38, 30, 22,
+    14, 6, 61, 53, 45, 37, 29, 21, 13, 5, 28, 20, 12, 4,
+];
+
+const PC2: [u8; 48] = [
+    14, 17, 11, 24, 1, 5, 3, 28, 15, 6, 21, 10,
+    23, 19, 12, 4, 26, 8, 16, 7, 27, 20, 13, 2,
+    41, 52, 31, 37, 47, 55, 30, 40, 51, 45, 33, 48,
+    44, 49, 39, 56, 34, 53, 46, 42, 50, 36, 29, 32,
+];
+
+const SHIFTS: [u32; 16] = [1, 1, 2, 2, 2, 2, 2, 2, 1, 2, 2, 2, 2, 2, 2, 1];
+
+const S_BOXES: [[u8; 64]; 8] = [
+    [
+        14, 4, 13, 1, 2, 15, 11, 8, 3, 10, 6, 12, 5, 9, 0, 7,
+        0, 15, 7, 4, 14, 2, 13, 1, 10, 6, 12, 11, 9, 5, 3, 8,
+        4, 1, 14, 8, 13, 6, 2, 11, 15, 12, 9, 7, 3, 10, 5, 0,
+        15, 12, 8, 2, 4, 9, 1, 7, 5, 11, 3, 14, 10, 0, 6, 13,
+    ],
+    [
+        15, 1, 8, 14, 6, 11, 3, 4, 9, 7, 2, 13, 12, 0, 5, 10,
+        3, 13, 4, 7, 15, 2, 8, 14, 12, 0, 1, 10, 6, 9, 11, 5,
+        0, 14, 7, 11, 10, 4, 13, 1, 5, 8, 12, 6, 9, 3, 2, 15,
+        13, 8, 10, 1, 3, 15, 4, 2, 11, 6, 7, 12, 0, 5, 14, 9,
+    ],
+    [
+        10, 0, 9, 14, 6, 3, 15, 5, 1, 13, 12, 7, 11, 4, 2, 8,
+        13, 7, 0, 9, 3, 4, 6, 10, 2, 8, 5, 14, 12, 11, 15, 1,
+        13, 6, 4, 9, 8, 15, 3, 0, 11, 1, 2, 12, 5, 10, 14, 7,
+        1, 10, 13, 0, 6, 9, 8, 7, 4, 15, 14, 3, 11, 5, 2, 12,
+    ],
+    [
+        7, 13, 14, 3, 0, 6, 9, 10, 1, 2, 8, 5, 11, 12, 4, 15,
+        13, 8, 11, 5, 6, 15, 0, 3, 4, 7, 2, 12, 1, 10, 14, 9,
+        10, 6, 9, 0, 12, 11, 7, 13, 15, 1, 3, 14, 5, 2, 8, 4,
+        3, 15, 0, 6, 10, 1, 13, 8, 9, 4, 5, 11, 12, 7, 2, 14,
+    ],
+    [
+        2, 12, 4, 1, 7, 10, 11, 6, 8, 5, 3, 15, 13, 0, 14, 9,
+        14, 11, 2, 12, 4, 7, 13, 1, 5, 0, 15, 10, 3, 9, 8, 6,
+        4, 2, 1, 11, 10, 13, 7, 8, 15, 9, 12, 5, 6, 3, 0, 14,
+        11, 8, 12, 7, 1, 14, 2, 13, 6, 15, 0, 9, 10, 4, 5, 3,
+    ],
+    [
+        12, 1, 10, 15, 9, 2, 6, 8, 0, 13, 3, 4, 14, 7, 5, 11,
+        10, 15, 4, 2, 7, 12, 9, 5, 6, 1, 13, 14, 0, 11, 3, 8,
+        9, 14, 15, 5, 2, 8, 12, 3, 7, 0, 4, 10, 1, 13, 11, 6,
+        4, 3, 2, 12, 9, 5, 15, 10, 11, 14, 1, 7, 6, 0, 8, 13,
+    ],
+    [
+        4, 11, 2, 14, 15, 0, 8, 13, 3, 12, 9, 7, 5, 10, 6, 1,
+        13, 0, 11, 7, 4, 9, 1, 10, 14, 3, 5, 12, 2, 15, 8, 6,
+        1, 4, 11, 13, 12, 3, 7, 14, 10, 15, 6, 8, 0, 5, 9, 2,
+        6, 11, 13, 8, 1, 4, 10, 7, 9, 5, 0, 15, 14, 2, 3, 12,
+    ],
+    [
+        13, 2, 8, 4, 6, 15, 11, 1, 10, 9, 3, 14, 5, 0, 12, 7,
+        1, 15, 13, 8, 10, 3, 7, 4, 12, 5, 6, 11, 0, 14, 9, 2,
+        7, 11, 4, 1, 9, 12, 14, 2, 0, 6, 10, 13, 15, 3, 5, 8,
+        2, 1, 14, 7, 4, 10, 8, 13, 15, 12, 9, 0, 3, 5, 6, 11,
+    ],
+];
+
+/// The four weak keys, whose 16 round keys are all equal, so encryption is its own inverse
+const WEAK_KEYS: [u64; 4] = [
+    0x0101010101010101, 0xFEFEFEFEFEFEFEFE, 0xE0E0E0E0F1F1F1F1, 0x1F1F1F1F0E0E0E0E,
+];
+
+/// The six pairs of semi-weak keys, where encryption with one key is decryption with the other
+const SEMI_WEAK_KEYS: [u64; 12] = [
+    0x01FE01FE01FE01FE, 0xFE01FE01FE01FE01,
+    0x1FE01FE00EF10EF1, 0xE01FE01FF10EF10E,
+    0x01E001E001F101F1, 0xE001E001F101F101,
+    0x1FFE1FFE0EFE0EFE, 0xFE1FFE1FFE0EFE0E,
+    0x011F011F010E010E, 0x1F011F010E010E01,
+    0xE0FEE0FEF1FEF1FE, 0xFEE0FEE0FEF1FEF1,
+];
+
+/// The lowest bit of every key byte is a parity bit that is not used by the cipher
+const PARITY_MASK: u64 = 0xFEFEFEFEFEFEFEFE;
+
+pub const DES_BLOCKSIZE: usize = 8;
+pub const DES_KEYLEN: usize = 8;
+pub type DesBlock = [u8; DES_BLOCKSIZE];
+
+const ROUNDS: usize = 16;
+
+/// Des Encryption and Decryption provider
+///
+/// Note: DES is broken by exhaustive key search and only provided for interoperability
+#[derive(Clone)]
+pub struct Des {
+    round_keys: [u64; ROUNDS],
+}
+
+impl Des {
+    /// Create a new Des instance. Parity bits are ignored and weak keys are accepted for interoperability
+    ///
+    /// Fails with `BlockCipherError::InvalidKeyLength` unless the key is exactly 8 bytes long
+    pub fn new(key: &[u8]) -> Result<Self, BlockCipherError> {
+        let key = key_to_u64(key)?;
+
+        Ok(Self {
+            round_keys: key_schedule(key),
+        })
+    }
+
+    /// Create a new Des instance, rejecting the weak and semi-weak keys regardless of their parity bits
+    ///
+    /// Fails with `BlockCipherError::InvalidKeyLength` unless the key is exactly 8 bytes long and
+    /// with `BlockCipherError::WeakKey` if the key is weak or semi-weak
+    pub fn new_checked(key: &[u8]) -> Result<Self, BlockCipherError> {
+        let value = key_to_u64(key)? & PARITY_MASK;
+
+        if WEAK_KEYS.iter().chain(SEMI_WEAK_KEYS.iter()).any(|weak| weak & PARITY_MASK == value) {
+            return Err(BlockCipherError::WeakKey);
+        }
+
+        Self::new(key)
+    }
+
+    fn crypt(&self, block: &mut DesBlock, round_key: impl Fn(usize) -> u64) {
+        let permuted = permute(u64::from_be_bytes(*block), 64, &IP);
+        let (l, r) = ((permuted >> 32) as u32, permuted as u32);
+
+        let (l, r) = feistel_rounds(l, r, ROUNDS, |round, r| feistel(r, round_key(round)));
+
+        let joined = ((l as u64) << 32) | r as u64;
+        *block = permute(joined, 64, &FP).to_be_bytes();
+    }
+}
+
+fn key_to_u64(key: &[u8]) -> Result<u64, BlockCipherError> {
+    match key.len() {
+        DES_KEYLEN => {
+            let mut bytes = [0; DES_KEYLEN];
+            bytes.copy_from_slice(key);
+            Ok(u64::from_be_bytes(bytes))
+        }
+
+        got => Err(BlockCipherError::InvalidKeyLength {
+            got,
+            min: Des::KEYLEN_MIN,
+            max: Des::KEYLEN_MAX,
+        }),
+    }
+}
+
+/// Moves the bits of the width bit wide input to the positions given by the table
+fn permute(input: u64, width: u32, table: &[u8]) -> u64 {
+    table
+        .iter()
+        .fold(0, |out, &bit| (out << 1) | ((input >> (width - bit as u32)) & 1))
+}
+
+/// Derives the 48 bit round keys from the two rotating 28 bit halves of the permuted key
+fn key_schedule(key: u64) -> [u64; ROUNDS] {
+    let permuted = permute(key, 64, &PC1);
+    let (mut c, mut d) = ((permuted >> 28) as u32, (permuted & 0x0FFFFFFF) as u32);
+    let mut round_keys = [0; ROUNDS];
+
+    for (round_key, &shift) in round_keys.iter_mut().zip(SHIFTS.iter()) {
+        c = ((c << shift) | (c >> (28 - shift))) & 0x0FFFFFFF;
+        d = ((d << shift) | (d >> (28 - shift))) & 0x0FFFFFFF;
+        *round_key = permute(((c as u64) << 28) | d as u64, 56, &PC2);
+    }
+
+    round_keys
+}
+
+fn feistel(r: u32, key: u64) -> u32 {
+    let x = permute(r as u64, 32, &E) ^ key;
+
+    let substituted = S_BOXES.iter().enumerate().fold(0u32, |out, (i, sbox)| {
+        let six = ((x >> (42 - 6 * i)) & 0x3F) as usize;
+        let row = ((six & 0x20) >> 4) | (six & 1);
+        let column = (six >> 1) & 0xF;
+        (out << 4) | sbox[row * 16 + column] as u32
+    });
+
+    permute(substituted as u64, 32, &P) as u32
+}
+
+impl PrimitiveInfo for Des {
+    const BLOCKSIZE: usize = DES_BLOCKSIZE;
+    const KEYLEN_MIN: usize = DES_KEYLEN;
+    const KEYLEN_MAX: usize = DES_KEYLEN;
+}
+
+impl PrimitiveEncryption<DES_BLOCKSIZE> for Des {
+    fn encrypt(&self, block: &mut DesBlock) {
+        self.crypt(block, |round| self.round_keys[round]);
+    }
+}
+
+impl PrimitiveDecryption<DES_BLOCKSIZE> for Des {
+    fn decrypt(&self, block: &mut DesBlock) {
+        self.crypt(block, |round| self.round_keys[ROUNDS - 1 - round]);
+    }
+}
@@ -0,0 +1,21 @@
+use core::ops::BitXor;
+
+/// Runs a balanced Feistel network over the halves l and r of a block and returns the output halves
+///
+/// Every round computes (l, r) = (r, l ^ f(round, r)) with the round index counting from 0, the swap of
+/// the last round is undone. Decryption is the same network with the round function applied in reverse
+/// order, eg. `|i, x| f(rounds - 1 - i, x)`.
+/// * 'l', 'r'  - The left and right half of the block
+/// * 'rounds'  - The number of rounds
+/// * 'f'       - The round function, receiving the round index and the right half
+pub fn feistel_rounds<H, F>(mut l: H, mut r: H, rounds: usize, mut f: F) -> (H, H)
+where
+    H: BitXor<Output = H> + Copy,
+    F: FnMut(usize, H) -> H,
+{
+    for round in 0..rounds {
+        (l, r) = (r, l ^ f(round, r));
+    }
+
+    (r, l)
+}
@@ -1 +1,7 @@
 pub mod aes;
+pub mod aria;
+pub mod blowfish;
+pub mod camellia;
+pub mod des;
+pub mod feistel;
+pub mod sm4;
@@ -0,0 +1,166 @@
+use crate::errors::blockcipher::BlockCipherError;
+use crate::traits::cipher::primitive::{
+    BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+    BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+    BlockCipherPrimitiveInfo as PrimitiveInfo,
+};
+
+use core::convert::TryInto;
+
+const S_BOX: [u8; 256] = [
+    0xD6, 0x90, 0xE9, 0xFE, 0xCC, 0xE1, 0x3D, 0xB7, 0x16, 0xB6, 0x14, 0xC2, 0x28, 0xFB, 0x2C, 0x05,
+    0x2B, 0x67, 0x9A, 0x76, 0x2A, 0xBE, 0x04, 0xC3, 0xAA, 0x44, 0x13, 0x26, 0x49, 0x86, 0x06, 0x99,
+    0x9C, 0x42, 0x50, 0xF4, 0x91, 0xEF, 0x98, 0x7A, 0x33, 0x54, 0x0B, 0x43, 0xED, 0xCF, 0xAC, 0x62,
+    0xE4, 0xB3, 0x1C, 0xA9, 0xC9, 0x08, 0xE8, 0x95, 0x80, 0xDF, 0x94, 0xFA, 0x75, 0x8F, 0x3F, 0xA6,
+    0x47, 0x07, 0xA7, 0xFC, 0xF3, 0x73, 0x17, 0xBA, 0x83, 0x59, 0x3C, 0x19, 0xE6, 0x85, 0x4F, 0xA8,
+    0x68, 0x6B, 0x81, 0xB2, 0x71, 0x64, 0xDA, 0x8B, 0xF8, 0xEB, 0x0F, 0x4B, 0x70, 0x56, 0x9D, 0x35,
+    0x1E, 0x24, 0x0E, 0x5E, 0x63, 0x58, 0xD1, 0xA2, 0x25, 0x22, 0x7C, 0x3B, 0x01, 0x21, 0x78, 0x87,
+    0xD4, 0x00, 0x46, 0x57, 0x9F, 0xD3, 0x27, 0x52, 0x4C, 0x36, 0x02, 0xE7, 0xA0, 0xC4, 0xC8, 0x9E,
+    0xEA, 0xBF, 0x8A, 0xD2, 0x40, 0xC7, 0x38, 0xB5, 0xA3, 0xF7, 0xF2, 0xCE, 0xF9, 0x61, 0x15, 0xA1,
+    0xE0, 0xAE, 0x5D, 0xA4, 0x9B, 0x34, 0x1A, 0x55, 0xAD, 0x93, 0x32, 0x30, 0xF5, 0x8C, 0xB1, 0xE3,
+    0x1D, 0xF6, 0xE2, 0x2E, 0x82, 0x66, 0xCA, 0x60, 0xC0, 0x29, 0x23, 0xAB, 0x0D, 0x53, 0x4E, 0x6F,
+    0xD5, 0xDB, 0x37, 0x45, 0xDE, 0xFD, 0x8E, 0x2F, 0x03, 0xFF, 0x6A, 0x72, 0x6D, 0x6C, 0x5B, 0x51,
+    0x8D, 0x1B, 0xAF, 0x92, 0xBB, 0xDD, 0xBC, 0x7F, 0x11, 0xD9, 0x5C, 0x41, 0x1F, 0x10, 0x5A, 0xD8,
+    0x0A, 0xC1, 0x31, 0x88, 0xA5, 0xCD, 0x7B, 0xBD, 0x2D, 0x74, 0xD0, 0x12, 0xB8, 0xE5, 0xB4, 0xB0,
+    0x89, 0x69, 0x97, 0x4A, 0x0C, 0x96, 0x77, 0x7E, 0x65, 0xB9, 0xF1, 0x09, 0xC5, 0x6E, 0xC6, 0x84,
+    0x18, 0xF0, 0x7D, 0xEC, 0x3A, 0xDC, 0x4D, 0x20, 0x79, 0xEE, 0x5F, 0x3E, 0xD7, 0xCB, 0x39, 0x48,
+];
+
+const FK: [u32; 4] = [0xA3B1BAC6, 0x56AA3350, 0x677D9197, 0xB27022DC];
+
+pub const SM4_BLOCKSIZE: usize = 16;
+pub const SM4_KEYLEN: usize = 16;
+pub type Sm4Block = [u8; SM4_BLOCKSIZE];
+
+const ROUNDS: usize = 32;
+
+/// SM4 Encryption and Decryption provider, as specified in GM/T 0002-2012
+#[derive(Clone)]
+pub struct Sm4 {
+    round_keys: [u32; ROUNDS],
+}
+
+impl Sm4 {
+    /// Create a new Sm4 instance
+    ///
+    /// Fails with `BlockCipherError::InvalidKeyLength` unless the key is exactly 16 bytes long
+    pub fn new(key: &[u8]) -> Result<Self, BlockCipherError> {
+        if key.len() != SM4_KEYLEN {
+            return Err(BlockCipherError::InvalidKeyLength {
+                got: key.len(),
+                min: Self::KEYLEN_MIN,
+                max: Self::KEYLEN_MAX,
+            });
+        }
+
+        Ok(Self {
+            round_keys: key_expansion(key),
+        })
+    }
+}
+
+impl PrimitiveInfo for Sm4 {
+    const BLOCKSIZE: usize = SM4_BLOCKSIZE;
+    const KEYLEN_MIN: usize = SM4_KEYLEN;
+    const KEYLEN_MAX: usize = SM4_KEYLEN;
+}
+
+impl PrimitiveEncryption<SM4_BLOCKSIZE> for Sm4 {
+    fn encrypt(&self, block: &mut Sm4Block) {
+        crypt(block, self.round_keys.iter());
+    }
+}
+
+impl PrimitiveDecryption<SM4_BLOCKSIZE> for Sm4 {
+    fn decrypt(&self, block: &mut Sm4Block) {
+        crypt(block, self.round_keys.iter().rev());
+    }
+}
+
+/// Runs the 32 rounds with the given round key order, followed by the reverse transformation
+fn crypt<'a, I: Iterator<Item = &'a u32>>(block: &mut Sm4Block, round_keys: I) {
+    let mut x = [0u32; 4];
+    for (word, chunk) in x.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+
+    for rk in round_keys {
+        let next = x[0] ^ t(x[1] ^ x[2] ^ x[3] ^ rk);
+        x = [x[1], x[2], x[3], next];
+    }
+
+    for (chunk, word) in block.chunks_exact_mut(4).zip(x.iter().rev()) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+}
+
+fn key_expansion(key: &[u8]) -> [u32; ROUNDS] {
+    let mut k = [0u32; 4];
+    for (i, chunk) in key.chunks_exact(4).enumerate() {
+        k[i] = u32::from_be_bytes(chunk.try_into().unwrap()) ^ FK[i];
+    }
+
+    let mut round_keys = [0u32; ROUNDS];
+    for (i, rk) in round_keys.iter_mut().enumerate() {
+        *rk = k[0] ^ t_key(k[1] ^ k[2] ^ k[3] ^ ck(i));
+        k = [k[1], k[2], k[3], *rk];
+    }
+
+    round_keys
+}
+
+/// Key schedule constant, byte j of CK_i is (4i + j) * 7 mod 256
+fn ck(i: usize) -> u32 {
+    let mut bytes = [0u8; 4];
+    for (j, byte) in bytes.iter_mut().enumerate() {
+        *byte = ((4 * i + j) * 7) as u8;
+    }
+
+    u32::from_be_bytes(bytes)
+}
+
+/// Nonlinear transformation, applies the S-box to every byte
+fn tau(a: u32) -> u32 {
+    let mut bytes = a.to_be_bytes();
+    for byte in bytes.iter_mut() {
+        *byte = S_BOX[*byte as usize];
+    }
+
+    u32::from_be_bytes(bytes)
+}
+
+/// Round transformation
+fn t(a: u32) -> u32 {
+    let b = tau(a);
+    b ^ b.rotate_left(2) ^ b.rotate_left(10) ^ b.rotate_left(18) ^ b.rotate_left(24)
+}
+
+/// Key schedule transformation
+fn t_key(a: u32) -> u32 {
+    let b = tau(a);
+    b ^ b.rotate_left(13) ^ b.rotate_left(23)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Intermediate values of the example in GM/T 0002-2012, Appendix A.1
+    #[test]
+    fn test_first_round() {
+        let key = [
+            0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10,
+        ];
+
+        let round_keys = key_expansion(&key);
+        assert_eq!(0xF12186F9, round_keys[0]);
+        assert_eq!(0x9124A012, round_keys[31]);
+
+        let x: Vec<u32> = key
+            .chunks_exact(4)
+            .map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap()))
+            .collect();
+
+        assert_eq!(0x27FAD345, x[0] ^ t(x[1] ^ x[2] ^ x[3] ^ round_keys[0]));
+    }
+}
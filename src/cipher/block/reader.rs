@@ -0,0 +1,108 @@
+use crate::errors::blockcipher::BlockCipherError;
+use crate::traits::cipher::sink::ByteSink;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io;
+
+/// Size of the chunks read from the source per refill
+#[cfg(feature = "std")]
+const CHUNK_SIZE: usize = 4096;
+
+/// How a `ReadCipher` handles an incomplete final block once its source is exhausted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Finish {
+    /// The source must be block aligned, an incomplete final block is an error
+    Exact,
+    /// An incomplete final block is processed as it is, for stream-like modes such as CTR, CFB or OFB
+    Partial,
+    /// PKCS#7 padding is added on encryption and removed on decryption
+    Padded,
+}
+
+/// A buffered cipher whose output can be taken while it is being fed, see `ReadCipher`
+pub trait PullCipher: ByteSink + Sized {
+    /// Takes the output processed so far. Output which depends on the final block, like the block
+    /// holding the padding on decryption, is kept back until `finish`.
+    fn take_processed(&mut self, finish: Finish) -> Vec<u8>;
+
+    /// Consumes the cipher, processes the final block according to finish and returns the remaining output
+    fn finish(self, finish: Finish) -> Result<Vec<u8>, BlockCipherError>;
+}
+
+/// Pull based cipher adapter
+///
+/// Reads from a source on demand, feeds it through a buffered cipher and yields the processed bytes,
+/// the counterpart to writing into a buffered cipher with io::Write. Errors of the cipher, eg. an incomplete
+/// final block or bad padding, are returned as io::Error by the read reaching the end of the source.
+#[cfg(feature = "std")]
+pub struct ReadCipher<R: io::Read, C: PullCipher> {
+    reader: R,
+    cipher: Option<C>,
+    finish: Finish,
+    pending: Vec<u8>,
+    pos: usize,
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read, C: PullCipher> ReadCipher<R, C> {
+    /// Create a new adapter reading from reader through a buffered cipher, eg.
+    /// `ReadCipher::new(file, aes.with_cbc_encryption(iv).buffered(), Finish::Padded)`
+    pub fn new(reader: R, cipher: C, finish: Finish) -> Self {
+        Self {
+            reader,
+            cipher: Some(cipher),
+            finish,
+            pending: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    /// Consumes the adapter and returns the source, discarding any unread output
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next chunk of the source into the pending output, finishing the cipher at the end of the source.
+    /// Returns false once the cipher is finished.
+    fn refill(&mut self) -> io::Result<bool> {
+        let cipher = match self.cipher.as_mut() {
+            Some(cipher) => cipher,
+            None => return Ok(false),
+        };
+
+        let mut chunk = [0; CHUNK_SIZE];
+        let read = self.reader.read(&mut chunk)?;
+
+        self.pending = if read == 0 {
+            let cipher = self.cipher.take().expect("cipher is not finished");
+            cipher.finish(self.finish)?
+        } else {
+            cipher.write_bytes(&chunk[..read])?;
+            cipher.take_processed(self.finish)
+        };
+        self.pos = 0;
+
+        Ok(true)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read, C: PullCipher> io::Read for ReadCipher<R, C> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while self.pos == self.pending.len() {
+            if !self.refill()? {
+                return Ok(0);
+            }
+        }
+
+        let n = buf.len().min(self.pending.len() - self.pos);
+        buf[..n].copy_from_slice(&self.pending[self.pos..self.pos + n]);
+        self.pos += n;
+
+        Ok(n)
+    }
+}
@@ -0,0 +1,31 @@
+pub mod syncronous;
+
+use syncronous::*;
+use crate::traits::cipher::primitive::{
+    BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+    BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+};
+
+pub trait XtsEncryptionProvider<const BLOCKSIZE: usize> {
+    type Primitive: PrimitiveEncryption<BLOCKSIZE>;
+    fn with_xts_encryption(self, tweak: Self::Primitive) -> XtsEncryption<Self::Primitive, BLOCKSIZE>;
+}
+
+impl<T: PrimitiveEncryption<B>, const B: usize> XtsEncryptionProvider<B> for T {
+    type Primitive = Self;
+    fn with_xts_encryption(self, tweak: Self::Primitive) -> XtsEncryption<Self::Primitive, B> {
+        XtsEncryption::new(self, tweak)
+    }
+}
+
+pub trait XtsDecryptionProvider<const BLOCKSIZE: usize> {
+    type Primitive: PrimitiveEncryption<BLOCKSIZE> + PrimitiveDecryption<BLOCKSIZE>;
+    fn with_xts_decryption(self, tweak: Self::Primitive) -> XtsDecryption<Self::Primitive, BLOCKSIZE>;
+}
+
+impl<T: PrimitiveEncryption<B> + PrimitiveDecryption<B>, const B: usize> XtsDecryptionProvider<B> for T {
+    type Primitive = Self;
+    fn with_xts_decryption(self, tweak: Self::Primitive) -> XtsDecryption<Self::Primitive, B> {
+        XtsDecryption::new(self, tweak)
+    }
+}
@@ -0,0 +1,188 @@
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mem;
+use crate::traits::cipher::primitive::{
+    BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+    BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+    BlockCipherRuntimeInfo as RuntimeInfo
+};
+use crate::util::gf128;
+
+/// XTS Encryption Provider
+///
+/// Tweakable XEX based mode for storage as specified in IEEE 1619, using two primitives keyed with independent keys.
+/// A data unit (eg. a disk sector) is encrypted as a whole, its length does not have to be a multiple
+/// of the blocksize since the last partial block is handled by ciphertext stealing.
+/// Only primitives with a blocksize of 128 bit are supported.
+pub struct XtsEncryption<T: PrimitiveEncryption<BLOCKSIZE>, const BLOCKSIZE: usize> {
+    primitive: T,
+    tweak: T,
+}
+
+impl<T: PrimitiveEncryption<B>, const B: usize> XtsEncryption<T, B> {
+    /// Create a new instance from the data primitive and the tweak primitive
+    ///
+    /// Panics if the blocksize of the primitives is not 16 bytes
+    pub fn new(primitive: T, tweak: T) -> Self {
+        assert_eq!(16, B, "XTS is only defined for a blocksize of 16 bytes");
+        Self { primitive, tweak }
+    }
+
+    /// Encrypts a data unit in place, using its sector number as tweak
+    pub fn encrypt_sector(&self, sector: u128, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.encrypt_with_tweak(sector_tweak(sector), data)
+    }
+
+    /// Encrypts a data unit in place with a raw tweak value
+    ///
+    /// Fails if the data unit is shorter than a single block
+    pub fn encrypt_with_tweak(&self, tweak: [u8; B], data: &mut [u8]) -> Result<(), BlockCipherError> {
+        let mut t = initial_tweak(&self.tweak, tweak, data.len())?;
+        let (full, partial) = split_blocks::<B>(data.len());
+
+        for block in data[..full * B].chunks_exact_mut(B) {
+            xex(block, &t, |b| self.primitive.encrypt(b));
+            t = double(&t);
+        }
+
+        if partial != 0 {
+            // The last full block is encrypted in place, its tail is stolen to pad the partial block
+            let (head, tail) = data[(full - 1) * B..].split_at_mut(B);
+            let mut stolen = [0; B];
+            stolen[..partial].copy_from_slice(tail);
+            stolen[partial..].copy_from_slice(&head[partial..]);
+            tail.copy_from_slice(&head[..partial]);
+
+            // The padded partial block takes the place of the last full block, using the next tweak
+            xex(&mut stolen, &t, |b| self.primitive.encrypt(b));
+            head.copy_from_slice(&stolen);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: PrimitiveEncryption<B>, const B: usize> RuntimeInfo for XtsEncryption<T, B> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
+}
+
+/// XTS Decryption Provider
+///
+/// Counterpart to `XtsEncryption`, the tweak primitive is used in encryption direction.
+pub struct XtsDecryption<T: PrimitiveEncryption<BLOCKSIZE> + PrimitiveDecryption<BLOCKSIZE>, const BLOCKSIZE: usize> {
+    primitive: T,
+    tweak: T,
+}
+
+impl<T: PrimitiveEncryption<B> + PrimitiveDecryption<B>, const B: usize> XtsDecryption<T, B> {
+    /// Create a new instance from the data primitive and the tweak primitive
+    ///
+    /// Panics if the blocksize of the primitives is not 16 bytes
+    pub fn new(primitive: T, tweak: T) -> Self {
+        assert_eq!(16, B, "XTS is only defined for a blocksize of 16 bytes");
+        Self { primitive, tweak }
+    }
+
+    /// Decrypts a data unit in place, using its sector number as tweak
+    pub fn decrypt_sector(&self, sector: u128, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.decrypt_with_tweak(sector_tweak(sector), data)
+    }
+
+    /// Decrypts a data unit in place with a raw tweak value
+    ///
+    /// Fails if the data unit is shorter than a single block
+    pub fn decrypt_with_tweak(&self, tweak: [u8; B], data: &mut [u8]) -> Result<(), BlockCipherError> {
+        let mut t = initial_tweak(&self.tweak, tweak, data.len())?;
+        let (full, partial) = split_blocks::<B>(data.len());
+
+        // With ciphertext stealing the last full block has to be decrypted with the tweak of the partial block first
+        let regular = if partial != 0 { full - 1 } else { full };
+
+        for block in data[..regular * B].chunks_exact_mut(B) {
+            xex(block, &t, |b| PrimitiveDecryption::decrypt(&self.primitive, b));
+            t = double(&t);
+        }
+
+        if partial != 0 {
+            let (head, tail) = data[regular * B..].split_at_mut(B);
+
+            let mut stolen = [0; B];
+            stolen.copy_from_slice(head);
+            xex(&mut stolen, &double(&t), |b| PrimitiveDecryption::decrypt(&self.primitive, b));
+
+            let mut last = [0; B];
+            last[..partial].copy_from_slice(tail);
+            last[partial..].copy_from_slice(&stolen[partial..]);
+            tail.copy_from_slice(&stolen[..partial]);
+
+            xex(&mut last, &t, |b| PrimitiveDecryption::decrypt(&self.primitive, b));
+            head.copy_from_slice(&last);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: PrimitiveEncryption<B> + PrimitiveDecryption<B>, const B: usize> RuntimeInfo for XtsDecryption<T, B> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
+}
+
+/// Returns the number of full blocks and the length of the trailing partial block
+fn split_blocks<const B: usize>(len: usize) -> (usize, usize) {
+    (len / B, len % B)
+}
+
+/// Encodes the sector number as little endian tweak value
+fn sector_tweak<const B: usize>(sector: u128) -> [u8; B] {
+    let mut tweak = [0; B];
+    tweak.copy_from_slice(&sector.to_le_bytes()[..B]);
+    tweak
+}
+
+/// Encrypts the tweak value with the tweak primitive, rejecting data units shorter than a block
+fn initial_tweak<T: PrimitiveEncryption<B>, const B: usize>(
+    primitive: &T,
+    mut tweak: [u8; B],
+    len: usize,
+) -> Result<[u8; B], BlockCipherError> {
+    if len < B {
+        return Err(BlockCipherError::IncompleteBlock(B - len));
+    }
+
+    primitive.encrypt(&mut tweak);
+    Ok(tweak)
+}
+
+/// XOR-Encrypt-XOR a single block with tweak t
+fn xex<const B: usize, F: FnOnce(&mut [u8; B])>(block: &mut [u8], t: &[u8; B], cipher: F) {
+    let mut buf = [0; B];
+    buf.copy_from_slice(block);
+
+    mem::xor_buffers(&mut buf, t);
+    cipher(&mut buf);
+    mem::xor_buffers(&mut buf, t);
+
+    block.copy_from_slice(&buf);
+}
+
+/// Multiplies the little endian tweak by x in GF(2^128)
+fn double<const B: usize>(t: &[u8; B]) -> [u8; B] {
+    let mut block = [0; 16];
+    block.copy_from_slice(t);
+    gf128::gf128_double_le(&mut block);
+
+    let mut doubled = [0; B];
+    doubled.copy_from_slice(&block);
+    doubled
+}
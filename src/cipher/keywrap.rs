@@ -0,0 +1,211 @@
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mem;
+use crate::traits::cipher::primitive::{
+    BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+    BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+};
+use alloc::{format, vec::Vec};
+use core::convert::{TryFrom, TryInto};
+
+pub const KW_BLOCKSIZE: usize = 16;
+pub const KW_SEMIBLOCK: usize = 8;
+pub const KW_IV: [u8; KW_SEMIBLOCK] = [0xA6; KW_SEMIBLOCK];
+pub const KWP_IV_PREFIX: [u8; 4] = [0xA6, 0x59, 0x59, 0xA6];
+
+/// AES Key Wrap provider
+///
+/// Wraps key material with a key encryption key as specified in RFC 3394, for a Primitive T with a blocksize of
+/// 128 bit eg. Aes. The key material is processed in 64 bit semiblocks over six rounds, the wrapped key is one
+/// semiblock longer and carries the integrity check value, which unwrapping verifies.
+/// Key material of any length is wrapped with padding as specified in RFC 5649 (AES-KWP).
+/// Wrapping is deterministic and needs no nonce, it is meant for keys and other high entropy data only.
+pub struct KeyWrap<T: PrimitiveEncryption<KW_BLOCKSIZE> + PrimitiveDecryption<KW_BLOCKSIZE>> {
+    primitive: T,
+}
+
+impl<T: PrimitiveEncryption<KW_BLOCKSIZE> + PrimitiveDecryption<KW_BLOCKSIZE>> KeyWrap<T> {
+    /// Create a new instance from a Cipher primitive keyed with the key encryption key
+    pub fn new(primitive: T) -> Self {
+        Self { primitive }
+    }
+
+    /// Wraps key material, which has to be a multiple of 8 bytes and at least 16 bytes long.
+    /// Returns the wrapped key, which is 8 bytes longer.
+    ///
+    /// Fails with `BlockCipherError::InvalidParameter` for any other length of key material
+    pub fn wrap_key(&self, key: &[u8]) -> Result<Vec<u8>, BlockCipherError> {
+        if key.len() < 2 * KW_SEMIBLOCK || key.len() % KW_SEMIBLOCK != 0 {
+            return Err(BlockCipherError::InvalidParameter(format!(
+                "key material must be a multiple of {} bytes and at least {} bytes long (found {})",
+                KW_SEMIBLOCK,
+                2 * KW_SEMIBLOCK,
+                key.len()
+            )));
+        }
+
+        Ok(self.wrap_semiblocks(KW_IV, key))
+    }
+
+    /// Unwraps a wrapped key and verifies its integrity check value
+    ///
+    /// Fails with `BlockCipherError::InvalidParameter` unless the wrapped key is a multiple of 8 bytes and at least
+    /// 24 bytes long, and with `BlockCipherError::AuthenticationFailed` if the integrity check value does not match
+    pub fn unwrap_key(&self, wrapped: &[u8]) -> Result<Vec<u8>, BlockCipherError> {
+        if wrapped.len() < 3 * KW_SEMIBLOCK || wrapped.len() % KW_SEMIBLOCK != 0 {
+            return Err(BlockCipherError::InvalidParameter(format!(
+                "wrapped key must be a multiple of {} bytes and at least {} bytes long (found {})",
+                KW_SEMIBLOCK,
+                3 * KW_SEMIBLOCK,
+                wrapped.len()
+            )));
+        }
+
+        let (a, mut key) = self.unwrap_semiblocks(wrapped);
+
+        if !mem::memeq_s(&a, &KW_IV) {
+            mem::zeroize(&mut key);
+            return Err(BlockCipherError::AuthenticationFailed);
+        }
+
+        Ok(key)
+    }
+
+    /// Wraps key material of any length with padding as in RFC 5649. The alternative initial value holds the
+    /// length of the key material, which is padded with zeros to a multiple of 8 bytes.
+    /// Returns the wrapped key, which is 8 to 15 bytes longer.
+    ///
+    /// Fails with `BlockCipherError::InvalidParameter` if the key material is empty or longer than 2^32 - 1 bytes
+    pub fn wrap_key_padded(&self, key: &[u8]) -> Result<Vec<u8>, BlockCipherError> {
+        let mli = match u32::try_from(key.len()) {
+            Ok(mli) if mli != 0 => mli,
+            _ => {
+                return Err(BlockCipherError::InvalidParameter(format!(
+                    "key material must be between 1 and {} bytes long (found {})",
+                    u32::MAX,
+                    key.len()
+                )))
+            }
+        };
+
+        let mut aiv = [0; KW_SEMIBLOCK];
+        aiv[..4].copy_from_slice(&KWP_IV_PREFIX);
+        aiv[4..].copy_from_slice(&mli.to_be_bytes());
+
+        let mut padded = key.to_vec();
+        padded.resize(
+            key.len() + (KW_SEMIBLOCK - key.len() % KW_SEMIBLOCK) % KW_SEMIBLOCK,
+            0,
+        );
+
+        // A single semiblock is encrypted together with the initial value as one block
+        let wrapped = if padded.len() == KW_SEMIBLOCK {
+            let mut block = [0; KW_BLOCKSIZE];
+            block[..KW_SEMIBLOCK].copy_from_slice(&aiv);
+            block[KW_SEMIBLOCK..].copy_from_slice(&padded);
+            self.primitive.encrypt(&mut block);
+            block.to_vec()
+        } else {
+            self.wrap_semiblocks(aiv, &padded)
+        };
+
+        mem::zeroize(&mut padded);
+        Ok(wrapped)
+    }
+
+    /// Unwraps a key wrapped with padding and verifies the alternative initial value,
+    /// the recovered length and the zero padding
+    ///
+    /// Fails with `BlockCipherError::InvalidParameter` unless the wrapped key is a multiple of 8 bytes and at least
+    /// 16 bytes long, and with `BlockCipherError::AuthenticationFailed` if the verification fails
+    pub fn unwrap_key_padded(&self, wrapped: &[u8]) -> Result<Vec<u8>, BlockCipherError> {
+        if wrapped.len() < KW_BLOCKSIZE || wrapped.len() % KW_SEMIBLOCK != 0 {
+            return Err(BlockCipherError::InvalidParameter(format!(
+                "wrapped key must be a multiple of {} bytes and at least {} bytes long (found {})",
+                KW_SEMIBLOCK,
+                KW_BLOCKSIZE,
+                wrapped.len()
+            )));
+        }
+
+        let (aiv, mut padded) = if wrapped.len() == KW_BLOCKSIZE {
+            let mut block = [0; KW_BLOCKSIZE];
+            block.copy_from_slice(wrapped);
+            self.primitive.decrypt(&mut block);
+
+            let mut aiv = [0; KW_SEMIBLOCK];
+            aiv.copy_from_slice(&block[..KW_SEMIBLOCK]);
+            let padded = block[KW_SEMIBLOCK..].to_vec();
+            mem::zeroize(&mut block);
+            (aiv, padded)
+        } else {
+            self.unwrap_semiblocks(wrapped)
+        };
+
+        // The length has to leave less than a semiblock of padding, which has to be all zeros
+        let mli =
+            u32::from_be_bytes(aiv[4..].try_into().expect("semiblock holds the length")) as usize;
+        let valid_length = mli <= padded.len() && mli + KW_SEMIBLOCK > padded.len();
+        let valid_padding = valid_length && padded[mli..].iter().all(|&b| b == 0);
+
+        if !mem::memeq_s(&aiv[..4], &KWP_IV_PREFIX) || !valid_padding {
+            mem::zeroize(&mut padded);
+            return Err(BlockCipherError::AuthenticationFailed);
+        }
+
+        padded.truncate(mli);
+        Ok(padded)
+    }
+
+    /// The wrapping function W, returns the initial value a followed by the wrapped semiblocks of data
+    fn wrap_semiblocks(&self, mut a: [u8; KW_SEMIBLOCK], data: &[u8]) -> Vec<u8> {
+        let mut r = data.to_vec();
+        let n = r.len() / KW_SEMIBLOCK;
+        let mut block = [0; KW_BLOCKSIZE];
+
+        for j in 0..6 {
+            for (i, semiblock) in r.chunks_exact_mut(KW_SEMIBLOCK).enumerate() {
+                block[..KW_SEMIBLOCK].copy_from_slice(&a);
+                block[KW_SEMIBLOCK..].copy_from_slice(semiblock);
+                self.primitive.encrypt(&mut block);
+
+                let t = (n * j + i + 1) as u64;
+                a.copy_from_slice(&block[..KW_SEMIBLOCK]);
+                mem::xor_buffers(&mut a, &t.to_be_bytes());
+                semiblock.copy_from_slice(&block[KW_SEMIBLOCK..]);
+            }
+        }
+
+        mem::zeroize(&mut block);
+
+        let mut wrapped = a.to_vec();
+        wrapped.append(&mut r);
+        wrapped
+    }
+
+    /// The unwrapping function W^-1, returns the recovered initial value and the unwrapped semiblocks
+    fn unwrap_semiblocks(&self, wrapped: &[u8]) -> ([u8; KW_SEMIBLOCK], Vec<u8>) {
+        let mut a = [0; KW_SEMIBLOCK];
+        a.copy_from_slice(&wrapped[..KW_SEMIBLOCK]);
+
+        let mut r = wrapped[KW_SEMIBLOCK..].to_vec();
+        let n = r.len() / KW_SEMIBLOCK;
+        let mut block = [0; KW_BLOCKSIZE];
+
+        for j in (0..6).rev() {
+            for (i, semiblock) in r.chunks_exact_mut(KW_SEMIBLOCK).enumerate().rev() {
+                let t = (n * j + i + 1) as u64;
+                mem::xor_buffers(&mut a, &t.to_be_bytes());
+
+                block[..KW_SEMIBLOCK].copy_from_slice(&a);
+                block[KW_SEMIBLOCK..].copy_from_slice(semiblock);
+                self.primitive.decrypt(&mut block);
+
+                a.copy_from_slice(&block[..KW_SEMIBLOCK]);
+                semiblock.copy_from_slice(&block[KW_SEMIBLOCK..]);
+            }
+        }
+
+        mem::zeroize(&mut block);
+        (a, r)
+    }
+}
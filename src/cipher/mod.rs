@@ -1,2 +1,5 @@
+pub mod aead;
 pub mod block;
+pub mod keywrap;
+pub mod mode;
 pub mod stream;
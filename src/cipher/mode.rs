@@ -0,0 +1,215 @@
+use crate::cipher::aead::ccm::{CCM_NONCELEN_MAX, CCM_NONCELEN_MIN};
+use crate::cipher::block::{
+    cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+    ecb::{EcbDecryptionProvider, EcbEncryptionProvider},
+};
+use crate::cipher::stream::{
+    cfb::{CfbDecryptionProvider, CfbEncryptionProvider},
+    ctr::CtrCipherProvider,
+    ofb::OfbCipherProvider,
+};
+use crate::errors::blockcipher::BlockCipherError;
+use crate::traits::cipher::{
+    block::{sealed, BlockCipherDecryption, BlockCipherEncryption, StreamMode},
+    primitive::{
+        BlockCipherPrimitiveDecryption as PrimitiveDecryption,
+        BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+    },
+    stream::{StreamCipherDecryption as StreamDecryption, StreamCipherEncryption as StreamEncryption},
+};
+use alloc::{boxed::Box, format};
+use core::convert::TryInto;
+use core::ops::RangeInclusive;
+
+/// Nonce length of GCM, other lengths are hashed into a counter block and not supported
+pub const GCM_NONCELEN: usize = 12;
+
+/// Common CCM nonce length, leaving a 3 byte length field for messages of up to 16 MiB
+const CCM_NONCELEN_DEFAULT: usize = 12;
+
+/// Modes of operation that can be selected at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Ecb,
+    Cbc,
+    Cfb,
+    Ctr,
+    Ofb,
+    Gcm,
+    Ccm,
+}
+
+impl Mode {
+    /// Returns the mode with the given name, ignoring case
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "ecb" => Some(Mode::Ecb),
+            "cbc" => Some(Mode::Cbc),
+            "cfb" => Some(Mode::Cfb),
+            "ctr" => Some(Mode::Ctr),
+            "ofb" => Some(Mode::Ofb),
+            "gcm" => Some(Mode::Gcm),
+            "ccm" => Some(Mode::Ccm),
+            _ => None,
+        }
+    }
+
+    /// Returns the IV/nonce length to use for a primitive with the given blocksize.
+    /// CCM accepts a range of lengths, for which the common choice of 12 bytes is returned, see `nonce_len_range`.
+    pub fn nonce_len(&self, block_size: usize) -> usize {
+        match self {
+            Mode::Ecb => 0,
+            Mode::Cbc | Mode::Cfb | Mode::Ctr | Mode::Ofb => block_size,
+            Mode::Gcm => GCM_NONCELEN,
+            Mode::Ccm => CCM_NONCELEN_DEFAULT,
+        }
+    }
+
+    /// Returns the range of IV/nonce lengths accepted for a primitive with the given blocksize
+    pub fn nonce_len_range(&self, block_size: usize) -> RangeInclusive<usize> {
+        match self {
+            Mode::Ccm => CCM_NONCELEN_MIN..=CCM_NONCELEN_MAX,
+            _ => {
+                let len = self.nonce_len(block_size);
+                len..=len
+            }
+        }
+    }
+
+    /// Checks whether an IV/nonce of length len may be used with this mode and a primitive with the given blocksize
+    ///
+    /// Fails with `BlockCipherError::InvalidIvLength` for modes with a fixed length and
+    /// with `BlockCipherError::InvalidParameter` if the length is outside the range accepted by CCM
+    pub fn validate_nonce_len(&self, len: usize, block_size: usize) -> Result<(), BlockCipherError> {
+        let range = self.nonce_len_range(block_size);
+
+        if range.contains(&len) {
+            return Ok(());
+        }
+
+        if range.start() == range.end() {
+            return Err(BlockCipherError::InvalidIvLength { got: len, expected: *range.start() });
+        }
+
+        Err(BlockCipherError::InvalidParameter(format!(
+            "nonce length must be between {} and {} bytes (found {})",
+            range.start(),
+            range.end(),
+            len
+        )))
+    }
+
+    /// Returns whether this is an AEAD mode, which has no block cipher interface
+    pub fn is_aead(&self) -> bool {
+        matches!(self, Mode::Gcm | Mode::Ccm)
+    }
+}
+
+/// Creates the encryption of the given mode for a primitive, the stream modes process whole blocks as well.
+/// The result may be made buffered like any other block cipher.
+///
+/// Fails with `BlockCipherError::InvalidIvLength` if the mode requires an IV and none (or one of a different length)
+/// is supplied, or if an IV is supplied for ECB. Fails with `BlockCipherError::InvalidParameter` for the AEAD modes,
+/// which are constructed directly, eg. `Ccm::new`.
+pub fn cipher_from_mode<T, const B: usize>(
+    mode: Mode,
+    primitive: T,
+    iv: Option<&[u8]>,
+) -> Result<Box<dyn BlockCipherEncryption<B>>, BlockCipherError>
+where
+    T: PrimitiveEncryption<B> + 'static,
+{
+    let iv = iv_for_mode::<B>(mode, iv)?;
+
+    Ok(match mode {
+        Mode::Ecb => Box::new(primitive.with_ecb_encryption()),
+        Mode::Cbc => Box::new(primitive.with_cbc_encryption(iv)),
+        Mode::Cfb => Box::new(StreamBlocks(primitive.with_cfb_encryption(iv))),
+        Mode::Ctr => Box::new(StreamBlocks(primitive.with_ctr(iv))),
+        Mode::Ofb => Box::new(StreamBlocks(primitive.with_ofb(iv))),
+        Mode::Gcm | Mode::Ccm => unreachable!("rejected by iv_for_mode"),
+    })
+}
+
+/// Creates the decryption of the given mode for a primitive, see `cipher_from_mode`
+pub fn decipher_from_mode<T, const B: usize>(
+    mode: Mode,
+    primitive: T,
+    iv: Option<&[u8]>,
+) -> Result<Box<dyn BlockCipherDecryption<B>>, BlockCipherError>
+where
+    T: PrimitiveEncryption<B> + PrimitiveDecryption<B> + 'static,
+{
+    let iv = iv_for_mode::<B>(mode, iv)?;
+
+    Ok(match mode {
+        Mode::Ecb => Box::new(primitive.with_ecb_decryption()),
+        Mode::Cbc => Box::new(primitive.with_cbc_decryption(iv)),
+        Mode::Cfb => Box::new(StreamBlocks(primitive.with_cfb_decryption(iv))),
+        Mode::Ctr => Box::new(StreamBlocks(primitive.with_ctr(iv))),
+        Mode::Ofb => Box::new(StreamBlocks(primitive.with_ofb(iv))),
+        Mode::Gcm | Mode::Ccm => unreachable!("rejected by iv_for_mode"),
+    })
+}
+
+/// Validates the IV for the mode, returns an all zero IV for modes that do not use one.
+/// Fails for the AEAD modes, which have no block cipher interface.
+fn iv_for_mode<const B: usize>(mode: Mode, iv: Option<&[u8]>) -> Result<[u8; B], BlockCipherError> {
+    if mode.is_aead() {
+        return Err(BlockCipherError::InvalidParameter(format!(
+            "{:?} is an AEAD mode without a block cipher interface",
+            mode
+        )));
+    }
+
+    let iv = iv.unwrap_or_default();
+    mode.validate_nonce_len(iv.len(), B)?;
+
+    Ok(iv.try_into().unwrap_or([0; B]))
+}
+
+/// Adapts a stream mode, eg. CTR, CFB or OFB, to the block interface so it composes with the buffered ciphers.
+/// A partial final block is processed as well, see `BufferedCipherEncryption::finalize_partial`.
+pub struct StreamBlocks<S>(S);
+
+impl<S> StreamBlocks<S> {
+    pub fn new(stream: S) -> Self {
+        Self(stream)
+    }
+
+    /// Consumes the adapter and returns the stream mode
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S> sealed::Sealed for StreamBlocks<S> {}
+impl<S> StreamMode for StreamBlocks<S> {}
+
+impl<S: StreamEncryption, const B: usize> BlockCipherEncryption<B> for StreamBlocks<S> {
+    fn encrypt(&mut self, data: &mut [u8; B]) {
+        self.0.encrypt(data);
+    }
+
+    fn try_encrypt(&mut self, data: &mut [u8; B]) -> Result<(), BlockCipherError> {
+        self.0.try_encrypt(data)
+    }
+
+    fn encrypt_partial(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.0.try_encrypt(data)
+    }
+}
+
+impl<S: StreamDecryption, const B: usize> BlockCipherDecryption<B> for StreamBlocks<S> {
+    fn decrypt(&mut self, data: &mut [u8; B]) {
+        self.0.decrypt(data);
+    }
+
+    fn try_decrypt(&mut self, data: &mut [u8; B]) -> Result<(), BlockCipherError> {
+        self.0.try_decrypt(data)
+    }
+
+    fn decrypt_partial(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.0.try_decrypt(data)
+    }
+}
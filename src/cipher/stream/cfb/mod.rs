@@ -1,7 +1,6 @@
 pub mod syncronous;
 pub mod threaded;
 use syncronous::*;
-use threaded::*;
 use crate::traits::cipher::primitive::BlockCipherPrimitiveEncryption as PrimitiveEncryption;
 
 pub trait CfbEncryptionProvider<const BLOCKSIZE: usize> {
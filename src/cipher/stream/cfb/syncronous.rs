@@ -3,9 +3,14 @@ use crate::traits::cipher::{
         StreamCipherEncryption as StreamEncryption,
         StreamCipherDecryption as StreamDecryption
     },
-    primitive::BlockCipherPrimitiveEncryption as PrimitiveEncryption
+    primitive::{
+        BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+        BlockCipherRuntimeInfo as RuntimeInfo
+    }
 };
+use crate::errors::blockcipher::BlockCipherError;
 use crate::mem;
+use core::convert::Infallible;
 
 pub struct CfbEncryption<const BLOCKSIZE: usize, T: PrimitiveEncryption<BLOCKSIZE>> {
     primitive: T,
@@ -18,26 +23,54 @@ impl<const B: usize, T: PrimitiveEncryption<B>> CfbEncryption<B,T> {
 
         Self { primitive, iv, pos: B }
     }
-}
 
-impl<const B: usize, T: PrimitiveEncryption<B>> StreamEncryption for CfbEncryption<B,T> {
-    fn encrypt(&mut self, data: &mut [u8]) {
+    /// Encrypts data, generating the next keystream block with encrypt_block.
+    /// Fails at a block boundary with the chaining state untouched.
+    fn process<E>(&mut self, data: &mut [u8], mut encrypt_block: impl FnMut(&T, &mut [u8; B]) -> Result<(), E>) -> Result<(), E> {
 
         let mut encrypted = 0;
 
         while encrypted < data.len() {
             if self.pos == B {
-                self.primitive.encrypt(&mut self.iv);
+                let mut block = self.iv;
+                encrypt_block(&self.primitive, &mut block)?;
+                self.iv = block;
                 self.pos = 0;
             }
 
             let op_slice = &mut self.iv[self.pos..];
-            let xored = mem::xor_buffers(&mut data[encrypted..], op_slice);
-            op_slice.copy_from_slice(&data[encrypted..encrypted+xored]);
+            let xored = mem::xor_slices(&mut data[encrypted..], op_slice);
+            // The data may end before the block does
+            op_slice[..xored].copy_from_slice(&data[encrypted..encrypted+xored]);
 
             encrypted += xored;
             self.pos += xored;
         }
+
+        Ok(())
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> StreamEncryption for CfbEncryption<B,T> {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        self.process(data, |primitive, block| {
+            primitive.encrypt(block);
+            Ok::<(), Infallible>(())
+        }).unwrap_or_else(|e| match e {});
+    }
+
+    fn try_encrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.process(data, |primitive, block| primitive.try_encrypt_block(block))
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> RuntimeInfo for CfbEncryption<B,T> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
     }
 }
 
@@ -52,28 +85,55 @@ impl<const B: usize, T: PrimitiveEncryption<B>> CfbDecryption<B,T> {
 
         Self { primitive, iv, pos: B }
     }
-}
 
-impl<const B: usize, T: PrimitiveEncryption<B>> StreamDecryption for CfbDecryption<B,T> {
-    fn decrypt(&mut self, data: &mut [u8]) {
+    /// Decrypts data, generating the next keystream block with encrypt_block.
+    /// Fails at a block boundary with the chaining state untouched.
+    fn process<E>(&mut self, data: &mut [u8], mut encrypt_block: impl FnMut(&T, &mut [u8; B]) -> Result<(), E>) -> Result<(), E> {
 
         let mut decrypted = 0;
 
         while decrypted < data.len() {
             if self.pos == B {
-                self.primitive.encrypt(&mut self.iv);
+                let mut block = self.iv;
+                encrypt_block(&self.primitive, &mut block)?;
+                self.iv = block;
                 self.pos = 0;
             }
 
-            let min = std::cmp::min(data.len() - decrypted, self.iv.len() - self.pos);
+            let min = core::cmp::min(data.len() - decrypted, self.iv.len() - self.pos);
             let op_slice = &mut self.iv[self.pos..self.pos+min];
             let dec_slice = &mut data[decrypted..decrypted+min];
 
             dec_slice.swap_with_slice(op_slice);
-            mem::xor_buffers(dec_slice, op_slice);
+            mem::xor_slices(dec_slice, op_slice);
 
             self.pos += min;
             decrypted += min;
         }
+
+        Ok(())
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> StreamDecryption for CfbDecryption<B,T> {
+    fn decrypt(&mut self, data: &mut [u8]) {
+        self.process(data, |primitive, block| {
+            primitive.encrypt(block);
+            Ok::<(), Infallible>(())
+        }).unwrap_or_else(|e| match e {});
     }
-}
\ No newline at end of file
+
+    fn try_decrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.process(data, |primitive, block| primitive.try_encrypt_block(block))
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> RuntimeInfo for CfbDecryption<B,T> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
+}
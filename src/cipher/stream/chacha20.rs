@@ -0,0 +1,147 @@
+use super::keystream::Keystream;
+use crate::traits::cipher::stream::{
+    StreamCipherDecryption as StreamDecryption,
+    StreamCipherEncryption as StreamEncryption,
+};
+
+pub const CHACHA20_KEYLEN: usize = 32;
+pub const CHACHA20_NONCELEN: usize = 12;
+pub const CHACHA20_BLOCKSIZE: usize = 64;
+pub const HCHACHA20_NONCELEN: usize = 16;
+
+/// "expand 32-byte k"
+const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+/// ChaCha20 Encryption and Decryption provider
+///
+/// Stream cipher with a 32 byte key, a 96 bit nonce and a 32 bit block counter as specified in RFC 8439.
+pub struct ChaCha20 {
+    state: [u32; 16],
+    keystream: Keystream<CHACHA20_BLOCKSIZE>,
+}
+
+impl ChaCha20 {
+    /// Create a new instance from a key, a nonce and the counter of the first keystream block
+    pub fn new(key: &[u8; CHACHA20_KEYLEN], nonce: &[u8; CHACHA20_NONCELEN], counter: u32) -> Self {
+        let mut state = [0; 16];
+        state[..4].copy_from_slice(&CONSTANTS);
+
+        for (word, bytes) in state[4..12].iter_mut().zip(key.chunks_exact(4)) {
+            *word = le32(bytes);
+        }
+
+        state[12] = counter;
+
+        for (word, bytes) in state[13..].iter_mut().zip(nonce.chunks_exact(4)) {
+            *word = le32(bytes);
+        }
+
+        Self {
+            state,
+            keystream: Keystream::new(),
+        }
+    }
+
+    /// Returns the next keystream block and advances the block counter
+    pub(crate) fn next_block(&mut self) -> [u8; CHACHA20_BLOCKSIZE] {
+        next_block(&mut self.state)
+    }
+
+    fn apply(&mut self, data: &mut [u8]) {
+        let state = &mut self.state;
+        self.keystream.apply(data, || next_block(state));
+    }
+}
+
+impl StreamEncryption for ChaCha20 {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        self.apply(data)
+    }
+}
+
+impl StreamDecryption for ChaCha20 {
+    fn decrypt(&mut self, data: &mut [u8]) {
+        self.apply(data)
+    }
+}
+
+fn next_block(state: &mut [u32; 16]) -> [u8; CHACHA20_BLOCKSIZE] {
+    let block = block(state);
+    state[12] = state[12].wrapping_add(1);
+    block
+}
+
+/// Computes a keystream block of the given state, running 20 rounds
+fn block(state: &[u32; 16]) -> [u8; CHACHA20_BLOCKSIZE] {
+    let mut working = *state;
+    rounds(&mut working);
+
+    let mut out = [0; CHACHA20_BLOCKSIZE];
+
+    for (i, (w, s)) in working.iter().zip(state.iter()).enumerate() {
+        out[4 * i..4 * i + 4].copy_from_slice(&w.wrapping_add(*s).to_le_bytes());
+    }
+
+    out
+}
+
+/// HChaCha20 as specified in draft-irtf-cfrg-xchacha, derives a subkey from a key and a 128 bit nonce.
+/// The ChaCha20 rounds are run without the final addition of the input,
+/// the first and last row of the state form the subkey.
+pub fn hchacha20(key: &[u8; CHACHA20_KEYLEN], nonce: &[u8; HCHACHA20_NONCELEN]) -> [u8; CHACHA20_KEYLEN] {
+    let mut state = [0; 16];
+    state[..4].copy_from_slice(&CONSTANTS);
+
+    for (word, bytes) in state[4..12].iter_mut().zip(key.chunks_exact(4)) {
+        *word = le32(bytes);
+    }
+
+    for (word, bytes) in state[12..].iter_mut().zip(nonce.chunks_exact(4)) {
+        *word = le32(bytes);
+    }
+
+    rounds(&mut state);
+
+    let mut subkey = [0; CHACHA20_KEYLEN];
+
+    for (i, w) in state[..4].iter().chain(state[12..].iter()).enumerate() {
+        subkey[4 * i..4 * i + 4].copy_from_slice(&w.to_le_bytes());
+    }
+
+    subkey
+}
+
+/// The 20 ChaCha rounds as 10 double rounds
+fn rounds(working: &mut [u32; 16]) {
+    for _ in 0..10 {
+        // Column rounds
+        quarter_round(working, 0, 4, 8, 12);
+        quarter_round(working, 1, 5, 9, 13);
+        quarter_round(working, 2, 6, 10, 14);
+        quarter_round(working, 3, 7, 11, 15);
+
+        // Diagonal rounds
+        quarter_round(working, 0, 5, 10, 15);
+        quarter_round(working, 1, 6, 11, 12);
+        quarter_round(working, 2, 7, 8, 13);
+        quarter_round(working, 3, 4, 9, 14);
+    }
+}
+
+fn quarter_round(s: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    s[a] = s[a].wrapping_add(s[b]);
+    s[d] = (s[d] ^ s[a]).rotate_left(16);
+
+    s[c] = s[c].wrapping_add(s[d]);
+    s[b] = (s[b] ^ s[c]).rotate_left(12);
+
+    s[a] = s[a].wrapping_add(s[b]);
+    s[d] = (s[d] ^ s[a]).rotate_left(8);
+
+    s[c] = s[c].wrapping_add(s[d]);
+    s[b] = (s[b] ^ s[c]).rotate_left(7);
+}
+
+fn le32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
@@ -0,0 +1,73 @@
+use super::syncronous::{CounterWidth, CtrState};
+use crate::traits::cipher::{
+    stream::{
+        StreamCipherEncryption as StreamEncryption,
+        StreamCipherDecryption as StreamDecryption
+    },
+    primitive::BlockCipherPrimitiveEncryption as PrimitiveEncryption
+};
+use crate::errors::blockcipher::BlockCipherError;
+
+/// Two independent CTR streams sharing a single primitive
+///
+/// Useful for protocols that encrypt two logical channels with the same key but separate IVs,
+/// without running the key schedule twice.
+pub struct DualChannelCtr<const BLOCKSIZE: usize, T: PrimitiveEncryption<BLOCKSIZE>> {
+    primitive: T,
+    a: CtrState<BLOCKSIZE>,
+    b: CtrState<BLOCKSIZE>
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> DualChannelCtr<B,T> {
+    /// Create a new instance from a primitive and the initial counter blocks of both channels
+    pub fn new(primitive: T, iv_a: [u8; B], iv_b: [u8; B]) -> Self {
+
+        Self { primitive, a: CtrState::new(iv_a), b: CtrState::new(iv_b) }
+    }
+
+    /// Only increments the low bits of the counter blocks of both channels, the remaining bytes of the IVs stay fixed
+    pub fn with_counter_width(mut self, width: CounterWidth) -> Self {
+        self.a.set_width(width);
+        self.b.set_width(width);
+        self
+    }
+
+    /// Returns a handle to channel A
+    pub fn channel_a(&mut self) -> CtrChannel<'_, B, T> {
+        CtrChannel { primitive: &self.primitive, state: &mut self.a }
+    }
+
+    /// Returns a handle to channel B
+    pub fn channel_b(&mut self) -> CtrChannel<'_, B, T> {
+        CtrChannel { primitive: &self.primitive, state: &mut self.b }
+    }
+}
+
+/// Handle to a single channel of a DualChannelCtr
+pub struct CtrChannel<'a, const BLOCKSIZE: usize, T: PrimitiveEncryption<BLOCKSIZE>> {
+    primitive: &'a T,
+    state: &'a mut CtrState<BLOCKSIZE>
+}
+
+impl<'a, const B: usize, T: PrimitiveEncryption<B>> StreamEncryption for CtrChannel<'a, B, T> {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        self.state.apply(self.primitive, data)
+    }
+
+    /// Encrypts data, failing with `BlockCipherError::CounterExhausted` and leaving data untouched
+    /// if a restricted counter would wrap around within it, or with the error of a failing primitive
+    fn try_encrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.state.try_apply(self.primitive, data)
+    }
+}
+
+impl<'a, const B: usize, T: PrimitiveEncryption<B>> StreamDecryption for CtrChannel<'a, B, T> {
+    fn decrypt(&mut self, data: &mut [u8]) {
+        self.state.apply(self.primitive, data)
+    }
+
+    /// Decrypts data, see `try_encrypt`
+    fn try_decrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.state.try_apply(self.primitive, data)
+    }
+}
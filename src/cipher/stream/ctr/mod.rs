@@ -0,0 +1,51 @@
+pub mod dual;
+#[cfg(feature = "std")]
+pub mod parallel;
+pub mod syncronous;
+use dual::*;
+#[cfg(feature = "std")]
+use parallel::*;
+use syncronous::*;
+use crate::traits::cipher::primitive::BlockCipherPrimitiveEncryption as PrimitiveEncryption;
+
+pub trait CtrCipherProvider<const BLOCKSIZE: usize> {
+    type Cipher: PrimitiveEncryption<BLOCKSIZE>;
+    fn with_ctr(self, iv: [u8; BLOCKSIZE]) -> CtrCipher<BLOCKSIZE, Self::Cipher>;
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> CtrCipherProvider<B> for T {
+    type Cipher = Self;
+    fn with_ctr(self, iv: [u8; B]) -> CtrCipher<B, Self::Cipher> {
+        CtrCipher::new(self, iv)
+    }
+}
+
+pub trait DualChannelCtrProvider<const BLOCKSIZE: usize> {
+    type Cipher: PrimitiveEncryption<BLOCKSIZE>;
+    fn with_dual_channel_ctr(
+        self,
+        iv_a: [u8; BLOCKSIZE],
+        iv_b: [u8; BLOCKSIZE],
+    ) -> DualChannelCtr<BLOCKSIZE, Self::Cipher>;
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> DualChannelCtrProvider<B> for T {
+    type Cipher = Self;
+    fn with_dual_channel_ctr(self, iv_a: [u8; B], iv_b: [u8; B]) -> DualChannelCtr<B, Self::Cipher> {
+        DualChannelCtr::new(self, iv_a, iv_b)
+    }
+}
+
+#[cfg(feature = "std")]
+pub trait ParallelCtrProvider<const BLOCKSIZE: usize> {
+    type Cipher: PrimitiveEncryption<BLOCKSIZE> + Clone + Send;
+    fn with_parallel_ctr(self, iv: [u8; BLOCKSIZE]) -> ParallelCtr<BLOCKSIZE, Self::Cipher>;
+}
+
+#[cfg(feature = "std")]
+impl<const B: usize, T: PrimitiveEncryption<B> + Clone + Send> ParallelCtrProvider<B> for T {
+    type Cipher = Self;
+    fn with_parallel_ctr(self, iv: [u8; B]) -> ParallelCtr<B, Self::Cipher> {
+        ParallelCtr::new(self, iv)
+    }
+}
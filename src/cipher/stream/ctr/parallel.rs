@@ -0,0 +1,150 @@
+use super::syncronous::{CounterWidth, CtrState};
+use crate::traits::cipher::{
+    stream::{
+        StreamCipherEncryption as StreamEncryption,
+        StreamCipherDecryption as StreamDecryption
+    },
+    primitive::BlockCipherPrimitiveEncryption as PrimitiveEncryption
+};
+use crate::errors::blockcipher::BlockCipherError;
+use core::convert::Infallible;
+use std::thread;
+
+/// CTR Encryption and Decryption spread across multiple threads
+///
+/// Since every keystream block only depends on its counter value, the input is split into
+/// one chunk per thread and each chunk is processed independently:
+/// * Any unused keystream of a previous call is applied first, so that every chunk starts at a block boundary
+/// * Each chunk spans `ceil(blocks / threads)` blocks, only the last chunk may be shorter or end in a partial block
+/// * Chunk i starts at the counter block `counter + i * ceil(blocks / threads)`
+///
+/// Every thread works on its own clone of the primitive, the output is identical to `CtrCipher`.
+pub struct ParallelCtr<const BLOCKSIZE: usize, T: PrimitiveEncryption<BLOCKSIZE> + Clone + Send> {
+    primitive: T,
+    state: CtrState<BLOCKSIZE>,
+    threads: usize
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B> + Clone + Send> ParallelCtr<B,T> {
+    /// Create a new instance from a primitive and the initial counter block,
+    /// using as many threads as the system reports available parallelism
+    pub fn new(primitive: T, iv: [u8; B]) -> Self {
+        let threads = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+
+        Self { primitive, state: CtrState::new(iv), threads }
+    }
+
+    /// Sets the number of worker threads, at least one thread is always used
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = core::cmp::max(threads, 1);
+        self
+    }
+
+    /// Only increments the low bits of the counter block, the remaining bytes of the IV stay fixed
+    pub fn with_counter_width(mut self, width: CounterWidth) -> Self {
+        self.state.set_width(width);
+        self
+    }
+
+    /// Returns the counter value that will be used for the next keystream block.
+    /// For blocksizes above 16 bytes only the last 16 bytes of the counter block are considered.
+    pub fn counter(&self) -> u128 {
+        self.state.counter()
+    }
+
+    /// Fails without touching data if a restricted counter would wrap around within data.
+    /// If the primitive fails, data is left partly processed and the stream does not advance past the leading keystream.
+    fn try_apply(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.state.check(data.len())?;
+        self.process(data, |state, primitive, chunk| state.try_apply(primitive, chunk))
+    }
+
+    /// Panics if a restricted counter would wrap around within data
+    fn apply(&mut self, data: &mut [u8]) {
+        if let Err(e) = self.state.check(data.len()) {
+            panic!("{}", e);
+        }
+
+        self.process(data, |state, primitive, chunk| {
+            state.apply(primitive, chunk);
+            Ok::<(), Infallible>(())
+        }).unwrap_or_else(|e| match e {});
+    }
+
+    /// Splits data into chunks and runs apply_chunk for each of them on its own thread
+    fn process<E: Send>(
+        &mut self,
+        data: &mut [u8],
+        apply_chunk: impl Fn(&mut CtrState<B>, &T, &mut [u8]) -> Result<(), E> + Sync,
+    ) -> Result<(), E> {
+        // Use up the current keystream block, so the rest of data starts at a block boundary
+        let leading = core::cmp::min(self.state.remaining(), data.len());
+        let (head, data) = data.split_at_mut(leading);
+        apply_chunk(&mut self.state, &self.primitive, head)?;
+
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let blocks = data.len().div_ceil(B);
+        let chunk_blocks = blocks.div_ceil(self.threads);
+
+        let primitive = &self.primitive;
+        let state = &self.state;
+        let apply_chunk = &apply_chunk;
+
+        // The state of the last chunk continues the stream
+        let results: Vec<_> = thread::scope(|scope| {
+            let workers: Vec<_> = data
+                .chunks_mut(chunk_blocks * B)
+                .enumerate()
+                .map(|(i, chunk)| {
+                    let primitive = primitive.clone();
+                    let mut state = state.clone();
+                    state.skip_blocks(i * chunk_blocks);
+
+                    scope.spawn(move || apply_chunk(&mut state, &primitive, chunk).map(|()| state))
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("ctr worker thread panicked"))
+                .collect()
+        });
+
+        let mut last = None;
+        for result in results {
+            last = Some(result?);
+        }
+
+        if let Some(last) = last {
+            self.state = last;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B> + Clone + Send> StreamEncryption for ParallelCtr<B,T> {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        self.apply(data)
+    }
+
+    /// Encrypts data, failing with `BlockCipherError::CounterExhausted` and leaving data untouched
+    /// if a restricted counter would wrap around within it, or with the error of a failing primitive
+    fn try_encrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.try_apply(data)
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B> + Clone + Send> StreamDecryption for ParallelCtr<B,T> {
+    fn decrypt(&mut self, data: &mut [u8]) {
+        self.apply(data)
+    }
+
+    /// Decrypts data, see `try_encrypt`
+    fn try_decrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.try_apply(data)
+    }
+}
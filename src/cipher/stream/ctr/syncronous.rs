@@ -0,0 +1,297 @@
+use crate::traits::cipher::{
+    stream::{
+        StreamCipherEncryption as StreamEncryption,
+        StreamCipherDecryption as StreamDecryption
+    },
+    primitive::{
+        BlockCipherPrimitiveEncryption as PrimitiveEncryption,
+        BlockCipherRuntimeInfo as RuntimeInfo
+    }
+};
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mem;
+use alloc::format;
+use core::convert::Infallible;
+
+/// Part of the counter block that is incremented, the bytes in front of it stay fixed eg. as a nonce
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CounterWidth {
+    /// The last 4 bytes form a 32 bit big endian counter
+    Bits32,
+    /// The last 8 bytes form a 64 bit big endian counter
+    Bits64,
+    /// The whole counter block is a big endian counter, which wraps around to zero after its maximum
+    Full,
+}
+
+impl CounterWidth {
+    fn bytes(&self, blocksize: usize) -> usize {
+        match self {
+            CounterWidth::Bits32 => core::cmp::min(blocksize, 4),
+            CounterWidth::Bits64 => core::cmp::min(blocksize, 8),
+            CounterWidth::Full => blocksize,
+        }
+    }
+}
+
+/// Counter and keystream state of a CTR stream, independent of the primitive
+#[derive(Clone)]
+pub(crate) struct CtrState<const BLOCKSIZE: usize> {
+    counter: [u8; BLOCKSIZE],
+    keystream: [u8; BLOCKSIZE],
+    pos: usize,
+    width: usize,
+    wraps: bool,
+    exhausted: bool
+}
+
+impl<const B: usize> CtrState<B> {
+    pub(crate) fn new(iv: [u8; B]) -> Self {
+        Self { counter: iv, keystream: [0; B], pos: B, width: B, wraps: true, exhausted: false }
+    }
+
+    /// Restricts the increment to the low order bytes of the counter block
+    pub(crate) fn set_width(&mut self, width: CounterWidth) {
+        self.width = width.bytes(B);
+        self.wraps = width == CounterWidth::Full;
+    }
+
+    /// Restarts at a new initial counter block, keeping the counter width
+    pub(crate) fn reset(&mut self, iv: [u8; B]) {
+        *self = Self { width: self.width, wraps: self.wraps, ..Self::new(iv) };
+    }
+
+    /// Returns the counter of the next keystream block, read from up to the last 16 bytes of the counter block
+    pub(crate) fn counter(&self) -> u128 {
+        let width = core::cmp::min(B, 16);
+
+        self.counter[B - width..]
+            .iter()
+            .fold(0, |acc, byte| (acc << 8) | *byte as u128)
+    }
+
+    /// Overrides the incremented part of the counter block, up to its last 16 bytes, and discards any unused keystream.
+    /// Fails if the counter does not fit into the counter width.
+    pub(crate) fn set_counter(&mut self, counter: u128) -> Result<(), BlockCipherError> {
+        let width = core::cmp::min(self.width, 16);
+
+        if width < 16 && counter >> (8 * width) != 0 {
+            return Err(BlockCipherError::InvalidParameter(format!(
+                "counter {:#x} does not fit into {} bytes",
+                counter, width
+            )));
+        }
+
+        let bytes = counter.to_be_bytes();
+        self.counter[B - width..].copy_from_slice(&bytes[16 - width..]);
+        self.pos = B;
+        self.exhausted = false;
+        Ok(())
+    }
+
+    /// Returns the number of unused keystream bytes of the current block
+    pub(crate) fn remaining(&self) -> usize {
+        B - self.pos
+    }
+
+    /// Advances the counter block by n blocks and discards any unused keystream.
+    /// Moving past the end of the counter range exhausts the stream.
+    #[cfg(feature = "std")]
+    pub(crate) fn skip_blocks(&mut self, n: usize) {
+        let mut carry = n as u128;
+
+        for byte in self.counter[B - self.width..].iter_mut().rev() {
+            if carry == 0 {
+                break;
+            }
+
+            let sum = *byte as u128 + (carry & 0xFF);
+            *byte = sum as u8;
+            carry = (carry >> 8) + (sum >> 8);
+        }
+
+        self.exhausted |= carry != 0;
+        self.pos = B;
+    }
+
+    /// Fails with `BlockCipherError::CounterExhausted` if a restricted counter would wrap before len bytes of keystream
+    pub(crate) fn check(&self, len: usize) -> Result<(), BlockCipherError> {
+        if self.wraps {
+            return Ok(());
+        }
+
+        let needed = len.saturating_sub(self.remaining()).div_ceil(B) as u128;
+
+        if needed > self.available_blocks() {
+            return Err(BlockCipherError::CounterExhausted);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of keystream blocks left until the counter wraps around
+    fn available_blocks(&self) -> u128 {
+        if self.exhausted {
+            return 0;
+        }
+
+        if self.width >= 16 {
+            // A counter of 128 bits or more can not be exhausted in practice
+            let current = self.counter();
+            return (u128::MAX - current).saturating_add(1);
+        }
+
+        let current = self.counter[B - self.width..]
+            .iter()
+            .fold(0, |acc, byte| (acc << 8) | *byte as u128);
+
+        (1 << (8 * self.width)) - current
+    }
+
+    /// XORs the keystream into data, generating new keystream blocks with encrypt_block as needed.
+    /// Fails at a block boundary with the counter untouched if encrypt_block fails.
+    fn xor_keystream<T, E>(
+        &mut self,
+        primitive: &T,
+        data: &mut [u8],
+        mut encrypt_block: impl FnMut(&T, &mut [u8; B]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut processed = 0;
+
+        while processed < data.len() {
+            if self.pos == B {
+                let mut keystream = self.counter;
+                encrypt_block(primitive, &mut keystream)?;
+
+                self.keystream = keystream;
+                self.exhausted = increment(&mut self.counter[B - self.width..]);
+                self.pos = 0;
+            }
+
+            let xored = mem::xor_slices(&mut data[processed..], &self.keystream[self.pos..]);
+
+            processed += xored;
+            self.pos += xored;
+        }
+
+        Ok(())
+    }
+
+    /// XORs the keystream into data, generating new keystream blocks with the fallible path of the primitive.
+    /// Fails without touching data if a restricted counter would wrap around and repeat the keystream.
+    pub(crate) fn try_apply<T: PrimitiveEncryption<B>>(&mut self, primitive: &T, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.check(data.len())?;
+        self.xor_keystream(primitive, data, |primitive, block| primitive.try_encrypt_block(block))
+    }
+
+    /// Infallible variant of `try_apply`
+    ///
+    /// Panics if a restricted counter would wrap around and repeat the keystream
+    pub(crate) fn apply<T: PrimitiveEncryption<B>>(&mut self, primitive: &T, data: &mut [u8]) {
+        if let Err(e) = self.check(data.len()) {
+            panic!("{}", e);
+        }
+
+        self.xor_keystream(primitive, data, |primitive, block| {
+            primitive.encrypt(block);
+            Ok::<(), Infallible>(())
+        }).unwrap_or_else(|e| match e {});
+    }
+}
+
+/// Increments the counter as a big endian integer, returns true if it wrapped around to zero
+fn increment(counter: &mut [u8]) -> bool {
+    for byte in counter.iter_mut().rev() {
+        *byte = byte.wrapping_add(1);
+
+        if *byte != 0 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// CTR Encryption and Decryption provider
+///
+/// Provides en- and decryption in Counter Mode based on a Primitive T eg. Aes.
+/// Since the keystream is XORed into the data, encryption and decryption are the same operation.
+/// By default the whole counter block is incremented and wraps around to zero after its maximum.
+/// See `with_counter_width` to only increment its low bits, in which case the keystream is never reused:
+/// once the counter would wrap around, `try_encrypt` fails and `encrypt` panics.
+pub struct CtrCipher<const BLOCKSIZE: usize, T: PrimitiveEncryption<BLOCKSIZE>> {
+    primitive: T,
+    state: CtrState<BLOCKSIZE>
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> CtrCipher<B,T> {
+    /// Create a new instance from a primitive and the initial counter block
+    pub fn new(primitive: T, iv: [u8; B]) -> Self {
+
+        Self { primitive, state: CtrState::new(iv) }
+    }
+
+    /// Only increments the low bits of the counter block, the remaining bytes of the IV stay fixed
+    pub fn with_counter_width(mut self, width: CounterWidth) -> Self {
+        self.state.set_width(width);
+        self
+    }
+
+    /// Returns the counter value that will be used for the next keystream block.
+    /// For blocksizes above 16 bytes only the last 16 bytes of the counter block are considered.
+    pub fn counter(&self) -> u128 {
+        self.state.counter()
+    }
+
+    /// Sets the counter value for the next keystream block, any remaining keystream of the current block is discarded.
+    /// Only the bytes of the counter width are overwritten, fails if the value does not fit into them.
+    pub fn set_counter(&mut self, counter: u128) -> Result<(), BlockCipherError> {
+        self.state.set_counter(counter)
+    }
+
+    /// Restarts the stream at a new initial counter block, keeping the primitive and its key schedule.
+    /// Fails if the IV is not exactly one block long.
+    pub fn reset_iv(&mut self, iv: &[u8]) -> Result<(), BlockCipherError> {
+        if iv.len() != B {
+            return Err(BlockCipherError::InvalidIvLength { got: iv.len(), expected: B });
+        }
+
+        let mut block = [0; B];
+        block.copy_from_slice(iv);
+        self.state.reset(block);
+        Ok(())
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> StreamEncryption for CtrCipher<B,T> {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        self.state.apply(&self.primitive, data)
+    }
+
+    /// Encrypts data, failing with `BlockCipherError::CounterExhausted` and leaving data untouched
+    /// if the counter would wrap around within it, or with the error of a failing primitive
+    fn try_encrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.state.try_apply(&self.primitive, data)
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> StreamDecryption for CtrCipher<B,T> {
+    fn decrypt(&mut self, data: &mut [u8]) {
+        self.state.apply(&self.primitive, data)
+    }
+
+    /// Decrypts data, see `try_encrypt`
+    fn try_decrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.state.try_apply(&self.primitive, data)
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> RuntimeInfo for CtrCipher<B,T> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
+}
@@ -0,0 +1,33 @@
+use crate::mem;
+
+/// Keystream buffer shared by the block based stream ciphers, eg. ChaCha20 and Salsa20
+///
+/// Holds the current keystream block and the position of the next unused byte,
+/// new blocks are requested from the cipher once the buffered one is used up.
+pub(crate) struct Keystream<const BLOCKSIZE: usize> {
+    block: [u8; BLOCKSIZE],
+    pos: usize,
+}
+
+impl<const B: usize> Keystream<B> {
+    pub(crate) fn new() -> Self {
+        Self { block: [0; B], pos: B }
+    }
+
+    /// XORs the keystream into data, calling next_block whenever a fresh keystream block is needed
+    pub(crate) fn apply<F: FnMut() -> [u8; B]>(&mut self, data: &mut [u8], mut next_block: F) {
+        let mut processed = 0;
+
+        while processed < data.len() {
+            if self.pos == B {
+                self.block = next_block();
+                self.pos = 0;
+            }
+
+            let xored = mem::xor_slices(&mut data[processed..], &self.block[self.pos..]);
+
+            processed += xored;
+            self.pos += xored;
+        }
+    }
+}
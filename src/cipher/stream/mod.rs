@@ -1 +1,9 @@
 pub mod cfb;
+pub mod chacha20;
+pub mod ctr;
+pub mod ofb;
+pub mod rc4;
+pub mod salsa20;
+pub mod xchacha20;
+
+mod keystream;
@@ -0,0 +1,106 @@
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mem;
+use crate::traits::cipher::{
+    primitive::{BlockCipherPrimitiveEncryption as PrimitiveEncryption, BlockCipherRuntimeInfo as RuntimeInfo},
+    stream::{StreamCipherDecryption as StreamDecryption, StreamCipherEncryption as StreamEncryption},
+};
+use core::convert::Infallible;
+
+pub trait OfbCipherProvider<const BLOCKSIZE: usize> {
+    type Cipher: PrimitiveEncryption<BLOCKSIZE>;
+    fn with_ofb(self, iv: [u8; BLOCKSIZE]) -> OfbCipher<BLOCKSIZE, Self::Cipher>;
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> OfbCipherProvider<B> for T {
+    type Cipher = Self;
+    fn with_ofb(self, iv: [u8; B]) -> OfbCipher<B, Self::Cipher> {
+        OfbCipher::new(self, iv)
+    }
+}
+
+/// OFB Encryption and Decryption provider
+///
+/// Output feedback mode as specified in NIST SP 800-38A. The keystream is the IV encrypted over and over,
+/// independent of the data, so encryption and decryption are the same operation.
+/// An IV must never be used for more than one message under the same key.
+pub struct OfbCipher<const BLOCKSIZE: usize, T: PrimitiveEncryption<BLOCKSIZE>> {
+    primitive: T,
+    feedback: [u8; BLOCKSIZE],
+    pos: usize,
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> OfbCipher<B, T> {
+    pub fn new(primitive: T, iv: [u8; B]) -> Self {
+        Self {
+            primitive,
+            feedback: iv,
+            pos: B,
+        }
+    }
+
+    /// XORs the keystream into data, generating the next feedback block with encrypt_block.
+    /// Fails at a block boundary with the feedback untouched.
+    fn process<E>(
+        &mut self,
+        data: &mut [u8],
+        mut encrypt_block: impl FnMut(&T, &mut [u8; B]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut processed = 0;
+
+        while processed < data.len() {
+            if self.pos == B {
+                let mut block = self.feedback;
+                encrypt_block(&self.primitive, &mut block)?;
+                self.feedback = block;
+                self.pos = 0;
+            }
+
+            let xored = mem::xor_slices(&mut data[processed..], &self.feedback[self.pos..]);
+            processed += xored;
+            self.pos += xored;
+        }
+
+        Ok(())
+    }
+
+    fn apply(&mut self, data: &mut [u8]) {
+        self.process(data, |primitive, block| {
+            primitive.encrypt(block);
+            Ok::<(), Infallible>(())
+        }).unwrap_or_else(|e| match e {});
+    }
+
+    fn try_apply(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.process(data, |primitive, block| primitive.try_encrypt_block(block))
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> StreamEncryption for OfbCipher<B, T> {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        self.apply(data)
+    }
+
+    fn try_encrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.try_apply(data)
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> StreamDecryption for OfbCipher<B, T> {
+    fn decrypt(&mut self, data: &mut [u8]) {
+        self.apply(data)
+    }
+
+    fn try_decrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.try_apply(data)
+    }
+}
+
+impl<const B: usize, T: PrimitiveEncryption<B>> RuntimeInfo for OfbCipher<B, T> {
+    fn block_size(&self) -> usize {
+        self.primitive.block_size()
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        self.primitive.key_bounds()
+    }
+}
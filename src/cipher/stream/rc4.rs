@@ -0,0 +1,78 @@
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mem;
+use crate::traits::cipher::stream::{
+    StreamCipherDecryption as StreamDecryption,
+    StreamCipherEncryption as StreamEncryption,
+};
+
+pub const RC4_KEYLEN_MIN: usize = 1;
+pub const RC4_KEYLEN_MAX: usize = 256;
+
+/// RC4 Encryption and Decryption provider
+///
+/// **RC4 is cryptographically broken and must not be used for new designs.** Its keystream is biased, most
+/// notably in the first bytes, which allows plaintext recovery from enough ciphertexts (see RFC 7465, which
+/// prohibits it in TLS). It is provided only to interoperate with legacy protocols and data.
+pub struct Rc4 {
+    s: [u8; 256],
+    i: u8,
+    j: u8,
+}
+
+impl Rc4 {
+    /// Create a new instance from a key of 1 to 256 bytes, running the key scheduling algorithm
+    ///
+    /// Fails with `BlockCipherError::InvalidKeyLength` for an empty or longer key
+    pub fn new(key: &[u8]) -> Result<Self, BlockCipherError> {
+        if !(RC4_KEYLEN_MIN..=RC4_KEYLEN_MAX).contains(&key.len()) {
+            return Err(BlockCipherError::InvalidKeyLength {
+                got: key.len(),
+                min: RC4_KEYLEN_MIN,
+                max: RC4_KEYLEN_MAX,
+            });
+        }
+
+        let mut s = [0; 256];
+        for (i, v) in s.iter_mut().enumerate() {
+            *v = i as u8;
+        }
+
+        let mut j: u8 = 0;
+        for i in 0..256 {
+            j = j.wrapping_add(s[i]).wrapping_add(key[i % key.len()]);
+            s.swap(i, j as usize);
+        }
+
+        Ok(Self { s, i: 0, j: 0 })
+    }
+
+    /// XORs the keystream of the pseudo random generation algorithm into data
+    fn apply(&mut self, data: &mut [u8]) {
+        for byte in data {
+            self.i = self.i.wrapping_add(1);
+            self.j = self.j.wrapping_add(self.s[self.i as usize]);
+            self.s.swap(self.i as usize, self.j as usize);
+
+            let k = self.s[self.i as usize].wrapping_add(self.s[self.j as usize]);
+            *byte ^= self.s[k as usize];
+        }
+    }
+}
+
+impl StreamEncryption for Rc4 {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        self.apply(data)
+    }
+}
+
+impl StreamDecryption for Rc4 {
+    fn decrypt(&mut self, data: &mut [u8]) {
+        self.apply(data)
+    }
+}
+
+impl Drop for Rc4 {
+    fn drop(&mut self) {
+        mem::zeroize(&mut self.s);
+    }
+}
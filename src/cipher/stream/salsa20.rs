@@ -0,0 +1,123 @@
+use super::keystream::Keystream;
+use crate::traits::cipher::stream::{
+    StreamCipherDecryption as StreamDecryption,
+    StreamCipherEncryption as StreamEncryption,
+};
+
+pub const SALSA20_KEYLEN: usize = 32;
+pub const SALSA20_NONCELEN: usize = 8;
+pub const SALSA20_BLOCKSIZE: usize = 64;
+
+/// "expand 32-byte k"
+const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+/// Salsa20 Encryption and Decryption provider
+///
+/// Stream cipher with a 32 byte key, a 64 bit nonce and a 64 bit block counter as specified by D. J. Bernstein.
+pub struct Salsa20 {
+    state: [u32; 16],
+    keystream: Keystream<SALSA20_BLOCKSIZE>,
+}
+
+impl Salsa20 {
+    /// Create a new instance from a key, a nonce and the counter of the first keystream block
+    pub fn new(key: &[u8; SALSA20_KEYLEN], nonce: &[u8; SALSA20_NONCELEN], counter: u64) -> Self {
+        let mut state = [0; 16];
+        let (k0, k1) = key.split_at(SALSA20_KEYLEN / 2);
+
+        // The constants are spread over the diagonal
+        state[0] = CONSTANTS[0];
+        state[5] = CONSTANTS[1];
+        state[10] = CONSTANTS[2];
+        state[15] = CONSTANTS[3];
+
+        for (word, bytes) in state[1..5].iter_mut().zip(k0.chunks_exact(4)) {
+            *word = le32(bytes);
+        }
+
+        for (word, bytes) in state[6..8].iter_mut().zip(nonce.chunks_exact(4)) {
+            *word = le32(bytes);
+        }
+
+        state[8] = counter as u32;
+        state[9] = (counter >> 32) as u32;
+
+        for (word, bytes) in state[11..15].iter_mut().zip(k1.chunks_exact(4)) {
+            *word = le32(bytes);
+        }
+
+        Self {
+            state,
+            keystream: Keystream::new(),
+        }
+    }
+
+    fn apply(&mut self, data: &mut [u8]) {
+        let state = &mut self.state;
+        self.keystream.apply(data, || next_block(state));
+    }
+}
+
+impl StreamEncryption for Salsa20 {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        self.apply(data)
+    }
+}
+
+impl StreamDecryption for Salsa20 {
+    fn decrypt(&mut self, data: &mut [u8]) {
+        self.apply(data)
+    }
+}
+
+/// Computes the keystream block of the state and advances the 64 bit block counter
+fn next_block(state: &mut [u32; 16]) -> [u8; SALSA20_BLOCKSIZE] {
+    let mut block = *state;
+    core(&mut block, 20);
+
+    let counter = (u64::from(state[9]) << 32 | u64::from(state[8])).wrapping_add(1);
+    state[8] = counter as u32;
+    state[9] = (counter >> 32) as u32;
+
+    let mut out = [0; SALSA20_BLOCKSIZE];
+    for (bytes, word) in out.chunks_exact_mut(4).zip(block.iter()) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+
+    out
+}
+
+/// The Salsa20 core with a variable number of rounds followed by the feedforward of the input,
+/// also used with 8 rounds by scrypt
+pub(crate) fn core(block: &mut [u32; 16], rounds: usize) {
+    let mut x = *block;
+
+    for _ in 0..rounds / 2 {
+        // Columns
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 5, 9, 13, 1);
+        quarter_round(&mut x, 10, 14, 2, 6);
+        quarter_round(&mut x, 15, 3, 7, 11);
+
+        // Rows
+        quarter_round(&mut x, 0, 1, 2, 3);
+        quarter_round(&mut x, 5, 6, 7, 4);
+        quarter_round(&mut x, 10, 11, 8, 9);
+        quarter_round(&mut x, 15, 12, 13, 14);
+    }
+
+    for (word, mixed) in block.iter_mut().zip(x.iter()) {
+        *word = word.wrapping_add(*mixed);
+    }
+}
+
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+    x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+    x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+    x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+}
+
+fn le32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
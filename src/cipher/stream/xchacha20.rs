@@ -0,0 +1,47 @@
+use super::chacha20::{hchacha20, ChaCha20, CHACHA20_KEYLEN, CHACHA20_NONCELEN, HCHACHA20_NONCELEN};
+use crate::mem;
+use crate::traits::cipher::stream::{
+    StreamCipherDecryption as StreamDecryption,
+    StreamCipherEncryption as StreamEncryption,
+};
+
+pub const XCHACHA20_KEYLEN: usize = CHACHA20_KEYLEN;
+pub const XCHACHA20_NONCELEN: usize = 24;
+
+/// XChaCha20 Encryption and Decryption provider
+///
+/// ChaCha20 with an extended 192 bit nonce as specified in draft-irtf-cfrg-xchacha. The first 16 bytes of the nonce
+/// derive a subkey with HChaCha20, ChaCha20 then runs under the subkey with the remaining 8 bytes of the nonce.
+/// The nonce is long enough to be chosen at random for every message.
+pub struct XChaCha20 {
+    cipher: ChaCha20,
+}
+
+impl XChaCha20 {
+    /// Create a new instance from a key, an extended nonce and the counter of the first keystream block
+    pub fn new(key: &[u8; XCHACHA20_KEYLEN], nonce: &[u8; XCHACHA20_NONCELEN], counter: u32) -> Self {
+        let mut prefix = [0; HCHACHA20_NONCELEN];
+        prefix.copy_from_slice(&nonce[..HCHACHA20_NONCELEN]);
+        let mut subkey = hchacha20(key, &prefix);
+
+        let mut chacha_nonce = [0; CHACHA20_NONCELEN];
+        chacha_nonce[4..].copy_from_slice(&nonce[HCHACHA20_NONCELEN..]);
+
+        let cipher = ChaCha20::new(&subkey, &chacha_nonce, counter);
+        mem::zeroize(&mut subkey);
+
+        Self { cipher }
+    }
+}
+
+impl StreamEncryption for XChaCha20 {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        self.cipher.encrypt(data)
+    }
+}
+
+impl StreamDecryption for XChaCha20 {
+    fn decrypt(&mut self, data: &mut [u8]) {
+        self.cipher.decrypt(data)
+    }
+}
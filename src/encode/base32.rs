@@ -0,0 +1,246 @@
+use crate::errors::base32::Base32Error;
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::io;
+
+const B32_CHARS: [char; 32] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+    'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '2', '3', '4', '5', '6', '7',
+];
+
+const PADDING: char = '=';
+
+/// Bytes per group of input
+const GROUP_BYTES: usize = 5;
+
+/// Characters per group of output
+const GROUP_CHARS: usize = 8;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Base32Encoder;
+
+impl Base32Encoder {
+    /// Create a new encoder with the RFC 4648 alphabet
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Encodes bytes to a String in Base32 format
+    /// * 'bytes' - The byte buffer to encode
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        let mut encoded = String::new();
+
+        for ch in bytes.chunks(GROUP_BYTES) {
+            encode_group(ch, &mut encoded);
+        }
+
+        encoded
+    }
+
+    /// Decodes a String in Base32 format to bytes
+    ///
+    /// Note: Will filter out any non-base32 characters
+    /// * 'string' - The string to decode
+    pub fn decode(&self, string: &str) -> Result<Vec<u8>, Base32Error> {
+        if string.len() % GROUP_CHARS != 0 {
+            return Err(Base32Error::InvalidInputLength(string.len()));
+        }
+
+        // filter out any non-b32 chars
+        let filtered: Vec<u8> = string.chars().filter_map(is_b32).collect();
+
+        let mut decoded = Vec::new();
+        for ch in filtered.chunks(GROUP_CHARS) {
+            decode_group(ch, &mut decoded)?;
+        }
+
+        Ok(decoded)
+    }
+}
+
+// Encodes up to 5 bytes into a padded group of 8 characters
+fn encode_group(group: &[u8], encoded: &mut String) {
+    // Each started 5 bit chunk produces a character, the rest of the group is padded
+    let chars = (group.len() * 8).div_ceil(5);
+
+    let mut bits = 0u64;
+    for (i, byte) in group.iter().enumerate() {
+        bits |= (*byte as u64) << (32 - 8 * i);
+    }
+
+    for i in 0..GROUP_CHARS {
+        if i < chars {
+            encoded.push(B32_CHARS[((bits >> (35 - 5 * i)) & 0b11111) as usize]);
+        } else {
+            encoded.push(PADDING);
+        }
+    }
+}
+
+// Decodes up to 8 character values into bytes
+fn decode_group(values: &[u8], decoded: &mut Vec<u8>) -> Result<(), Base32Error> {
+    let bytes = match values.len() {
+        8 => 5,
+        7 => 4,
+        5 => 3,
+        4 => 2,
+        2 => 1,
+        n => return Err(Base32Error::InvalidFormat(n)),
+    };
+
+    let mut bits = 0u64;
+    for (i, value) in values.iter().enumerate() {
+        bits |= (*value as u64) << (35 - 5 * i);
+    }
+
+    decoded.extend((0..bytes).map(|i| (bits >> (32 - 8 * i)) as u8));
+    Ok(())
+}
+
+// Returns the index into the encoding array
+fn is_b32(c: char) -> Option<u8> {
+    match c {
+        'A'..='Z' => Some(c as u8 - b'A'),
+        '2'..='7' => Some(c as u8 - b'2' + 26),
+        _ => None,
+    }
+}
+
+/// Streaming Base32 encoder
+///
+/// Buffers incoming bytes in groups of 5 and writes 8 characters per group to the inner writer.
+/// The final, possibly partial group is only written on `finish`.
+#[cfg(feature = "std")]
+pub struct Base32Writer<W: io::Write> {
+    inner: W,
+    group: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> Base32Writer<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            group: Vec::with_capacity(GROUP_BYTES),
+        }
+    }
+
+    fn write_group(&mut self) -> io::Result<()> {
+        let mut encoded = String::with_capacity(GROUP_CHARS);
+        encode_group(&self.group, &mut encoded);
+        self.group.clear();
+
+        self.inner.write_all(encoded.as_bytes())
+    }
+
+    /// Writes the padded final group and returns the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        if !self.group.is_empty() {
+            self.write_group()?;
+        }
+
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> io::Write for Base32Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for byte in buf {
+            self.group.push(*byte);
+
+            if self.group.len() == GROUP_BYTES {
+                self.write_group()?;
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    /// Flushes the inner writer, an incomplete group stays buffered until `finish`
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Streaming Base32 decoder
+///
+/// Reads characters from the inner reader and yields the decoded bytes.
+/// Like `Base32Encoder::decode`, any non-base32 characters (including padding) are skipped.
+#[cfg(feature = "std")]
+pub struct Base32Reader<R: io::Read> {
+    inner: R,
+    group: Vec<u8>,
+    decoded: Vec<u8>,
+    pos: usize,
+    eof: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> Base32Reader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            group: Vec::with_capacity(GROUP_CHARS),
+            decoded: Vec::new(),
+            pos: 0,
+            eof: false,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    // Reads from the inner reader until some bytes have been decoded or the input is exhausted
+    fn fill(&mut self) -> io::Result<()> {
+        self.decoded.clear();
+        self.pos = 0;
+
+        let mut chunk = [0u8; 64];
+
+        while self.decoded.is_empty() && !self.eof {
+            let read = self.inner.read(&mut chunk)?;
+
+            if read == 0 {
+                self.eof = true;
+
+                if !self.group.is_empty() {
+                    decode_group(&self.group, &mut self.decoded)?;
+                    self.group.clear();
+                }
+
+                break;
+            }
+
+            for value in chunk[..read].iter().filter_map(|b| is_b32(*b as char)) {
+                self.group.push(value);
+
+                if self.group.len() == GROUP_CHARS {
+                    decode_group(&self.group, &mut self.decoded)?;
+                    self.group.clear();
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> io::Read for Base32Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos == self.decoded.len() {
+            self.fill()?;
+        }
+
+        let available = &self.decoded[self.pos..];
+        let len = available.len().min(buf.len());
+
+        buf[..len].copy_from_slice(&available[..len]);
+        self.pos += len;
+
+        Ok(len)
+    }
+}
@@ -1,4 +1,7 @@
-use crate::errors::base64::Base64Error;
+use crate::errors::base64::{Base64Error, ParseKindError};
+use alloc::{string::{String, ToString}, vec::Vec};
+use core::fmt;
+use core::str::FromStr;
 
 const B64_CHARS: [char; 64] = [
     'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
@@ -34,61 +37,32 @@ impl Base64Encoder {
         }
     }
 
+    /// Create a new encoder with the given alphabet, eg. parsed from a configuration value
+    pub fn with_kind(kind: Kind) -> Self {
+        Self { kind }
+    }
+
+    /// Returns the alphabet of the encoder
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
     /// Encodes bytes to a String in Base64 format
     /// * 'bytes' - The byte buffer to encode
     pub fn encode(&self, bytes: &[u8]) -> String {
-        let mut encoded = String::new();
+        let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
 
-        if bytes.is_empty() {
-            return encoded;
-        }
-
-        // Bytes are split into chunks of 6 bit each -> Must add up to multiple of 24 bit
-        let mut chunks = bytes.chunks_exact(3);
-
-        while let Some(ch) = chunks.next() {
-            // Main encoding step
-            let ia = ch[0] >> 2;
-            let ib = ((ch[0] & 0b11) << 4) | ((ch[1] & 0b11110000) >> 4);
-            let ic = ((ch[1] & 0b1111) << 2) | ((ch[2] & 0b11000000) >> 6);
-            let id = ch[2] & 0b111111;
-
-            encoded.extend([
-                self.kind.value_at(ia as usize),
-                self.kind.value_at(ib as usize),
-                self.kind.value_at(ic as usize),
-                self.kind.value_at(id as usize),
-            ]);
-        }
-
-        // Each PADDING character amounts to two zero bits that have been appended to the remaining bits
-        if chunks.remainder().len() == 1 {
-            let ia = chunks.remainder()[0] >> 2;
-            let ib = (chunks.remainder()[0] & 0b11) << 4;
-
-            encoded.extend([
-                self.kind.value_at(ia as usize),
-                self.kind.value_at(ib as usize),
-                PADDING,
-                PADDING,
-            ]);
-        } else if chunks.remainder().len() == 2 {
-            let ia = chunks.remainder()[0] >> 2;
-            let ib =
-                ((chunks.remainder()[0] & 0b11) << 4) | ((chunks.remainder()[1] & 0b11110000) >> 4);
-            let ic = (chunks.remainder()[1] & 0b1111) << 2;
-
-            encoded.extend([
-                self.kind.value_at(ia as usize),
-                self.kind.value_at(ib as usize),
-                self.kind.value_at(ic as usize),
-                PADDING,
-            ]);
-        }
+        // Writing to a String never fails
+        base64_encode_to(self.kind, bytes, &mut encoded).unwrap();
 
         encoded
     }
 
+    /// Encodes bytes in Base64 format into an existing writer, see `base64_encode_to`
+    pub fn encode_to<W: fmt::Write>(&self, bytes: &[u8], w: &mut W) -> fmt::Result {
+        base64_encode_to(self.kind, bytes, w)
+    }
+
     /// Decodes a String in Base64 format to bytes
     ///
     /// Note: Will filter out any non-base64 characters
@@ -103,6 +77,35 @@ impl Base64Encoder {
 
         decode_core(filtered)
     }
+
+    /// Decodes a String in Base64 format to bytes, rejecting any character outside of the alphabet.
+    /// Up to two padding characters are only accepted at the end.
+    ///
+    /// Fails with `Base64Error::InvalidCharacter` holding the byte offset of the first offending character
+    /// * 'string' - The string to decode
+    pub fn decode_strict(&self, string: &str) -> Result<Vec<u8>, Base64Error> {
+        if string.len() % 4 != 0 {
+            return Err(Base64Error::InvalidInputLength(string.len()));
+        }
+
+        let unpadded = string.trim_end_matches(PADDING);
+
+        // The first padding character is where data was expected
+        if string.len() - unpadded.len() > 2 {
+            return Err(Base64Error::InvalidCharacter { index: unpadded.len(), found: PADDING });
+        }
+
+        let mut filtered = Vec::with_capacity(unpadded.len());
+
+        for (index, c) in unpadded.char_indices() {
+            match self.kind.is_b64(c) {
+                Some(value) => filtered.push(value),
+                None => return Err(Base64Error::InvalidCharacter { index, found: c }),
+            }
+        }
+
+        decode_core(filtered)
+    }
 }
 
 impl Default for Base64Encoder {
@@ -112,12 +115,104 @@ impl Default for Base64Encoder {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Kind {
+/// Encodes bytes in Base64 format into a writer, eg. a String or fmt::Formatter, without building an
+/// intermediate String. Output is written one group of 4 characters at a time.
+/// * 'kind' - The alphabet to encode with
+/// * 'bytes' - The byte buffer to encode
+/// * 'w' - The writer receiving the encoded characters
+pub fn base64_encode_to<W: fmt::Write>(kind: Kind, bytes: &[u8], w: &mut W) -> fmt::Result {
+    // Bytes are split into chunks of 6 bit each -> Must add up to multiple of 24 bit
+    let mut chunks = bytes.chunks_exact(3);
+
+    for ch in chunks.by_ref() {
+        // Main encoding step
+        let ia = ch[0] >> 2;
+        let ib = ((ch[0] & 0b11) << 4) | ((ch[1] & 0b11110000) >> 4);
+        let ic = ((ch[1] & 0b1111) << 2) | ((ch[2] & 0b11000000) >> 6);
+        let id = ch[2] & 0b111111;
+
+        let group = [
+            kind.value_at(ia as usize),
+            kind.value_at(ib as usize),
+            kind.value_at(ic as usize),
+            kind.value_at(id as usize),
+        ];
+        write_group(w, group)?;
+    }
+
+    // Each PADDING character amounts to two zero bits that have been appended to the remaining bits
+    if chunks.remainder().len() == 1 {
+        let ia = chunks.remainder()[0] >> 2;
+        let ib = (chunks.remainder()[0] & 0b11) << 4;
+
+        let group = [
+            kind.value_at(ia as usize),
+            kind.value_at(ib as usize),
+            PADDING,
+            PADDING,
+        ];
+        write_group(w, group)?;
+    } else if chunks.remainder().len() == 2 {
+        let ia = chunks.remainder()[0] >> 2;
+        let ib =
+            ((chunks.remainder()[0] & 0b11) << 4) | ((chunks.remainder()[1] & 0b11110000) >> 4);
+        let ic = (chunks.remainder()[1] & 0b1111) << 2;
+
+        let group = [
+            kind.value_at(ia as usize),
+            kind.value_at(ib as usize),
+            kind.value_at(ic as usize),
+            PADDING,
+        ];
+        write_group(w, group)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a group of 4 ASCII characters with a single call
+fn write_group<W: fmt::Write>(w: &mut W, group: [char; 4]) -> fmt::Result {
+    let mut buf = [0; 4];
+
+    for (b, c) in buf.iter_mut().zip(group.iter()) {
+        *b = *c as u8;
+    }
+
+    // The alphabets and the padding character are ASCII
+    w.write_str(core::str::from_utf8(&buf).unwrap())
+}
+
+/// Alphabet of a Base64Encoder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Standard alphabet with '+' and '/'
     Basic,
+    /// URL and filename safe alphabet with '-' and '_'
     UrlSafe,
 }
 
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Kind::Basic => write!(f, "basic"),
+            Kind::UrlSafe => write!(f, "url-safe"),
+        }
+    }
+}
+
+impl FromStr for Kind {
+    type Err = ParseKindError;
+
+    /// Parses "basic", "url-safe" or "url", ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "basic" => Ok(Kind::Basic),
+            "url-safe" | "url" => Ok(Kind::UrlSafe),
+            _ => Err(ParseKindError(s.to_string())),
+        }
+    }
+}
+
 impl Kind {
     // Returns the character at the given index
     fn value_at(&self, ix: usize) -> char {
@@ -157,7 +252,7 @@ fn decode_core(filtered: Vec<u8>) -> Result<Vec<u8>, Base64Error> {
 
     let mut chunks = filtered.chunks_exact(4);
 
-    while let Some(ch) = chunks.next() {
+    for ch in chunks.by_ref() {
         decoded.push((ch[0] << 2) | (ch[1] >> 4));
         decoded.push((ch[1] << 4) | (ch[2] >> 2));
         decoded.push((ch[2] << 6) | ch[3]);
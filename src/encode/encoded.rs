@@ -0,0 +1,43 @@
+use super::Base64Encoder;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use alloc::{string::String, vec::Vec};
+
+/// Bytes that serialize as a basic Base64 string and deserialize by decoding it
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Encoded(pub Vec<u8>);
+
+impl Encoded {
+    /// Consumes the wrapper, returning the raw bytes
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for Encoded {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for Encoded {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Serialize for Encoded {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&Base64Encoder::new().encode(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Encoded {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+
+        Base64Encoder::new()
+            .decode(&string)
+            .map(Self)
+            .map_err(de::Error::custom)
+    }
+}
@@ -1,3 +1,6 @@
+use crate::errors::hex::HexError;
+use alloc::{format, string::String, vec::Vec};
+
 const CHARSET_UPPERCASE: [char; 16] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
 ];
@@ -12,6 +15,7 @@ pub struct HexEncoder {
     terminator: String,
     groupsize: usize,
     case: Case,
+    strict: bool,
 }
 
 impl HexEncoder {
@@ -22,45 +26,61 @@ impl HexEncoder {
             terminator: String::from(""),
             groupsize: 0,
             case: Case::Upper,
+            strict: false,
         }
     }
 
     /// Encode in uppercase letters
-    pub fn set_uppercase<'a>(&'a mut self) -> &'a mut Self {
+    pub fn set_uppercase(&mut self) -> &mut Self {
         self.case = Case::Upper;
         self
     }
 
     /// Encode in lowercase letters
-    pub fn set_lowercase<'a>(&'a mut self) -> &'a mut Self {
+    pub fn set_lowercase(&mut self) -> &mut Self {
         self.case = Case::Lower;
         self
     }
 
+    /// Toggles strict RFC 4648 Base16 decoding in `try_decode`
+    ///
+    /// A strict decoder rejects any non-hex character, including whitespace, header, seperator and terminator
+    pub fn set_strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+        self
+    }
+
     /// Will be prepended to the resulting string
-    pub fn with_header<'a>(&'a mut self, header: &str) -> &'a mut Self {
+    pub fn with_header(&mut self, header: &str) -> &mut Self {
         self.header = String::from(header);
         self
     }
 
     /// Will be inserted after each encoded grouping
-    pub fn with_seperator<'a>(&'a mut self, seperator: &str) -> &'a mut Self {
+    pub fn with_seperator(&mut self, seperator: &str) -> &mut Self {
         self.seperator = String::from(seperator);
         self
     }
 
     /// Will be appended to the resulting string
-    pub fn with_terminator<'a>(&'a mut self, terminator: &str) -> &'a mut Self {
+    pub fn with_terminator(&mut self, terminator: &str) -> &mut Self {
         self.terminator = String::from(terminator);
         self
     }
 
-    /// Controls the byte grouping -> eg groupsize = 1: 01:02; groupsize = 2: 0102
-    pub fn set_groupsize<'a>(&'a mut self, groupsize: usize) -> &'a mut Self {
+    /// Controls the byte grouping -> eg groupsize = 1: 01:02; groupsize = 2: 0102:0304
+    ///
+    /// A groupsize of 0 disables grouping, no seperator is inserted
+    pub fn set_groupsize(&mut self, groupsize: usize) -> &mut Self {
         self.groupsize = groupsize;
         self
     }
 
+    /// Inserts the seperator after every groupsize bytes -> eg groupsize = 4, " ": 01020304 05060708
+    pub fn with_grouping(&mut self, groupsize: usize, seperator: &str) -> &mut Self {
+        self.set_groupsize(groupsize).with_seperator(seperator)
+    }
+
     /// Encodes a byte buffer to a Hex string
     /// * 'data'    - The data to encode
     pub fn encode(&self, data: &[u8]) -> String {
@@ -68,7 +88,7 @@ impl HexEncoder {
 
         for (i, v) in data.iter().enumerate() {
             // Insert the seperator after each grouping
-            if i % self.groupsize == 0 && i != 0 {
+            if self.groupsize != 0 && i % self.groupsize == 0 && i != 0 {
                 encoded.push_str(&self.seperator);
             }
 
@@ -100,7 +120,7 @@ impl HexEncoder {
         };
 
         // filter out any non-hex chars
-        let mut filtered = stripped.chars().filter_map(|c| is_hex(c));
+        let mut filtered = stripped.chars().filter_map(is_hex);
 
         while let Some(v1) = filtered.next() {
             match filtered.next() {
@@ -111,6 +131,36 @@ impl HexEncoder {
 
         decoded
     }
+
+    /// Decodes a hex string into its bytes, in strict mode as RFC 4648 Base16.
+    /// Without strict mode this never fails and behaves like `decode`.
+    ///
+    /// Fails in strict mode with `HexError::InvalidCharacter` holding the byte offset of the first non-hex character
+    /// or with `HexError::InvalidInputLength` if the number of hex characters is odd
+    /// * 'hex' - The hex string to decode
+    pub fn try_decode(&self, hex: &str) -> Result<Vec<u8>, HexError> {
+        if !self.strict {
+            return Ok(self.decode(hex));
+        }
+
+        let mut nibbles = Vec::with_capacity(hex.len());
+
+        for (index, c) in hex.char_indices() {
+            match is_hex(c) {
+                Some(value) => nibbles.push(value),
+                None => return Err(HexError::InvalidCharacter { index, found: c }),
+            }
+        }
+
+        if nibbles.len() % 2 != 0 {
+            return Err(HexError::InvalidInputLength(nibbles.len()));
+        }
+
+        Ok(nibbles
+            .chunks_exact(2)
+            .map(|pair| (pair[0] << 4) | pair[1])
+            .collect())
+    }
 }
 
 impl Default for HexEncoder {
@@ -120,6 +170,7 @@ impl Default for HexEncoder {
     /// * no terminator
     /// * 1 as groupsize
     /// * uppercase letters
+    /// * lenient decoding
     fn default() -> Self {
         Self {
             header: String::from(""),
@@ -127,6 +178,7 @@ impl Default for HexEncoder {
             terminator: String::from(""),
             groupsize: 1usize,
             case: Case::Upper,
+            strict: false,
         }
     }
 }
@@ -0,0 +1,48 @@
+use alloc::string::String;
+
+use core::fmt::Write;
+
+const BYTES_PER_LINE: usize = 16;
+
+/// Formats bytes for debugging in the layout of `hexdump -C`: the offset, 16 bytes in two columns of 8
+/// and an ASCII gutter showing non-printable bytes as '.'. The last line holds the total length.
+///
+/// Unlike `hexdump -C`, repeated lines are printed instead of being collapsed into '*'.
+/// * 'bytes' - The bytes to format
+pub fn hexdump(bytes: &[u8]) -> String {
+    let mut dump = String::new();
+
+    if bytes.is_empty() {
+        return dump;
+    }
+
+    for (line, chunk) in bytes.chunks(BYTES_PER_LINE).enumerate() {
+        // Writing to a String never fails
+        write!(dump, "{:08x}  ", line * BYTES_PER_LINE).unwrap();
+
+        for i in 0..BYTES_PER_LINE {
+            if i == BYTES_PER_LINE / 2 {
+                dump.push(' ');
+            }
+
+            match chunk.get(i) {
+                Some(byte) => write!(dump, "{:02x} ", byte).unwrap(),
+                // Pad the last line so the gutter stays aligned
+                None => dump.push_str("   "),
+            }
+        }
+
+        dump.push_str(" |");
+        dump.extend(chunk.iter().map(|&b| {
+            if b == b' ' || b.is_ascii_graphic() {
+                b as char
+            } else {
+                '.'
+            }
+        }));
+        dump.push_str("|\n");
+    }
+
+    writeln!(dump, "{:08x}", bytes.len()).unwrap();
+    dump
+}
@@ -1,4 +1,13 @@
+mod base32;
 mod base64;
+#[cfg(feature = "serde")]
+mod encoded;
 mod hex;
+mod hexdump;
+pub mod token;
+pub use base32::*;
 pub use base64::*;
+#[cfg(feature = "serde")]
+pub use encoded::*;
 pub use hex::*;
+pub use hexdump::*;
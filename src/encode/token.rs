@@ -0,0 +1,30 @@
+//! Compact token format: url safe Base64 without padding characters,
+//! eg. for iv || ciphertext || tag in web tokens.
+
+use super::Base64Encoder;
+use crate::errors::base64::Base64Error;
+use alloc::{string::String, vec::Vec};
+
+/// Encodes bytes to url safe Base64 and strips the padding
+/// * 'bytes' - The byte buffer to encode
+pub fn encode(bytes: &[u8]) -> String {
+    let mut encoded = Base64Encoder::new_url().encode(bytes);
+
+    while encoded.ends_with('=') {
+        encoded.pop();
+    }
+
+    encoded
+}
+
+/// Decodes a token produced by `encode`, re-adding the stripped padding first
+/// * 'token' - The token to decode
+pub fn decode(token: &str) -> Result<Vec<u8>, Base64Error> {
+    let mut padded = String::from(token);
+
+    while padded.len() % 4 != 0 {
+        padded.push('=');
+    }
+
+    Base64Encoder::new_url().decode(&padded)
+}
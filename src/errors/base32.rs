@@ -0,0 +1,19 @@
+#[cfg(feature = "std")]
+use std::io;
+use thiserror::Error as ThisErr;
+
+#[derive(ThisErr, Debug)]
+pub enum Base32Error {
+    #[error("input length must be a multiple of 8 (found {0})")]
+    InvalidInputLength(usize),
+
+    #[error("invalid length after stripping non-base32 characters, remainder must be either 0, 2, 4, 5 or 7 (found {0})")]
+    InvalidFormat(usize),
+}
+
+#[cfg(feature = "std")]
+impl From<Base32Error> for io::Error {
+    fn from(err: Base32Error) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
@@ -1,4 +1,5 @@
 use thiserror::Error as ThisErr;
+use alloc::string::String;
 
 #[derive(ThisErr, Debug)]
 pub enum Base64Error {
@@ -7,4 +8,11 @@ pub enum Base64Error {
 
     #[error("invalid length after stripping non-base64 characters, remainder must be either 0, 2 or 3 (found {0})")]
     InvalidFormat(usize),
+
+    #[error("invalid character {found:?} at byte offset {index}")]
+    InvalidCharacter { index: usize, found: char },
 }
+
+#[derive(ThisErr, Debug, Clone, PartialEq, Eq)]
+#[error("unknown base64 kind {0:?}, expected one of basic, url-safe or url")]
+pub struct ParseKindError(pub String);
@@ -1,7 +1,81 @@
+use alloc::string::String;
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
 use thiserror::Error as ThisErr;
 
 #[derive(Debug, ThisErr)]
 pub enum BlockCipherError {
-    #[error("last block is incomplete, found {0} missing bytes")]
+    #[error("incomplete block: {0} bytes missing")]
     IncompleteBlock(usize),
+
+    #[error("invalid key length, expected between {min} and {max} bytes (found {got})")]
+    InvalidKeyLength { got: usize, min: usize, max: usize },
+
+    #[error("weak key rejected")]
+    WeakKey,
+
+    #[error("invalid iv length, expected {expected} bytes (found {got})")]
+    InvalidIvLength { got: usize, expected: usize },
+
+    #[error("invalid block length, expected {expected} bytes (found {got})")]
+    InvalidBlockLength { got: usize, expected: usize },
+
+    #[error("output buffer too small, expected at least {expected} bytes (found {got})")]
+    OutputTooSmall { got: usize, expected: usize },
+
+    #[error("bad padding, {kind}")]
+    BadPadding { kind: PaddingErrorKind },
+
+    #[error("counter exhausted, the keystream would repeat")]
+    CounterExhausted,
+
+    #[error("authentication failed")]
+    AuthenticationFailed,
+
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(String),
+
+    #[error("primitive backend failed: {0}")]
+    BackendFailure(String),
+}
+
+/// Distinguishes a truncated ciphertext from one whose final block does not decrypt to valid padding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaddingErrorKind {
+    /// The ciphertext is empty or not a multiple of the blocksize
+    Length,
+    /// The final block does not end in valid padding bytes
+    Content,
+}
+
+impl fmt::Display for PaddingErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaddingErrorKind::Length => write!(f, "ciphertext is not a whole number of blocks"),
+            PaddingErrorKind::Content => write!(f, "final block does not end in valid padding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<BlockCipherError> for io::Error {
+    fn from(err: BlockCipherError) -> Self {
+        let kind = match err {
+            BlockCipherError::IncompleteBlock(_) => io::ErrorKind::UnexpectedEof,
+            BlockCipherError::InvalidKeyLength { .. } => io::ErrorKind::InvalidInput,
+            BlockCipherError::WeakKey => io::ErrorKind::InvalidInput,
+            BlockCipherError::InvalidIvLength { .. } => io::ErrorKind::InvalidInput,
+            BlockCipherError::InvalidBlockLength { .. } => io::ErrorKind::InvalidInput,
+            BlockCipherError::OutputTooSmall { .. } => io::ErrorKind::InvalidInput,
+            BlockCipherError::BadPadding { .. } => io::ErrorKind::InvalidData,
+            BlockCipherError::CounterExhausted => io::ErrorKind::InvalidInput,
+            BlockCipherError::AuthenticationFailed => io::ErrorKind::InvalidData,
+            BlockCipherError::InvalidParameter(_) => io::ErrorKind::InvalidInput,
+            BlockCipherError::BackendFailure(_) => io::ErrorKind::Other,
+        };
+
+        io::Error::new(kind, err)
+    }
 }
@@ -0,0 +1,14 @@
+use thiserror::Error as ThisErr;
+
+#[derive(ThisErr, Debug, PartialEq, Eq)]
+pub enum FrameError {
+    #[error("truncated frame at byte offset {offset}, needed {needed} more bytes but only {available} are left")]
+    Truncated {
+        offset: usize,
+        needed: usize,
+        available: usize,
+    },
+
+    #[error("segment of {0} bytes does not fit a 32 bit length prefix")]
+    SegmentTooLong(usize),
+}
@@ -0,0 +1,10 @@
+use thiserror::Error as ThisErr;
+
+#[derive(ThisErr, Debug, PartialEq, Eq)]
+pub enum HexError {
+    #[error("input length must be a multiple of 2 (found {0})")]
+    InvalidInputLength(usize),
+
+    #[error("invalid character {found:?} at byte offset {index}")]
+    InvalidCharacter { index: usize, found: char },
+}
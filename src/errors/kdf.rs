@@ -0,0 +1,11 @@
+use thiserror::Error as ThisErr;
+use alloc::string::String;
+
+#[derive(Debug, ThisErr)]
+pub enum KdfError {
+    #[error("requested output length is too long, at most {max} bytes can be derived (found {got})")]
+    InvalidOutputLength { got: usize, max: usize },
+
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(String),
+}
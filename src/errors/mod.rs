@@ -1,2 +1,6 @@
+pub mod base32;
 pub mod base64;
 pub mod blockcipher;
+pub mod frame;
+pub mod hex;
+pub mod kdf;
@@ -0,0 +1,159 @@
+use super::sha512::H0_512 as IV;
+use crate::traits::hash::Hash;
+use crate::util::buffer::ArrayBuffer;
+use alloc::vec::Vec;
+
+/// Message word permutations, round i uses SIGMA[i % 10]
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+const ROUNDS: usize = 12;
+
+pub const BLAKE2B_BLOCKSIZE: usize = 128;
+pub const BLAKE2B_DIGEST_LEN: usize = 64;
+pub const BLAKE2B_KEYLEN_MAX: usize = 64;
+
+/// BLAKE2b as specified in RFC 7693, with an optional key and a digest of 1 to 64 bytes
+pub struct Blake2b {
+    state: [u64; 8],
+    buffer: ArrayBuffer<u8, BLAKE2B_BLOCKSIZE>,
+    len: u128,
+    out_len: usize,
+}
+
+impl Blake2b {
+    /// Create a new instance with a key of up to 64 bytes and a digest of out_len bytes.
+    /// An empty key results in the unkeyed hash, a keyed instance may be used as a MAC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is longer than 64 bytes or out_len is not between 1 and 64
+    pub fn with_key(key: &[u8], out_len: usize) -> Self {
+        assert!(
+            key.len() <= BLAKE2B_KEYLEN_MAX,
+            "key must be at most {} bytes (found {})",
+            BLAKE2B_KEYLEN_MAX,
+            key.len()
+        );
+        assert!(
+            (1..=BLAKE2B_DIGEST_LEN).contains(&out_len),
+            "output length must be between 1 and {} bytes (found {})",
+            BLAKE2B_DIGEST_LEN,
+            out_len
+        );
+
+        let mut state = IV;
+        state[0] ^= 0x01010000 ^ ((key.len() as u64) << 8) ^ out_len as u64;
+
+        let mut hash = Self {
+            state,
+            buffer: ArrayBuffer::new(),
+            len: 0,
+            out_len,
+        };
+
+        // The key is padded to a full block and processed like the first block of the message
+        if !key.is_empty() {
+            let mut block = [0u8; BLAKE2B_BLOCKSIZE];
+            block[..key.len()].copy_from_slice(key);
+            hash.update(&block);
+        }
+
+        hash
+    }
+}
+
+impl Hash for Blake2b {
+    const BLOCKSIZE: usize = BLAKE2B_BLOCKSIZE;
+    const DIGEST_LEN: usize = BLAKE2B_DIGEST_LEN;
+
+    /// Unkeyed BLAKE2b-512
+    fn new() -> Self {
+        Self::with_key(&[], BLAKE2B_DIGEST_LEN)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let mut consumed = 0;
+
+        while consumed < data.len() {
+            // The last block is compressed differently, so a full buffer is only
+            // compressed once it is known that more data follows
+            if self.buffer.is_full() {
+                self.len += BLAKE2B_BLOCKSIZE as u128;
+                compress(&mut self.state, &self.buffer.extract(), self.len, false);
+            }
+
+            consumed += self.buffer.push_slice(&data[consumed..]);
+        }
+    }
+
+    fn finalize(mut self) -> Vec<u8> {
+        self.len += self.buffer.len() as u128;
+        compress(&mut self.state, &self.buffer.extract(), self.len, true);
+
+        self.state
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .take(self.out_len)
+            .collect()
+    }
+}
+
+fn compress(state: &mut [u64; 8], block: &[u8; BLAKE2B_BLOCKSIZE], len: u128, last: bool) {
+    let mut m = [0u64; 16];
+    for (word, chunk) in m.iter_mut().zip(block.chunks_exact(8)) {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(chunk);
+        *word = u64::from_le_bytes(bytes);
+    }
+
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(state);
+    v[8..].copy_from_slice(&IV);
+    v[12] ^= len as u64;
+    v[13] ^= (len >> 64) as u64;
+
+    if last {
+        v[14] = !v[14];
+    }
+
+    for round in 0..ROUNDS {
+        let s = &SIGMA[round % 10];
+
+        g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+        g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+        g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+        g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+
+        g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+        g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+        g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+        g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+    }
+
+    for i in 0..8 {
+        state[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+/// The G mixing function
+fn g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
@@ -0,0 +1,47 @@
+use crate::util::buffer::ArrayBuffer;
+
+/// Merkle–Damgård message buffering and padding shared by the SHA family
+pub(crate) struct MerkleDamgard<const BLOCKSIZE: usize> {
+    buffer: ArrayBuffer<u8, BLOCKSIZE>,
+    len: u128,
+}
+
+impl<const B: usize> MerkleDamgard<B> {
+    pub(crate) fn new() -> Self {
+        Self {
+            buffer: ArrayBuffer::new(),
+            len: 0,
+        }
+    }
+
+    /// Buffers data and calls compress for every full block
+    pub(crate) fn update<F: FnMut(&[u8; B])>(&mut self, data: &[u8], mut compress: F) {
+        let mut consumed = 0;
+        self.len += data.len() as u128;
+
+        while consumed < data.len() {
+            consumed += self.buffer.push_slice(&data[consumed..]);
+
+            if self.buffer.is_full() {
+                compress(&self.buffer.extract());
+            }
+        }
+    }
+
+    /// Appends the padding: a single one bit, zeros and the message length in bits as a
+    /// big endian integer of length_bytes, then compresses the remaining blocks
+    pub(crate) fn finalize<F: FnMut(&[u8; B])>(mut self, length_bytes: usize, mut compress: F) {
+        let bits = (self.len << 3).to_be_bytes();
+
+        self.buffer.push(0x80);
+
+        if self.buffer.remaining() < length_bytes {
+            while self.buffer.push(0) {}
+            compress(&self.buffer.extract());
+        }
+
+        let mut last = self.buffer.extract();
+        last[B - length_bytes..].copy_from_slice(&bits[16 - length_bytes..]);
+        compress(&last);
+    }
+}
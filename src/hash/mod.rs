@@ -0,0 +1,6 @@
+mod md;
+pub mod blake2b;
+pub mod sha1;
+pub mod sha256;
+pub mod sha3;
+pub mod sha512;
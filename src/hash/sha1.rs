@@ -0,0 +1,81 @@
+use super::md::MerkleDamgard;
+use crate::traits::hash::Hash;
+use alloc::vec::Vec;
+
+const H0: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+pub const SHA1_BLOCKSIZE: usize = 64;
+pub const SHA1_DIGEST_LEN: usize = 20;
+
+/// SHA-1 as specified in FIPS 180-4
+///
+/// SHA-1 is broken with regards to collision resistance and only provided for interoperability,
+/// e.g. to verify legacy HMAC or PBKDF2 outputs. Do not use it for new designs
+pub struct Sha1 {
+    state: [u32; 5],
+    md: MerkleDamgard<SHA1_BLOCKSIZE>,
+}
+
+impl Hash for Sha1 {
+    const BLOCKSIZE: usize = SHA1_BLOCKSIZE;
+    const DIGEST_LEN: usize = SHA1_DIGEST_LEN;
+
+    fn new() -> Self {
+        Self {
+            state: H0,
+            md: MerkleDamgard::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let state = &mut self.state;
+        self.md.update(data, |block| compress(state, block));
+    }
+
+    fn finalize(mut self) -> Vec<u8> {
+        let state = &mut self.state;
+        self.md.finalize(8, |block| compress(state, block));
+
+        self.state.iter().flat_map(|word| word.to_be_bytes()).collect()
+    }
+}
+
+fn compress(state: &mut [u32; 5], block: &[u8; SHA1_BLOCKSIZE]) {
+    let mut w = [0u32; 80];
+
+    for (i, chunk) in block.chunks_exact(4).enumerate() {
+        w[i] = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+
+    for i in 16..80 {
+        w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = *state;
+
+    for (i, wi) in w.iter().enumerate() {
+        let (f, k) = match i {
+            0..=19 => ((b & c) | (!b & d), 0x5A827999),
+            20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+            40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+            _ => (b ^ c ^ d, 0xCA62C1D6),
+        };
+
+        let t = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(*wi);
+
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = t;
+    }
+
+    for (word, v) in state.iter_mut().zip([a, b, c, d, e]) {
+        *word = word.wrapping_add(v);
+    }
+}
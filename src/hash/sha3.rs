@@ -0,0 +1,174 @@
+use crate::traits::hash::Hash;
+use crate::util::buffer::ArrayBuffer;
+use alloc::vec::Vec;
+
+/// Round constants of the iota step
+const RC: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000,
+    0x000000000000808B, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A,
+    0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+/// Rotation offsets of the rho step, indexed by x + 5y
+const ROTATIONS: [u32; 25] = [
+    0, 1, 62, 28, 27,
+    36, 44, 6, 55, 20,
+    3, 10, 43, 25, 39,
+    41, 45, 15, 21, 8,
+    18, 2, 61, 56, 14,
+];
+
+/// Domain separation suffix of the SHA-3 hash functions, followed by the first bit of the padding
+const SHA3_SUFFIX: u8 = 0x06;
+
+pub const SHA3_256_BLOCKSIZE: usize = 136;
+pub const SHA3_256_DIGEST_LEN: usize = 32;
+pub const SHA3_512_BLOCKSIZE: usize = 72;
+pub const SHA3_512_DIGEST_LEN: usize = 64;
+
+/// SHA3-256 as specified in FIPS 202
+pub struct Sha3_256 {
+    sponge: Sponge<SHA3_256_BLOCKSIZE>,
+}
+
+impl Hash for Sha3_256 {
+    const BLOCKSIZE: usize = SHA3_256_BLOCKSIZE;
+    const DIGEST_LEN: usize = SHA3_256_DIGEST_LEN;
+
+    fn new() -> Self {
+        Self {
+            sponge: Sponge::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.sponge.absorb(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.sponge.squeeze(SHA3_SUFFIX, SHA3_256_DIGEST_LEN)
+    }
+}
+
+/// SHA3-512 as specified in FIPS 202
+pub struct Sha3_512 {
+    sponge: Sponge<SHA3_512_BLOCKSIZE>,
+}
+
+impl Hash for Sha3_512 {
+    const BLOCKSIZE: usize = SHA3_512_BLOCKSIZE;
+    const DIGEST_LEN: usize = SHA3_512_DIGEST_LEN;
+
+    fn new() -> Self {
+        Self {
+            sponge: Sponge::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.sponge.absorb(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.sponge.squeeze(SHA3_SUFFIX, SHA3_512_DIGEST_LEN)
+    }
+}
+
+/// Keccak sponge over Keccak-f[1600] with a rate of RATE bytes
+struct Sponge<const RATE: usize> {
+    state: [u64; 25],
+    buffer: ArrayBuffer<u8, RATE>,
+}
+
+impl<const RATE: usize> Sponge<RATE> {
+    fn new() -> Self {
+        Self {
+            state: [0; 25],
+            buffer: ArrayBuffer::new(),
+        }
+    }
+
+    /// Buffers data and permutes the state for every full block
+    fn absorb(&mut self, data: &[u8]) {
+        let mut consumed = 0;
+
+        while consumed < data.len() {
+            consumed += self.buffer.push_slice(&data[consumed..]);
+
+            if self.buffer.is_full() {
+                let block = self.buffer.extract();
+                self.absorb_block(&block);
+            }
+        }
+    }
+
+    fn absorb_block(&mut self, block: &[u8; RATE]) {
+        for (lane, chunk) in self.state.iter_mut().zip(block.chunks_exact(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            *lane ^= u64::from_le_bytes(bytes);
+        }
+
+        keccak_f(&mut self.state);
+    }
+
+    /// Appends the domain suffix and the pad10*1 padding, then squeezes len bytes of output
+    fn squeeze(mut self, suffix: u8, len: usize) -> Vec<u8> {
+        let position = self.buffer.len();
+        let mut last = self.buffer.extract();
+        last[position] ^= suffix;
+        last[RATE - 1] ^= 0x80;
+        self.absorb_block(&last);
+
+        let mut output = Vec::with_capacity(len);
+        loop {
+            let block = self.state.iter().flat_map(|lane| lane.to_le_bytes()).take(RATE);
+            output.extend(block.take(len - output.len()));
+
+            if output.len() == len {
+                return output;
+            }
+
+            keccak_f(&mut self.state);
+        }
+    }
+}
+
+/// The Keccak-f[1600] permutation, lanes are indexed by x + 5y
+fn keccak_f(a: &mut [u64; 25]) {
+    for rc in RC {
+        // theta
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        }
+
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                a[x + 5 * y] ^= d;
+            }
+        }
+
+        // rho and pi
+        let mut b = [0u64; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotate_left(ROTATIONS[x + 5 * y]);
+            }
+        }
+
+        // chi
+        for x in 0..5 {
+            for y in 0..5 {
+                a[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+            }
+        }
+
+        // iota
+        a[0] ^= rc;
+    }
+}
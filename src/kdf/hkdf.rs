@@ -0,0 +1,91 @@
+use crate::errors::kdf::KdfError;
+use crate::hash::sha256::Sha256;
+use crate::mac::hmac::Hmac;
+use crate::mem;
+use crate::traits::hash::Hash;
+use alloc::{vec, vec::Vec};
+
+const CONFIRM_LABEL: &[u8] = b"confirm";
+pub const CONFIRM_LEN: usize = 32;
+
+/// HKDF-Extract as specified in RFC 5869, returns a pseudorandom key of the hash length
+/// * 'salt' - Optional salt, an empty salt is replaced by a string of zeros of the hash length
+/// * 'ikm'  - Input keying material
+pub fn hkdf_extract<H: Hash>(salt: &[u8], ikm: &[u8]) -> Vec<u8> {
+    let zeros;
+    let salt = if salt.is_empty() {
+        zeros = vec![0; H::DIGEST_LEN];
+        &zeros
+    } else {
+        salt
+    };
+
+    // PRK = HMAC(salt, IKM)
+    Hmac::<H>::mac(salt, ikm)
+}
+
+/// HKDF-Expand as specified in RFC 5869
+/// * 'prk'  - Pseudorandom key of at least the hash length
+/// * 'info' - Optional context information, may be empty
+/// * 'len'  - Length of the output keying material, at most 255 times the hash length
+pub fn hkdf_expand<H: Hash>(prk: &[u8], info: &[u8], len: usize) -> Result<Vec<u8>, KdfError> {
+    let max = 255 * H::DIGEST_LEN;
+    if len > max {
+        return Err(KdfError::InvalidOutputLength { got: len, max });
+    }
+
+    let mut okm = Vec::with_capacity(len);
+    let mut t = Vec::new();
+    let mut counter = 1u8;
+
+    // T(i) = HMAC(PRK, T(i - 1) | info | i)
+    while okm.len() < len {
+        let mut hmac = Hmac::<H>::new(prk);
+        hmac.update(&t);
+        hmac.update(info);
+        hmac.update(&[counter]);
+        t = hmac.finalize();
+
+        let take = core::cmp::min(t.len(), len - okm.len());
+        okm.extend_from_slice(&t[..take]);
+        counter = counter.wrapping_add(1);
+    }
+
+    Ok(okm)
+}
+
+/// HKDF as specified in RFC 5869, HKDF-Extract followed by HKDF-Expand
+/// * 'salt' - Optional salt, may be empty
+/// * 'ikm'  - Input keying material
+/// * 'info' - Optional context information, may be empty
+/// * 'len'  - Length of the output keying material, at most 255 times the hash length
+pub fn hkdf<H: Hash>(salt: &[u8], ikm: &[u8], info: &[u8], len: usize) -> Result<Vec<u8>, KdfError> {
+    let mut prk = hkdf_extract::<H>(salt, ikm);
+    let okm = hkdf_expand::<H>(&prk, info, len);
+    mem::zeroize(&mut prk);
+
+    okm
+}
+
+/// Computes a key confirmation value HKDF-Expand(prk, "confirm" | label, 32) with HMAC-SHA256
+///
+/// Both parties of a key agreement can exchange this value to confirm they derived the same key.
+/// * 'prk'   - Pseudorandom key derived by the key agreement
+/// * 'label' - Additional context, eg. the role of the party, may be empty
+pub fn confirm(prk: &[u8], label: &[u8]) -> [u8; CONFIRM_LEN] {
+    let mut info = Vec::with_capacity(CONFIRM_LABEL.len() + label.len());
+    info.extend_from_slice(CONFIRM_LABEL);
+    info.extend_from_slice(label);
+
+    let okm = hkdf_expand::<Sha256>(prk, &info, CONFIRM_LEN)
+        .expect("confirmation length is below the HKDF output limit");
+
+    let mut tag = [0; CONFIRM_LEN];
+    tag.copy_from_slice(&okm);
+    tag
+}
+
+/// Checks a received key confirmation value in constant time
+pub fn verify_confirm(prk: &[u8], label: &[u8], tag: &[u8]) -> bool {
+    mem::memeq_s(&confirm(prk, label), tag)
+}
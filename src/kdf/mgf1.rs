@@ -0,0 +1,33 @@
+use crate::traits::hash::Hash;
+use alloc::vec::Vec;
+
+/// MGF1 mask generation function as specified in RFC 8017, appendix B.2.1
+///
+/// Hashes the seed followed by a big endian 32 bit counter, concatenating the digests until length bytes are produced.
+/// * 'seed'   - Seed the mask is generated from
+/// * 'length' - Length of the mask, at most 2^32 times the hash length
+///
+/// Panics if length exceeds 2^32 times the hash length
+pub fn mgf1<H: Hash>(seed: &[u8], length: usize) -> Vec<u8> {
+    assert!(
+        (length as u128) <= (H::DIGEST_LEN as u128) << 32,
+        "mask length exceeds 2^32 times the hash length"
+    );
+
+    let mut mask = Vec::with_capacity(length);
+    let mut counter = 0u32;
+
+    // T = T | Hash(seed | C)
+    while mask.len() < length {
+        let mut hash = H::new();
+        hash.update(seed);
+        hash.update(&counter.to_be_bytes());
+        let digest = hash.finalize();
+
+        let take = core::cmp::min(digest.len(), length - mask.len());
+        mask.extend_from_slice(&digest[..take]);
+        counter = counter.wrapping_add(1);
+    }
+
+    mask
+}
@@ -0,0 +1,4 @@
+pub mod hkdf;
+pub mod mgf1;
+pub mod pbkdf2;
+pub mod scrypt;
@@ -0,0 +1,39 @@
+use crate::errors::kdf::KdfError;
+use crate::mac::hmac::Hmac;
+use crate::mem;
+use crate::traits::hash::Hash;
+use alloc::vec::Vec;
+
+/// PBKDF2 with HMAC as pseudorandom function, as specified in RFC 8018
+/// * 'password'   - Password, used as HMAC key
+/// * 'salt'       - Salt, may be empty
+/// * 'iterations' - Iteration count, at least 1
+/// * 'len'        - Length of the derived key
+pub fn pbkdf2<H: Hash>(password: &[u8], salt: &[u8], iterations: u32, len: usize) -> Result<Vec<u8>, KdfError> {
+    if iterations == 0 {
+        return Err(KdfError::InvalidParameter("iteration count must be at least 1".into()));
+    }
+
+    let mut dk = Vec::with_capacity(len);
+    let mut index = 1u32;
+
+    // T(i) = U_1 ^ U_2 ^ ... ^ U_c, U_1 = HMAC(P, S | i), U_j = HMAC(P, U_(j - 1))
+    while dk.len() < len {
+        let mut hmac = Hmac::<H>::new(password);
+        hmac.update(salt);
+        hmac.update(&index.to_be_bytes());
+        let mut u = hmac.finalize();
+        let mut t = u.clone();
+
+        for _ in 1..iterations {
+            u = Hmac::<H>::mac(password, &u);
+            mem::xor_buffers_unchecked(&mut t, &u);
+        }
+
+        let take = core::cmp::min(t.len(), len - dk.len());
+        dk.extend_from_slice(&t[..take]);
+        index += 1;
+    }
+
+    Ok(dk)
+}
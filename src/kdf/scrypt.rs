@@ -0,0 +1,150 @@
+use super::pbkdf2::pbkdf2;
+use crate::cipher::stream::salsa20;
+use crate::errors::kdf::KdfError;
+use crate::hash::sha256::Sha256;
+use crate::mem;
+use alloc::{format, vec, vec::Vec};
+use core::convert::TryFrom;
+
+/// Size of a Salsa20 block in 32 bit words
+const SALSA_WORDS: usize = 16;
+
+/// scrypt as specified in RFC 7914
+/// * 'password' - Password
+/// * 'salt'     - Salt, may be empty
+/// * 'n'        - CPU/memory cost, a power of two greater than 1
+/// * 'r'        - Block size, at least 1
+/// * 'p'        - Parallelization, at least 1
+/// * 'len'      - Length of the derived key
+///
+/// The memory required is roughly 128 * r * n bytes
+pub fn scrypt(password: &[u8], salt: &[u8], n: u64, r: u32, p: u32, len: usize) -> Result<Vec<u8>, KdfError> {
+    if n < 2 || !n.is_power_of_two() {
+        return Err(KdfError::InvalidParameter(format!(
+            "cost parameter must be a power of two greater than 1 (found {})",
+            n
+        )));
+    }
+
+    if r == 0 || p == 0 {
+        return Err(KdfError::InvalidParameter(format!(
+            "block size and parallelization must be at least 1 (found r = {}, p = {})",
+            r, p
+        )));
+    }
+
+    let (r, p) = (r as usize, p as usize);
+    let block_words = 32 * r;
+
+    let too_large = || KdfError::InvalidParameter("parameters exceed the addressable memory".into());
+    let n_usize = usize::try_from(n).map_err(|_| too_large())?;
+    let memory_words = n_usize.checked_mul(block_words).ok_or_else(too_large)?;
+    let b_len = p.checked_mul(4 * block_words).ok_or_else(too_large)?;
+
+    let mut b = pbkdf2::<Sha256>(password, salt, 1, b_len)?;
+
+    let mut x = vec![0u32; block_words];
+    let mut v = vec![0u32; memory_words];
+
+    for chunk in b.chunks_exact_mut(4 * block_words) {
+        for (word, bytes) in x.iter_mut().zip(chunk.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+
+        ro_mix(&mut x, &mut v, n_usize);
+
+        for (bytes, word) in chunk.chunks_exact_mut(4).zip(x.iter()) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    let dk = pbkdf2::<Sha256>(password, &b, 1, len);
+
+    mem::zeroize(&mut b);
+    mem::zeroize(&mut x);
+    mem::zeroize(&mut v);
+
+    dk
+}
+
+/// Sequential memory-hard mixing of a single block, v is scratch space of n blocks
+fn ro_mix(x: &mut [u32], v: &mut [u32], n: usize) {
+    let block_words = x.len();
+    let mut y = vec![0u32; block_words];
+
+    for i in 0..n {
+        v[i * block_words..(i + 1) * block_words].copy_from_slice(x);
+        block_mix(x, &mut y);
+    }
+
+    for _ in 0..n {
+        let j = integerify(x) & (n - 1);
+        mem::xor_buffers_unchecked(x, &v[j * block_words..(j + 1) * block_words]);
+        block_mix(x, &mut y);
+    }
+
+    mem::zeroize(&mut y);
+}
+
+/// Interprets the first 64 bits of the last Salsa20 block as a little endian integer
+fn integerify(x: &[u32]) -> usize {
+    let last = x.len() - SALSA_WORDS;
+    (((x[last + 1] as u64) << 32) | x[last] as u64) as usize
+}
+
+/// Mixes the 2r Salsa20 blocks of b, using y as scratch space of the same size
+fn block_mix(b: &mut [u32], y: &mut [u32]) {
+    let blocks = b.len() / SALSA_WORDS;
+    let half = blocks / 2;
+
+    let mut x = [0u32; SALSA_WORDS];
+    x.copy_from_slice(&b[(blocks - 1) * SALSA_WORDS..]);
+
+    // Even blocks go to the first half of the output, odd blocks to the second
+    for i in 0..blocks {
+        mem::xor_buffers_unchecked(&mut x, &b[i * SALSA_WORDS..(i + 1) * SALSA_WORDS]);
+        salsa20_8(&mut x);
+
+        let position = (i / 2) + (i % 2) * half;
+        y[position * SALSA_WORDS..(position + 1) * SALSA_WORDS].copy_from_slice(&x);
+    }
+
+    b.copy_from_slice(y);
+}
+
+/// The Salsa20/8 core, 8 rounds of Salsa20 followed by the feedforward of the input
+fn salsa20_8(block: &mut [u32; SALSA_WORDS]) {
+    salsa20::core(block, 8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Salsa20/8 core example from RFC 7914, section 8
+    #[test]
+    fn test_salsa20_8() {
+        let input: [u8; 64] = [
+            0x7e, 0x87, 0x9a, 0x21, 0x4f, 0x3e, 0xc9, 0x86, 0x7c, 0xa9, 0x40, 0xe6, 0x41, 0x71, 0x8f, 0x26,
+            0xba, 0xee, 0x55, 0x5b, 0x8c, 0x61, 0xc1, 0xb5, 0x0d, 0xf8, 0x46, 0x11, 0x6d, 0xcd, 0x3b, 0x1d,
+            0xee, 0x24, 0xf3, 0x19, 0xdf, 0x9b, 0x3d, 0x85, 0x14, 0x12, 0x1e, 0x4b, 0x5a, 0xc5, 0xaa, 0x32,
+            0x76, 0x02, 0x1d, 0x29, 0x09, 0xc7, 0x48, 0x29, 0xed, 0xeb, 0xc6, 0x8d, 0xb8, 0xb8, 0xc2, 0x5e,
+        ];
+        let expected: [u8; 64] = [
+            0xa4, 0x1f, 0x85, 0x9c, 0x66, 0x08, 0xcc, 0x99, 0x3b, 0x81, 0xca, 0xcb, 0x02, 0x0c, 0xef, 0x05,
+            0x04, 0x4b, 0x21, 0x81, 0xa2, 0xfd, 0x33, 0x7d, 0xfd, 0x7b, 0x1c, 0x63, 0x96, 0x68, 0x2f, 0x29,
+            0xb4, 0x39, 0x31, 0x68, 0xe3, 0xc9, 0xe6, 0xbc, 0xfe, 0x6b, 0xc5, 0xb7, 0xa0, 0x6d, 0x96, 0xba,
+            0xe4, 0x24, 0xcc, 0x10, 0x2c, 0x91, 0x74, 0x5c, 0x24, 0xad, 0x67, 0x3d, 0xc7, 0x61, 0x8f, 0x81,
+        ];
+
+        let mut block = [0u32; SALSA_WORDS];
+        for (word, bytes) in block.iter_mut().zip(input.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+
+        salsa20_8(&mut block);
+
+        let output: Vec<u8> = block.iter().flat_map(|word| word.to_le_bytes()).collect();
+        assert_eq!(expected.as_ref(), output);
+    }
+}
@@ -1,8 +1,28 @@
+//! Without the default `std` feature the crate is `no_std`. Everything but the allocation free helpers in `mem`
+//! and `math` needs a global allocator and the `alloc` feature. Threading, `std::io` integration, randomness from
+//! the operating system and CBC IV reuse detection are only available with `std`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
 pub mod prelude;
+#[cfg(feature = "alloc")]
 pub mod cipher;
+#[cfg(feature = "alloc")]
 pub mod encode;
+#[cfg(feature = "alloc")]
 pub mod errors;
+#[cfg(feature = "alloc")]
+pub mod hash;
+#[cfg(feature = "alloc")]
+pub mod kdf;
+#[cfg(feature = "alloc")]
+pub mod mac;
 pub mod math;
 pub mod mem;
+#[cfg(feature = "alloc")]
 pub mod traits;
+#[cfg(feature = "alloc")]
 pub mod util;
@@ -0,0 +1,145 @@
+use crate::mem;
+use crate::traits::cipher::primitive::BlockCipherPrimitiveEncryption as PrimitiveEncryption;
+use crate::util::buffer::ArrayBuffer;
+
+/// CMAC (OMAC1) provider
+///
+/// Computes a message authentication code based on a Primitive T eg. Aes, as specified in NIST SP 800-38B.
+/// Only primitives with a blocksize of 64 or 128 bit are supported.
+pub struct Cmac<T: PrimitiveEncryption<BLOCKSIZE>, const BLOCKSIZE: usize> {
+    primitive: T,
+    k1: [u8; BLOCKSIZE],
+    k2: [u8; BLOCKSIZE],
+    state: [u8; BLOCKSIZE],
+    buffer: ArrayBuffer<u8, BLOCKSIZE>,
+}
+
+impl<T: PrimitiveEncryption<B>, const B: usize> Cmac<T, B> {
+    /// Create a new instance from a Cipher primitive
+    ///
+    /// Panics if the blocksize of the primitive is neither 8 nor 16 bytes
+    pub fn new(primitive: T) -> Self {
+        let (k1, k2) = subkeys(&primitive);
+
+        Self {
+            primitive,
+            k1,
+            k2,
+            state: [0; B],
+            buffer: ArrayBuffer::new(),
+        }
+    }
+
+    /// Feeds data into the MAC, may be called multiple times
+    pub fn update(&mut self, data: &[u8]) {
+        let mut consumed = 0;
+
+        // The last block is always kept in the buffer, since it has to be treated with a subkey on finalization
+        while consumed < data.len() {
+            if self.buffer.is_full() {
+                self.process_buffer();
+            }
+
+            consumed += self.buffer.push_slice(&data[consumed..]);
+        }
+    }
+
+    fn process_buffer(&mut self) {
+        let block = self.buffer.extract();
+        mem::xor_buffers(&mut self.state, &block);
+        self.primitive.encrypt(&mut self.state);
+    }
+
+    /// Consumes the MAC and returns the tag
+    pub fn finalize(mut self) -> [u8; B] {
+        let subkey = if self.buffer.is_full() {
+            self.k1
+        } else {
+            // Pad with a single one bit followed by zeros
+            self.buffer.push(0x80);
+            self.k2
+        };
+
+        let mut last = self.buffer.extract();
+        mem::xor_buffers(&mut last, &subkey);
+        mem::xor_buffers(&mut self.state, &last);
+        self.primitive.encrypt(&mut self.state);
+
+        self.state
+    }
+}
+
+/// Derives the subkeys K1 and K2 by encrypting the zero block and doubling
+fn subkeys<T: PrimitiveEncryption<B>, const B: usize>(primitive: &T) -> ([u8; B], [u8; B]) {
+    let mut l = [0; B];
+    primitive.encrypt(&mut l);
+
+    let k1 = double(&l);
+    let k2 = double(&k1);
+
+    (k1, k2)
+}
+
+/// Multiplies a big endian block by x in GF(2^(8*B))
+fn double<const B: usize>(block: &[u8; B]) -> [u8; B] {
+    let rb = match B {
+        8 => 0x1B,
+        16 => 0x87,
+        _ => panic!("CMAC is only defined for blocksizes of 8 or 16 bytes (found {})", B),
+    };
+
+    let mut doubled = [0; B];
+    let mut carry = 0;
+
+    for i in (0..B).rev() {
+        doubled[i] = (block[i] << 1) | carry;
+        carry = block[i] >> 7;
+    }
+
+    doubled[B - 1] ^= rb * carry;
+    doubled
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::cipher::block::primitive::aes::Aes;
+
+    fn decode(s: &str) -> Vec<u8> {
+        use crate::encode::HexEncoder;
+        HexEncoder::builder().decode(s)
+    }
+
+    // Subkeys from NIST SP 800-38B, D.1
+    #[test]
+    fn test_subkeys_aes128() {
+        let key = decode("2b7e1516 28aed2a6 abf71588 09cf4f3c");
+        let (k1, k2) = subkeys(&Aes::new(&key).unwrap());
+
+        assert_eq!(decode("fbeed618 35713366 7c85e08f 7236a8de"), k1);
+        assert_eq!(decode("f7ddac30 6ae266cc f90bc11e e46d513b"), k2);
+    }
+
+    #[test]
+    fn test_double_without_carry() {
+        let mut block = [0; 16];
+        block[15] = 0x01;
+
+        let mut expected = [0; 16];
+        expected[15] = 0x02;
+
+        assert_eq!(expected, double(&block));
+    }
+
+    #[test]
+    fn test_double_with_carry() {
+        let mut block = [0; 16];
+        block[0] = 0x80;
+
+        let mut expected = [0; 16];
+        expected[15] = 0x87;
+
+        assert_eq!(expected, double(&block));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::mem;
+use crate::util::buffer::ArrayBuffer;
+use crate::util::gf128::gf128_mul_reflected;
+
+pub const GHASH_BLOCKSIZE: usize = 16;
+pub const GHASH_KEYLEN: usize = 16;
+pub const GHASH_TAGLEN: usize = 16;
+
+/// GHASH universal hash function
+///
+/// Authenticates associated data followed by data in GF(2^128) as specified in NIST SP 800-38D.
+/// Each section is padded with zeros to a multiple of 16 bytes and the bit lengths of both
+/// are authenticated in a final length block. This is the authentication part of GCM and GMAC,
+/// on its own the hash subkey H must be kept secret and the output must be encrypted.
+pub struct Ghash {
+    h: [u8; GHASH_KEYLEN],
+    state: [u8; GHASH_BLOCKSIZE],
+    buffer: ArrayBuffer<u8, GHASH_BLOCKSIZE>,
+    aad_len: u64,
+    data_len: u64,
+}
+
+impl Ghash {
+    /// Create a new instance from the hash subkey H, which GCM derives by encrypting the zero block
+    pub fn new(h: &[u8; GHASH_KEYLEN]) -> Self {
+        Self {
+            h: *h,
+            state: [0; GHASH_BLOCKSIZE],
+            buffer: ArrayBuffer::new(),
+            aad_len: 0,
+            data_len: 0,
+        }
+    }
+
+    /// Feeds associated data into the hash, may be called multiple times
+    ///
+    /// Panics if data has already been fed with `update`
+    pub fn update_aad(&mut self, aad: &[u8]) {
+        assert!(self.data_len == 0, "associated data must precede the data");
+
+        self.aad_len += aad.len() as u64;
+        self.absorb(aad);
+    }
+
+    /// Feeds data into the hash, may be called multiple times. The associated data ends with the first call
+    pub fn update(&mut self, data: &[u8]) {
+        if self.data_len == 0 {
+            self.pad();
+        }
+
+        self.data_len += data.len() as u64;
+        self.absorb(data);
+    }
+
+    /// Consumes the hash and returns the output, authenticating the bit lengths of both sections
+    pub fn finalize(mut self) -> [u8; GHASH_TAGLEN] {
+        self.pad();
+
+        let mut lengths = [0; GHASH_BLOCKSIZE];
+        lengths[..8].copy_from_slice(&(self.aad_len * 8).to_be_bytes());
+        lengths[8..].copy_from_slice(&(self.data_len * 8).to_be_bytes());
+        self.process_block(&lengths);
+
+        mem::zeroize(&mut self.h);
+        self.state
+    }
+
+    fn absorb(&mut self, data: &[u8]) {
+        let mut consumed = 0;
+
+        while consumed < data.len() {
+            consumed += self.buffer.push_slice(&data[consumed..]);
+
+            if self.buffer.is_full() {
+                let block = self.buffer.extract();
+                self.process_block(&block);
+            }
+        }
+    }
+
+    /// Completes a partially filled block with zeros
+    fn pad(&mut self) {
+        if !self.buffer.is_empty() {
+            let block = self.buffer.extract();
+            self.process_block(&block);
+        }
+    }
+
+    fn process_block(&mut self, block: &[u8; GHASH_BLOCKSIZE]) {
+        mem::xor_buffers(&mut self.state, block);
+        self.state = gf128_mul_reflected(&self.state, &self.h);
+    }
+}
@@ -0,0 +1,76 @@
+use super::ghash::Ghash;
+use crate::errors::blockcipher::BlockCipherError;
+use crate::mem;
+use crate::traits::cipher::primitive::BlockCipherPrimitiveEncryption as PrimitiveEncryption;
+
+pub const GMAC_BLOCKSIZE: usize = 16;
+pub const GMAC_NONCELEN: usize = 12;
+pub const GMAC_TAGLEN: usize = 16;
+
+/// GMAC provider
+///
+/// Computes a message authentication code based on a Primitive T eg. Aes, as specified in NIST SP 800-38D.
+/// This is GCM authenticating its input as associated data without any plaintext.
+/// A nonce must never be used for more than one message under the same key.
+pub struct Gmac {
+    ghash: Ghash,
+    mask: [u8; GMAC_BLOCKSIZE],
+}
+
+impl Gmac {
+    /// Create a new instance from a Cipher primitive and a nonce, which should be 12 bytes long.
+    /// Other lengths are hashed into the initial counter block as for GCM.
+    ///
+    /// Fails with `BlockCipherError::InvalidIvLength` if the nonce is empty
+    pub fn new<T: PrimitiveEncryption<GMAC_BLOCKSIZE>>(primitive: T, nonce: &[u8]) -> Result<Self, BlockCipherError> {
+        if nonce.is_empty() {
+            return Err(BlockCipherError::InvalidIvLength {
+                got: 0,
+                expected: GMAC_NONCELEN,
+            });
+        }
+
+        let mut h = [0; GMAC_BLOCKSIZE];
+        primitive.encrypt(&mut h);
+
+        let mut mask = if nonce.len() == GMAC_NONCELEN {
+            let mut j0 = [0; GMAC_BLOCKSIZE];
+            j0[..GMAC_NONCELEN].copy_from_slice(nonce);
+            j0[GMAC_BLOCKSIZE - 1] = 1;
+            j0
+        } else {
+            let mut ghash = Ghash::new(&h);
+            ghash.update(nonce);
+            ghash.finalize()
+        };
+        primitive.encrypt(&mut mask);
+
+        let ghash = Ghash::new(&h);
+        mem::zeroize(&mut h);
+
+        Ok(Self { ghash, mask })
+    }
+
+    /// Computes the tag of data in one go
+    pub fn mac<T: PrimitiveEncryption<GMAC_BLOCKSIZE>>(
+        primitive: T,
+        nonce: &[u8],
+        data: &[u8],
+    ) -> Result<[u8; GMAC_TAGLEN], BlockCipherError> {
+        let mut gmac = Self::new(primitive, nonce)?;
+        gmac.update(data);
+        Ok(gmac.finalize())
+    }
+
+    /// Feeds data into the MAC, may be called multiple times
+    pub fn update(&mut self, data: &[u8]) {
+        self.ghash.update_aad(data);
+    }
+
+    /// Consumes the MAC and returns the tag
+    pub fn finalize(self) -> [u8; GMAC_TAGLEN] {
+        let mut tag = self.ghash.finalize();
+        mem::xor_buffers(&mut tag, &self.mask);
+        tag
+    }
+}
@@ -0,0 +1,59 @@
+use crate::mem;
+use crate::traits::hash::Hash;
+use alloc::vec::Vec;
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5C;
+
+/// HMAC provider
+///
+/// Computes a keyed message authentication code based on a Hash H eg. Sha256, as specified in RFC 2104
+pub struct Hmac<H: Hash> {
+    inner: H,
+    outer_key: Vec<u8>,
+}
+
+impl<H: Hash> Hmac<H> {
+    /// Create a new instance from a key of arbitrary length
+    pub fn new(key: &[u8]) -> Self {
+        let mut block_key = if key.len() > H::BLOCKSIZE {
+            H::digest(key)
+        } else {
+            key.to_vec()
+        };
+        block_key.resize(H::BLOCKSIZE, 0);
+
+        let mut inner_key: Vec<u8> = block_key.iter().map(|b| b ^ IPAD).collect();
+        let outer_key = block_key.iter().map(|b| b ^ OPAD).collect();
+
+        let mut inner = H::new();
+        inner.update(&inner_key);
+
+        mem::zeroize(&mut block_key);
+        mem::zeroize(&mut inner_key);
+
+        Self { inner, outer_key }
+    }
+
+    /// Computes the MAC of data in one go
+    pub fn mac(key: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut hmac = Self::new(key);
+        hmac.update(data);
+        hmac.finalize()
+    }
+
+    /// Feeds data into the MAC, may be called multiple times
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data)
+    }
+
+    /// Consumes the MAC and returns the tag
+    pub fn finalize(mut self) -> Vec<u8> {
+        let mut outer = H::new();
+        outer.update(&self.outer_key);
+        mem::zeroize(&mut self.outer_key);
+
+        outer.update(&self.inner.finalize());
+        outer.finalize()
+    }
+}
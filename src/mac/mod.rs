@@ -0,0 +1,5 @@
+pub mod cmac;
+pub mod ghash;
+pub mod gmac;
+pub mod hmac;
+pub mod poly1305;
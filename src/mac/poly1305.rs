@@ -0,0 +1,163 @@
+use crate::util::buffer::ArrayBuffer;
+
+pub const POLY1305_KEYLEN: usize = 32;
+pub const POLY1305_BLOCKSIZE: usize = 16;
+pub const POLY1305_TAGLEN: usize = 16;
+
+const LIMB_MASK: u32 = 0x3ffffff;
+
+/// Poly1305 one-time authenticator
+///
+/// Computes a message authentication code in the field modulo 2^130 - 5 as specified in RFC 8439.
+/// A key must never be used for more than one message.
+pub struct Poly1305 {
+    r: [u32; 5],
+    s: [u32; 4],
+    h: [u32; 5],
+    buffer: ArrayBuffer<u8, POLY1305_BLOCKSIZE>,
+}
+
+impl Poly1305 {
+    /// Create a new instance from a 32 byte one-time key
+    pub fn new(key: &[u8; POLY1305_KEYLEN]) -> Self {
+        // Clamp r, the accumulator is kept in five 26 bit limbs
+        let r = [
+            le32(&key[0..]) & 0x3ffffff,
+            (le32(&key[3..]) >> 2) & 0x3ffff03,
+            (le32(&key[6..]) >> 4) & 0x3ffc0ff,
+            (le32(&key[9..]) >> 6) & 0x3f03fff,
+            (le32(&key[12..]) >> 8) & 0x00fffff,
+        ];
+
+        let s = [le32(&key[16..]), le32(&key[20..]), le32(&key[24..]), le32(&key[28..])];
+
+        Self {
+            r,
+            s,
+            h: [0; 5],
+            buffer: ArrayBuffer::new(),
+        }
+    }
+
+    /// Computes the tag of data in one go
+    pub fn mac(key: &[u8; POLY1305_KEYLEN], data: &[u8]) -> [u8; POLY1305_TAGLEN] {
+        let mut poly = Self::new(key);
+        poly.update(data);
+        poly.finalize()
+    }
+
+    /// Feeds data into the MAC, may be called multiple times
+    pub fn update(&mut self, data: &[u8]) {
+        let mut consumed = 0;
+
+        while consumed < data.len() {
+            consumed += self.buffer.push_slice(&data[consumed..]);
+
+            if self.buffer.is_full() {
+                let block = self.buffer.extract();
+                self.process_block(&block, 1 << 24);
+            }
+        }
+    }
+
+    /// Consumes the MAC and returns the tag
+    pub fn finalize(mut self) -> [u8; POLY1305_TAGLEN] {
+        if !self.buffer.is_empty() {
+            // The final partial block is padded with a single one byte followed by zeros instead of the high bit
+            self.buffer.push(0x01);
+            let block = self.buffer.extract();
+            self.process_block(&block, 0);
+        }
+
+        let mut h = self.h;
+
+        // Fully carry h
+        for i in 1..4 {
+            let c = h[i] >> 26;
+            h[i] &= LIMB_MASK;
+            h[i + 1] += c;
+        }
+
+        let c = h[4] >> 26;
+        h[4] &= LIMB_MASK;
+        h[0] += c * 5;
+
+        let c = h[0] >> 26;
+        h[0] &= LIMB_MASK;
+        h[1] += c;
+
+        // Compute g = h + -p
+        let mut g = [0u32; 5];
+        let mut c = 5;
+
+        for (g, h) in g.iter_mut().zip(h.iter()).take(4) {
+            *g = h + c;
+            c = *g >> 26;
+            *g &= LIMB_MASK;
+        }
+
+        g[4] = (h[4] + c).wrapping_sub(1 << 26);
+
+        // Select h if h < p, or g if h >= p, without branching
+        let mask = (g[4] >> 31).wrapping_sub(1);
+
+        for (h, g) in h.iter_mut().zip(g.iter()) {
+            *h = (*h & !mask) | (g & mask);
+        }
+
+        // h = h % 2^128
+        let words = [
+            h[0] | (h[1] << 26),
+            (h[1] >> 6) | (h[2] << 20),
+            (h[2] >> 12) | (h[3] << 14),
+            (h[3] >> 18) | (h[4] << 8),
+        ];
+
+        // tag = (h + s) % 2^128
+        let mut tag = [0; POLY1305_TAGLEN];
+        let mut carry = 0u64;
+
+        for (i, (word, s)) in words.iter().zip(self.s.iter()).enumerate() {
+            let sum = *word as u64 + *s as u64 + carry;
+            tag[4 * i..4 * i + 4].copy_from_slice(&(sum as u32).to_le_bytes());
+            carry = sum >> 32;
+        }
+
+        tag
+    }
+
+    /// Adds a block to the accumulator and multiplies by r, hibit is the bit above the 128 message bits
+    fn process_block(&mut self, block: &[u8; POLY1305_BLOCKSIZE], hibit: u32) {
+        let [r0, r1, r2, r3, r4] = self.r.map(|r| r as u64);
+        let [s1, s2, s3, s4] = [r1 * 5, r2 * 5, r3 * 5, r4 * 5];
+
+        let h0 = (self.h[0] + (le32(&block[0..]) & LIMB_MASK)) as u64;
+        let h1 = (self.h[1] + ((le32(&block[3..]) >> 2) & LIMB_MASK)) as u64;
+        let h2 = (self.h[2] + ((le32(&block[6..]) >> 4) & LIMB_MASK)) as u64;
+        let h3 = (self.h[3] + ((le32(&block[9..]) >> 6) & LIMB_MASK)) as u64;
+        let h4 = (self.h[4] + ((le32(&block[12..]) >> 8) | hibit)) as u64;
+
+        let d0 = h0 * r0 + h1 * s4 + h2 * s3 + h3 * s2 + h4 * s1;
+        let mut d1 = h0 * r1 + h1 * r0 + h2 * s4 + h3 * s3 + h4 * s2;
+        let mut d2 = h0 * r2 + h1 * r1 + h2 * r0 + h3 * s4 + h4 * s3;
+        let mut d3 = h0 * r3 + h1 * r2 + h2 * r1 + h3 * r0 + h4 * s4;
+        let mut d4 = h0 * r4 + h1 * r3 + h2 * r2 + h3 * r1 + h4 * r0;
+
+        // Partial reduction modulo 2^130 - 5
+        let mask = LIMB_MASK as u64;
+        d1 += d0 >> 26;
+        d2 += d1 >> 26;
+        d3 += d2 >> 26;
+        d4 += d3 >> 26;
+
+        let mut h0 = (d0 & mask) + (d4 >> 26) * 5;
+        let h1 = (d1 & mask) + (h0 >> 26);
+        h0 &= mask;
+
+        self.h = [h0 as u32, h1 as u32, (d2 & mask) as u32, (d3 & mask) as u32, (d4 & mask) as u32];
+    }
+}
+
+fn le32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
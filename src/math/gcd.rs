@@ -1,4 +1,4 @@
-use std::ops::{Div, Mul, Rem, Sub};
+use core::ops::{Div, Mul, Rem, Sub};
 
 pub trait ExtendedGcd:
     Copy
@@ -73,6 +73,7 @@ impl ExtendedGcd for isize {
     const ONE: Self = 1;
 }
 
+#[allow(dead_code)]
 mod gf {
 
     pub fn multiplicative_inverse(sel: u8) -> u8 {
@@ -141,10 +142,9 @@ mod tests {
 
     #[test]
     fn testing() {
-        let poly = 0x1B;
         let a = 0x1B;
         let b = 148;
-        let (gcd, bez1, bez2) = i64::extended_gcd(b.clone(), a.clone());
+        let (gcd, bez1, bez2) = i64::extended_gcd(b, a);
         println!("{:?} {:?} {:?}", gcd, bez1, bez2);
         println!("{}", gf::mul(b as u8, gcd as u8));
     }
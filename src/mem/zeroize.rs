@@ -1,4 +1,4 @@
-use std::ops::{Drop, Deref, DerefMut};
+use core::ops::{Drop, Deref, DerefMut};
 
 pub fn zeroize<T: Default>(x: &mut [T]) {
     for element in x {
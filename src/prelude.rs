@@ -1,3 +1,5 @@
+pub use crate::cipher::aead::Aead;
+
 pub use crate::traits::{
     cipher::block::{
         BlockCipherEncryption, 
@@ -6,8 +8,18 @@ pub use crate::traits::{
     cipher::primitive::{
         BlockCipherPrimitiveEncryption, 
         BlockCipherPrimitiveDecryption, 
-        BlockCipherPrimitiveInfo
+        BlockCipherPrimitiveInfo,
+        BlockCipherRuntimeInfo
     },
+    cipher::finalize::{
+        BlockCipherResult,
+        Finalizable
+    },
+    cipher::oneshot::{
+        Encryptor,
+        Decryptor
+    },
+    cipher::sink::ByteSink,
     cipher::stream::{
         StreamCipherEncryption,
         StreamCipherDecryption
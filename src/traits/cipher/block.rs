@@ -1,7 +1,111 @@
+use crate::errors::blockcipher::BlockCipherError;
+use alloc::boxed::Box;
+use core::convert::TryInto;
+
 pub trait BlockCipherEncryption<const BLOCKSIZE: usize> {
     fn encrypt(&mut self, data: &mut [u8; BLOCKSIZE]);
+
+    /// Fallible variant of encrypt, surfacing errors of the underlying primitive
+    fn try_encrypt(&mut self, data: &mut [u8; BLOCKSIZE]) -> Result<(), BlockCipherError> {
+        self.encrypt(data);
+        Ok(())
+    }
+
+    /// Encrypts a slice of exactly one block, eg. when the cipher is used as a trait object
+    ///
+    /// Fails with `BlockCipherError::InvalidBlockLength` if the slice is not BLOCKSIZE bytes long
+    fn encrypt_slice(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        let got = data.len();
+        let block: &mut [u8; BLOCKSIZE] = data
+            .try_into()
+            .map_err(|_| BlockCipherError::InvalidBlockLength { got, expected: BLOCKSIZE })?;
+
+        self.try_encrypt(block)
+    }
+
+    /// Encrypts a final block shorter than BLOCKSIZE, which only stream-like modes such as CTR, CFB or OFB support
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` by default
+    fn encrypt_partial(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        Err(BlockCipherError::IncompleteBlock(BLOCKSIZE - data.len() % BLOCKSIZE))
+    }
 }
 
 pub trait BlockCipherDecryption<const BLOCKSIZE: usize> {
     fn decrypt(&mut self, data: &mut [u8; BLOCKSIZE]);
+
+    /// Fallible variant of decrypt, surfacing errors of the underlying primitive
+    fn try_decrypt(&mut self, data: &mut [u8; BLOCKSIZE]) -> Result<(), BlockCipherError> {
+        self.decrypt(data);
+        Ok(())
+    }
+
+    /// Decrypts a slice of exactly one block, eg. when the cipher is used as a trait object
+    ///
+    /// Fails with `BlockCipherError::InvalidBlockLength` if the slice is not BLOCKSIZE bytes long
+    fn decrypt_slice(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        let got = data.len();
+        let block: &mut [u8; BLOCKSIZE] = data
+            .try_into()
+            .map_err(|_| BlockCipherError::InvalidBlockLength { got, expected: BLOCKSIZE })?;
+
+        self.try_decrypt(block)
+    }
+
+    /// Decrypts a final block shorter than BLOCKSIZE, which only stream-like modes such as CTR, CFB or OFB support
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` by default
+    fn decrypt_partial(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        Err(BlockCipherError::IncompleteBlock(BLOCKSIZE - data.len() % BLOCKSIZE))
+    }
+}
+
+/// Marker for block ciphers of stream-like modes such as CTR, CFB or OFB, which XOR a keystream into the data.
+/// Implementors must process partial blocks in `encrypt_partial` and `decrypt_partial` without failing,
+/// which allows the buffered ciphers to finalize a message of any length with `finalize_stream`.
+///
+/// The trait is sealed, since `finalize_stream` relies on that guarantee. It is implemented by `StreamBlocks`.
+pub trait StreamMode: sealed::Sealed {}
+
+impl<T: StreamMode + ?Sized> sealed::Sealed for Box<T> {}
+impl<T: StreamMode + ?Sized> StreamMode for Box<T> {}
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+impl<T: BlockCipherEncryption<B> + ?Sized, const B: usize> BlockCipherEncryption<B> for Box<T> {
+    fn encrypt(&mut self, data: &mut [u8; B]) {
+        (**self).encrypt(data)
+    }
+
+    fn try_encrypt(&mut self, data: &mut [u8; B]) -> Result<(), BlockCipherError> {
+        (**self).try_encrypt(data)
+    }
+
+    fn encrypt_slice(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        (**self).encrypt_slice(data)
+    }
+
+    fn encrypt_partial(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        (**self).encrypt_partial(data)
+    }
+}
+
+impl<T: BlockCipherDecryption<B> + ?Sized, const B: usize> BlockCipherDecryption<B> for Box<T> {
+    fn decrypt(&mut self, data: &mut [u8; B]) {
+        (**self).decrypt(data)
+    }
+
+    fn try_decrypt(&mut self, data: &mut [u8; B]) -> Result<(), BlockCipherError> {
+        (**self).try_decrypt(data)
+    }
+
+    fn decrypt_slice(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        (**self).decrypt_slice(data)
+    }
+
+    fn decrypt_partial(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        (**self).decrypt_partial(data)
+    }
 }
@@ -0,0 +1,12 @@
+use crate::errors::blockcipher::BlockCipherError;
+use crate::util::readable::Readable;
+use alloc::vec::Vec;
+
+pub type BlockCipherResult = Result<Readable<Vec<u8>>, BlockCipherError>;
+
+/// Common finalization of buffering cipher providers
+///
+/// Fails with `BlockCipherError::IncompleteBlock` if an incomplete block is still buffered.
+pub trait Finalizable {
+    fn finalize_readable(self) -> BlockCipherResult;
+}
@@ -1,4 +1,6 @@
 pub mod block;
+pub mod finalize;
+pub mod oneshot;
 pub mod primitive;
+pub mod sink;
 pub mod stream;
-
@@ -0,0 +1,63 @@
+use super::block::{BlockCipherDecryption, BlockCipherEncryption};
+use super::sink::ByteSink;
+use crate::cipher::block::buffered::{BufferedCipherDecryption, BufferedCipherEncryption};
+use crate::errors::blockcipher::{BlockCipherError, PaddingErrorKind};
+use alloc::vec::Vec;
+
+/// One-shot encryption of a whole message with a mode provider, buffering and finalizing internally
+pub trait Encryptor<const BLOCKSIZE: usize>: BlockCipherEncryption<BLOCKSIZE> + Sized {
+    /// Encrypts a whole message and returns the ciphertext.
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` if the message is not a multiple of the blocksize,
+    /// use `encrypt_all_padded` for messages of any length
+    fn encrypt_all(self, data: &[u8]) -> Result<Vec<u8>, BlockCipherError> {
+        let mut cipher = BufferedCipherEncryption::new(self);
+        cipher.encrypt_blocks(data)?;
+
+        Ok(cipher.finalize())
+    }
+
+    /// Encrypts a whole message of any length with PKCS#7 padding and returns the ciphertext
+    fn encrypt_all_padded(self, data: &[u8]) -> Result<Vec<u8>, BlockCipherError> {
+        let aligned = data.len() - data.len() % BLOCKSIZE;
+
+        let mut cipher = BufferedCipherEncryption::new(self);
+        cipher.encrypt_blocks(&data[..aligned])?;
+        cipher
+            .write_bytes(&data[aligned..])
+            .expect("an incomplete block is only buffered");
+
+        cipher.finalize_padded()
+    }
+}
+
+impl<T: BlockCipherEncryption<B>, const B: usize> Encryptor<B> for T {}
+
+/// One-shot decryption of a whole message with a mode provider, buffering and finalizing internally
+pub trait Decryptor<const BLOCKSIZE: usize>: BlockCipherDecryption<BLOCKSIZE> + Sized {
+    /// Decrypts a whole ciphertext and returns the plaintext.
+    ///
+    /// Fails with `BlockCipherError::IncompleteBlock` if the ciphertext is not a multiple of the blocksize
+    fn decrypt_all(self, data: &[u8]) -> Result<Vec<u8>, BlockCipherError> {
+        let mut cipher = BufferedCipherDecryption::new(self);
+        cipher.decrypt_blocks(data)?;
+
+        cipher.finalize()
+    }
+
+    /// Decrypts a whole ciphertext and returns the plaintext with its PKCS#7 padding removed.
+    ///
+    /// Fails with `BlockCipherError::BadPadding` as described for `BufferedCipherDecryption::finalize_unpadded`
+    fn decrypt_all_padded(self, data: &[u8]) -> Result<Vec<u8>, BlockCipherError> {
+        if data.len() % BLOCKSIZE != 0 {
+            return Err(BlockCipherError::BadPadding { kind: PaddingErrorKind::Length });
+        }
+
+        let mut cipher = BufferedCipherDecryption::new(self);
+        cipher.decrypt_blocks(data)?;
+
+        cipher.finalize_unpadded()
+    }
+}
+
+impl<T: BlockCipherDecryption<B>, const B: usize> Decryptor<B> for T {}
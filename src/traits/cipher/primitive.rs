@@ -1,12 +1,11 @@
+use crate::errors::blockcipher::BlockCipherError;
+
 /// Adds information about common data to a blockcipher primitive
 pub trait BlockCipherPrimitiveInfo {
     const BLOCKSIZE: usize;
     const KEYLEN_MIN: usize;
     const KEYLEN_MAX: usize;
 
-    fn block_size(&self) -> usize {
-        Self::BLOCKSIZE
-    }
     fn keylen_min(&self) -> usize {
         Self::KEYLEN_MIN
     }
@@ -15,12 +14,49 @@ pub trait BlockCipherPrimitiveInfo {
     }
 }
 
+/// Object safe runtime access to the blocksize and key bounds of a primitive or a mode built on it,
+/// eg. to validate a key before constructing a cipher selected at runtime
+pub trait BlockCipherRuntimeInfo {
+    fn block_size(&self) -> usize;
+
+    /// Returns the minimum and maximum key length in bytes
+    fn key_bounds(&self) -> (usize, usize);
+}
+
+impl<T: BlockCipherPrimitiveInfo> BlockCipherRuntimeInfo for T {
+    fn block_size(&self) -> usize {
+        T::BLOCKSIZE
+    }
+
+    fn key_bounds(&self) -> (usize, usize) {
+        (T::KEYLEN_MIN, T::KEYLEN_MAX)
+    }
+}
+
 /// Trait for a blockcipher primitive encryption
+///
+/// A primitive only transforms a single block in place. Chaining, eg. the IV XOR of CBC, belongs to the modes.
 pub trait BlockCipherPrimitiveEncryption<const BLOCKSIZE: usize>: BlockCipherPrimitiveInfo {
     fn encrypt(&self, block: &mut [u8; BLOCKSIZE]);
+
+    /// Fallible variant of encrypt for backends that may fail, eg. hardware.
+    /// Software primitives are infallible and use the default implementation.
+    fn try_encrypt_block(&self, block: &mut [u8; BLOCKSIZE]) -> Result<(), BlockCipherError> {
+        self.encrypt(block);
+        Ok(())
+    }
 }
 
 /// Trait for a blockcipher primitive decryption
+///
+/// Counterpart to `BlockCipherPrimitiveEncryption`, chaining is left to the modes as well.
 pub trait BlockCipherPrimitiveDecryption<const BLOCKSIZE: usize>: BlockCipherPrimitiveInfo {
     fn decrypt(&self, block: &mut [u8; BLOCKSIZE]);
+
+    /// Fallible variant of decrypt for backends that may fail, eg. hardware.
+    /// Software primitives are infallible and use the default implementation.
+    fn try_decrypt_block(&self, block: &mut [u8; BLOCKSIZE]) -> Result<(), BlockCipherError> {
+        self.decrypt(block);
+        Ok(())
+    }
 }
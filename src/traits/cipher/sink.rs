@@ -0,0 +1,17 @@
+use crate::errors::blockcipher::BlockCipherError;
+use alloc::vec::Vec;
+
+/// Byte sink without std::io, the entry point of buffering cipher providers for environments without std
+///
+/// Mirrors `io::Write::write_all`, which the providers implement on top of it.
+pub trait ByteSink {
+    /// Feeds all of data into the sink, may be called multiple times
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BlockCipherError>;
+}
+
+impl ByteSink for Vec<u8> {
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), BlockCipherError> {
+        self.extend_from_slice(data);
+        Ok(())
+    }
+}
@@ -1,7 +1,22 @@
+use crate::errors::blockcipher::BlockCipherError;
+
 pub trait StreamCipherEncryption {
     fn encrypt(&mut self, data: &mut [u8]);
+
+    /// Fallible variant of encrypt for streams built on a primitive that may fail, eg. hardware,
+    /// or that can be exhausted. Infallible streams use the default implementation.
+    fn try_encrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.encrypt(data);
+        Ok(())
+    }
 }
 
 pub trait StreamCipherDecryption {
     fn decrypt(&mut self, data: &mut [u8]);
+
+    /// Fallible variant of decrypt, see `StreamCipherEncryption::try_encrypt`
+    fn try_decrypt(&mut self, data: &mut [u8]) -> Result<(), BlockCipherError> {
+        self.decrypt(data);
+        Ok(())
+    }
 }
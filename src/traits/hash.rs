@@ -0,0 +1,28 @@
+use alloc::vec::Vec;
+
+/// Trait for a cryptographic hash function
+pub trait Hash {
+    /// Size of the internal block in bytes
+    const BLOCKSIZE: usize;
+    /// Size of the digest in bytes
+    const DIGEST_LEN: usize;
+
+    /// Create a new instance
+    fn new() -> Self;
+
+    /// Feeds data into the hash, may be called multiple times
+    fn update(&mut self, data: &[u8]);
+
+    /// Consumes the hash and returns the digest
+    fn finalize(self) -> Vec<u8>;
+
+    /// Computes the digest of data in one go
+    fn digest(data: &[u8]) -> Vec<u8>
+    where
+        Self: Sized,
+    {
+        let mut hash = Self::new();
+        hash.update(data);
+        hash.finalize()
+    }
+}
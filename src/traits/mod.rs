@@ -1 +1,2 @@
 pub mod cipher;
+pub mod hash;
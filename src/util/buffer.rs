@@ -1,7 +1,9 @@
-pub use conversion::*;
-pub use index::*;
-pub use iter::*;
-use std::mem;
+#[cfg(feature = "std")]
+use std::io;
+use core::mem;
+
+/// Former name of ArrayBuffer, kept for compatibility
+pub type FixedBuffer<T, const BLOCKSIZE: usize> = ArrayBuffer<T, BLOCKSIZE>;
 
 #[derive(Clone, Copy, Debug)]
 pub struct ArrayBuffer<T, const BLOCKSIZE: usize>
@@ -9,7 +11,7 @@ where
     T: Clone + Copy + Default,
 {
     buf: [T; BLOCKSIZE],
-    capacity: usize,
+    remaining: usize,
 }
 
 impl<T, const B: usize> ArrayBuffer<T, B>
@@ -20,13 +22,13 @@ where
     pub fn new() -> Self {
         Self {
             buf: [T::default(); B],
-            capacity: B,
+            remaining: B,
         }
     }
 
-    /// Returns the length of the buffer
+    /// Returns the number of elements that have been pushed so far
     pub fn len(&self) -> usize {
-        B
+        B - self.remaining
     }
 
     pub fn as_slice(&self) -> &[T] {
@@ -37,27 +39,32 @@ where
         &mut self.buf
     }
 
+    /// Returns the elements that have been pushed so far
+    pub fn filled(&self) -> &[T] {
+        &self.buf[..self.len()]
+    }
+
     /// Pushes an element into a buffer. Returns a bool whether the operation was successful
     pub fn push_ref(&mut self, element: &T) -> bool {
-        if self.capacity == 0 {
+        if self.remaining == 0 {
             return false;
         }
 
-        let position = self.len() - self.capacity;
-        self.buf[position] = element.clone();
-        self.capacity -= 1;
+        let position = self.len();
+        self.buf[position] = *element;
+        self.remaining -= 1;
         true
     }
 
     /// Pushes an element into a buffer. Returns a bool whether the operation was successful
     pub fn push(&mut self, element: T) -> bool {
-        if self.capacity == 0 {
+        if self.remaining == 0 {
             return false;
         }
 
-        let position = self.len() - self.capacity;
+        let position = self.len();
         self.buf[position] = element;
-        self.capacity -= 1;
+        self.remaining -= 1;
         true
     }
 
@@ -65,7 +72,7 @@ where
     pub fn push_slice(&mut self, slice: &[T]) -> usize {
         let mut pushed = 0;
 
-        while self.capacity() != 0 && pushed < slice.len() {
+        while self.remaining != 0 && pushed < slice.len() {
             self.push_ref(&slice[pushed]);
             pushed += 1;
         }
@@ -73,35 +80,93 @@ where
         pushed
     }
 
-    /// Returns the capacity
+    /// Pushes a whole slice into the buffer or nothing at all.
+    /// Returns the number of elements that would overflow the buffer if the slice does not fit.
+    pub fn try_push_slice(&mut self, slice: &[T]) -> Result<(), usize> {
+        if slice.len() > self.remaining {
+            return Err(slice.len() - self.remaining);
+        }
+
+        self.push_slice(slice);
+        Ok(())
+    }
+
+    /// Returns the total number of elements the buffer can hold
     pub fn capacity(&self) -> usize {
-        self.capacity
+        B
+    }
+
+    /// Returns the number of elements that can still be pushed
+    pub fn remaining(&self) -> usize {
+        self.remaining
     }
 
     /// Returns a bool indicating whether the buffer is filled
     pub fn is_full(&self) -> bool {
-        self.capacity() == 0
+        self.remaining == 0
     }
+
+    /// Returns a bool indicating whether nothing has been pushed
     pub fn is_empty(&self) -> bool {
-        self.capacity() == B
+        self.remaining == B
     }
 
     /// Extract the buffers contents and resets the buffer
     pub fn extract(&mut self) -> [T; B] {
-        self.capacity = B;
+        self.remaining = B;
         mem::replace(&mut self.buf, [T::default(); B])
     }
 
-    /// Extract the buffers contents and resets the buffer in place, not resetting the capacity
+    /// Resets the buffer and pushes contents into it. Returns the number of elements successfully pushed
+    pub fn override_contents(&mut self, contents: &[T]) -> usize {
+        self.extract();
+        self.push_slice(contents)
+    }
+
+    /// Extract the buffers contents and resets the buffer in place, keeping the number of pushed elements
     pub fn extract_in_place(&mut self, buf: [T; B]) -> [T; B] {
         mem::replace(&mut self.buf, buf)
     }
 }
 
+#[cfg(feature = "std")]
+impl<const B: usize> ArrayBuffer<u8, B> {
+    /// Reads from r until the buffer is full or r reached EOF. Returns the number of bytes read
+    pub fn fill_from<R: io::Read>(&mut self, r: &mut R) -> io::Result<usize> {
+        let mut read = 0;
+
+        while !self.is_full() {
+            let position = self.len();
+
+            match r.read(&mut self.buf[position..]) {
+                Ok(0) => break,
+                Ok(n) => {
+                    self.remaining -= n;
+                    read += n;
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(read)
+    }
+}
+
+impl<T, const B: usize> Default for ArrayBuffer<T, B>
+where
+    T: Clone + Copy + Default,
+{
+    /// Create a new, empty buffer
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 mod index {
     use super::*;
-    use std::ops::{Index, IndexMut};
-    use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+    use core::ops::{Index, IndexMut};
+    use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
     impl<T, const B: usize> Index<usize> for ArrayBuffer<T, B>
     where
@@ -129,7 +194,7 @@ mod index {
     {
         type Output = [T];
 
-        fn index<'a>(&'a self, index: Range<usize>) -> &'a Self::Output {
+        fn index(&self, index: Range<usize>) -> &Self::Output {
             &self.buf[index]
         }
     }
@@ -138,7 +203,7 @@ mod index {
     where
         T: Clone + Copy + Default,
     {
-        fn index_mut<'a>(&'a mut self, index: Range<usize>) -> &'a mut Self::Output {
+        fn index_mut(&mut self, index: Range<usize>) -> &mut Self::Output {
             &mut self.buf[index]
         }
     }
@@ -149,7 +214,7 @@ mod index {
     {
         type Output = [T];
 
-        fn index<'a>(&'a self, index: RangeFrom<usize>) -> &'a Self::Output {
+        fn index(&self, index: RangeFrom<usize>) -> &Self::Output {
             &self.buf[index]
         }
     }
@@ -158,7 +223,7 @@ mod index {
     where
         T: Clone + Copy + Default,
     {
-        fn index_mut<'a>(&'a mut self, index: RangeFrom<usize>) -> &'a mut Self::Output {
+        fn index_mut(&mut self, index: RangeFrom<usize>) -> &mut Self::Output {
             &mut self.buf[index]
         }
     }
@@ -169,7 +234,7 @@ mod index {
     {
         type Output = [T];
 
-        fn index<'a>(&'a self, index: RangeFull) -> &'a Self::Output {
+        fn index(&self, index: RangeFull) -> &Self::Output {
             &self.buf[index]
         }
     }
@@ -178,7 +243,7 @@ mod index {
     where
         T: Clone + Copy + Default,
     {
-        fn index_mut<'a>(&'a mut self, index: RangeFull) -> &'a mut Self::Output {
+        fn index_mut(&mut self, index: RangeFull) -> &mut Self::Output {
             &mut self.buf[index]
         }
     }
@@ -189,7 +254,7 @@ mod index {
     {
         type Output = [T];
 
-        fn index<'a>(&'a self, index: RangeInclusive<usize>) -> &'a Self::Output {
+        fn index(&self, index: RangeInclusive<usize>) -> &Self::Output {
             &self.buf[index]
         }
     }
@@ -198,7 +263,7 @@ mod index {
     where
         T: Clone + Copy + Default,
     {
-        fn index_mut<'a>(&'a mut self, index: RangeInclusive<usize>) -> &'a mut Self::Output {
+        fn index_mut(&mut self, index: RangeInclusive<usize>) -> &mut Self::Output {
             &mut self.buf[index]
         }
     }
@@ -209,7 +274,7 @@ mod index {
     {
         type Output = [T];
 
-        fn index<'a>(&'a self, index: RangeTo<usize>) -> &'a Self::Output {
+        fn index(&self, index: RangeTo<usize>) -> &Self::Output {
             &self.buf[index]
         }
     }
@@ -218,7 +283,7 @@ mod index {
     where
         T: Clone + Copy + Default,
     {
-        fn index_mut<'a>(&'a mut self, index: RangeTo<usize>) -> &'a mut Self::Output {
+        fn index_mut(&mut self, index: RangeTo<usize>) -> &mut Self::Output {
             &mut self.buf[index]
         }
     }
@@ -229,7 +294,7 @@ mod index {
     {
         type Output = [T];
 
-        fn index<'a>(&'a self, index: RangeToInclusive<usize>) -> &'a Self::Output {
+        fn index(&self, index: RangeToInclusive<usize>) -> &Self::Output {
             &self.buf[index]
         }
     }
@@ -238,7 +303,7 @@ mod index {
     where
         T: Clone + Copy + Default,
     {
-        fn index_mut<'a>(&'a mut self, index: RangeToInclusive<usize>) -> &'a mut Self::Output {
+        fn index_mut(&mut self, index: RangeToInclusive<usize>) -> &mut Self::Output {
             &mut self.buf[index]
         }
     }
@@ -246,7 +311,8 @@ mod index {
 
 mod conversion {
     use super::*;
-    use std::convert::{AsMut, AsRef};
+    use core::array::TryFromSliceError;
+    use core::convert::{AsMut, AsRef, TryFrom};
 
     impl<T, const B: usize> AsRef<[T; B]> for ArrayBuffer<T, B>
     where
@@ -269,15 +335,15 @@ mod conversion {
     impl<T: Clone + Copy + Default, const B: usize> From<[T; B]> for ArrayBuffer<T, B> {
         /// Create a new filled buffer from an array of same type and length
         fn from(buf: [T; B]) -> Self {
-            Self { buf, capacity: 0 }
+            Self { buf, remaining: 0 }
         }
     }
 
     impl<'a, T: Clone + Copy + Default, const B: usize> From<&'a [T; B]> for ArrayBuffer<T, B> {
         fn from(buf: &'a [T; B]) -> Self {
             Self {
-                buf: buf.clone(),
-                capacity: 0,
+                buf: *buf,
+                remaining: 0,
             }
         }
     }
@@ -285,12 +351,21 @@ mod conversion {
     impl<'a, T: Clone + Copy + Default, const B: usize> From<&'a mut [T; B]> for ArrayBuffer<T, B> {
         fn from(buf: &'a mut [T; B]) -> Self {
             Self {
-                buf: buf.clone(),
-                capacity: 0,
+                buf: *buf,
+                remaining: 0,
             }
         }
     }
 
+    impl<'a, T: Clone + Copy + Default, const B: usize> TryFrom<&'a [T]> for ArrayBuffer<T, B> {
+        type Error = TryFromSliceError;
+
+        /// Create a new filled buffer from a slice, failing if its length is not exactly B
+        fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+            <[T; B]>::try_from(slice).map(Self::from)
+        }
+    }
+
     impl<T: Clone + Copy + Default, const B: usize> From<ArrayBuffer<T, B>> for [T; B] {
         fn from(buf: ArrayBuffer<T, B>) -> [T; B] {
             buf.buf
@@ -305,7 +380,7 @@ mod iter {
         T: Clone + Copy + Default,
     {
         type Item = T;
-        type IntoIter = std::array::IntoIter<T, B>;
+        type IntoIter = core::array::IntoIter<T, B>;
 
         fn into_iter(self) -> Self::IntoIter {
             IntoIterator::into_iter(self.buf)
@@ -317,7 +392,7 @@ mod iter {
         T: Clone + Copy + Default,
     {
         type Item = &'a T;
-        type IntoIter = std::slice::Iter<'a, T>;
+        type IntoIter = core::slice::Iter<'a, T>;
 
         fn into_iter(self) -> Self::IntoIter {
             self.buf.iter()
@@ -329,7 +404,7 @@ mod iter {
         T: Clone + Copy + Default,
     {
         type Item = &'a mut T;
-        type IntoIter = std::slice::IterMut<'a, T>;
+        type IntoIter = core::slice::IterMut<'a, T>;
 
         fn into_iter(self) -> Self::IntoIter {
             self.buf.iter_mut()
@@ -0,0 +1,99 @@
+use crate::errors::frame::FrameError;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+const PREFIX_LEN: usize = 4;
+
+/// Length prefixed framing of concatenated segments, eg. an IV, ciphertext and tag stored together
+///
+/// Every segment is preceded by its length as a big endian u32.
+#[derive(Debug, Clone, Default)]
+pub struct Frame {
+    buf: Vec<u8>,
+}
+
+impl Frame {
+    pub fn new() -> Self {
+        Self { buf: Vec::new() }
+    }
+
+    /// Appends a length prefixed segment
+    ///
+    /// Fails with `FrameError::SegmentTooLong` if the length does not fit in a u32
+    pub fn push(&mut self, segment: &[u8]) -> Result<&mut Self, FrameError> {
+        let len = u32::try_from(segment.len()).map_err(|_| FrameError::SegmentTooLong(segment.len()))?;
+
+        self.buf.extend_from_slice(&len.to_be_bytes());
+        self.buf.extend_from_slice(segment);
+        Ok(self)
+    }
+
+    /// Consumes the Frame and returns the packed bytes
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Packs all segments in order
+    pub fn pack(segments: &[&[u8]]) -> Result<Vec<u8>, FrameError> {
+        let mut frame = Self::new();
+
+        for segment in segments {
+            frame.push(segment)?;
+        }
+
+        Ok(frame.into_inner())
+    }
+
+    /// Splits packed bytes back into its segments without copying
+    ///
+    /// Fails with `FrameError::Truncated` if a length prefix or segment is cut short
+    pub fn unpack(data: &[u8]) -> Result<Vec<&[u8]>, FrameError> {
+        Segments { data, pos: 0 }.collect()
+    }
+
+    /// Returns an iterator over the segments of packed bytes, yielding an error once on truncation
+    pub fn segments(data: &[u8]) -> Segments<'_> {
+        Segments { data, pos: 0 }
+    }
+}
+
+/// Iterator over the segments of packed bytes, see `Frame::segments`
+pub struct Segments<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Segments<'a> {
+    fn take(&mut self, needed: usize) -> Result<&'a [u8], FrameError> {
+        let available = self.data.len() - self.pos;
+
+        if needed > available {
+            let offset = self.pos;
+            // Nothing is yielded after a truncated segment
+            self.pos = self.data.len();
+            return Err(FrameError::Truncated { offset, needed, available });
+        }
+
+        let taken = &self.data[self.pos..self.pos + needed];
+        self.pos += needed;
+        Ok(taken)
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Result<&'a [u8], FrameError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.data.len() {
+            return None;
+        }
+
+        let segment = self.take(PREFIX_LEN).and_then(|prefix| {
+            let mut len = [0; PREFIX_LEN];
+            len.copy_from_slice(prefix);
+            self.take(u32::from_be_bytes(len) as usize)
+        });
+
+        Some(segment)
+    }
+}
@@ -0,0 +1,70 @@
+//! Arithmetic in GF(2^128) with the reduction polynomial x^128 + x^7 + x^2 + x + 1
+//!
+//! Field elements are 16 byte blocks, the conventions differ in how bits map to coefficients:
+//! * Big endian (CMAC): The most significant bit of byte 0 is the coefficient of x^127,
+//!   the least significant bit of byte 15 the coefficient of x^0.
+//! * Little endian (XTS): Byte 15 is the most significant byte, byte 0 the least significant one.
+//! * Bit reflected (GHASH): The most significant bit of byte 0 is the coefficient of x^0,
+//!   the least significant bit of byte 15 the coefficient of x^127, as specified in NIST SP 800-38D.
+
+/// Low terms of the reduction polynomial, x^7 + x^2 + x + 1
+const R: u128 = 0x87;
+
+/// Low terms of the reduction polynomial in bit reflected order
+const R_REFLECTED: u128 = 0xE1 << 120;
+
+/// Multiplies a big endian element by x
+pub fn gf128_double(a: &mut [u8; 16]) {
+    *a = double(u128::from_be_bytes(*a)).to_be_bytes();
+}
+
+/// Multiplies two big endian elements
+pub fn gf128_mul(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let (a, b) = (u128::from_be_bytes(*a), u128::from_be_bytes(*b));
+    let mut product = 0;
+
+    // Horner's method, starting with the coefficient of x^127 of b
+    for i in (0..128).rev() {
+        product = double(product);
+        product ^= a & mask(b >> i);
+    }
+
+    product.to_be_bytes()
+}
+
+/// Multiplies a little endian element by x, as used for the XTS tweak
+pub fn gf128_double_le(a: &mut [u8; 16]) {
+    *a = double(u128::from_le_bytes(*a)).to_le_bytes();
+}
+
+/// Multiplies a bit reflected element by x
+pub fn gf128_double_reflected(a: &mut [u8; 16]) {
+    *a = double_reflected(u128::from_be_bytes(*a)).to_be_bytes();
+}
+
+/// Multiplies two bit reflected elements, as used by GHASH
+pub fn gf128_mul_reflected(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let (a, mut v) = (u128::from_be_bytes(*a), u128::from_be_bytes(*b));
+    let mut product = 0;
+
+    // NIST SP 800-38D, Algorithm 1
+    for i in (0..128).rev() {
+        product ^= v & mask(a >> i);
+        v = double_reflected(v);
+    }
+
+    product.to_be_bytes()
+}
+
+fn double(a: u128) -> u128 {
+    (a << 1) ^ (R & mask(a >> 127))
+}
+
+fn double_reflected(a: u128) -> u128 {
+    (a >> 1) ^ (R_REFLECTED & mask(a))
+}
+
+/// Expands the lowest bit to a full mask, avoiding secret dependent branches
+fn mask(bit: u128) -> u128 {
+    0u128.wrapping_sub(bit & 1)
+}
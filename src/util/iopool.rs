@@ -1,6 +1,6 @@
 use crossbeam::channel::{unbounded, Receiver, Sender};
 use crossbeam::sync::ShardedLock;
-use std::ops::FnOnce;
+use core::ops::FnOnce;
 use std::sync::{Arc, Mutex};
 use std::thread;
 
@@ -16,23 +16,19 @@ impl<T: Send> PartialEq for Transmission<T> {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
     }
-
-    fn ne(&self, other: &Self) -> bool {
-        self.id != other.id
-    }
 }
 
 impl<T: Send> Eq for Transmission<T> {}
 
 impl<T: Send> Ord for Transmission<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.id.cmp(&other.id)
     }
 }
 
 impl<T: Send> PartialOrd for Transmission<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.id.cmp(&other.id))
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -1,4 +1,9 @@
 pub mod buffer;
+pub mod frame;
+pub mod gf128;
+#[cfg(feature = "std")]
 pub mod iopool;
+#[cfg(feature = "std")]
+pub mod random;
 pub mod readable;
 pub mod secure;
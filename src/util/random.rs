@@ -0,0 +1,57 @@
+#[cfg(feature = "std")]
+use std::io;
+
+/// Fills the buffer with random bytes from the operating system's entropy source
+///
+/// Uses the native CSPRNG of each platform via the `getrandom` crate, eg. the getrandom syscall on Linux,
+/// getentropy on macOS and BCryptGenRandom on Windows. Fails if the entropy source is not available.
+#[cfg(feature = "std")]
+pub fn fill_random(buf: &mut [u8]) -> io::Result<()> {
+    getrandom::getrandom(buf)?;
+    Ok(())
+}
+
+/// Returns n random bytes from the operating system's entropy source
+///
+/// Panics if the entropy source is not available, use `fill_random` to handle this case
+#[cfg(feature = "std")]
+pub fn random_bytes(n: usize) -> Vec<u8> {
+    let mut bytes = vec![0; n];
+    fill_random(&mut bytes).expect("os entropy source is not available");
+
+    bytes
+}
+
+/// Deterministic xorshift64 pseudo random number generator
+///
+/// Note: This is NOT cryptographically secure, it is meant for reproducible tests only
+#[derive(Debug, Clone)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// Create a new generator from a seed, a seed of zero is replaced by a fixed non-zero constant
+    pub fn new(seed: u64) -> Self {
+        let state = if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed };
+        Self { state }
+    }
+
+    /// Returns the next pseudo random number
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// Fills the buffer with pseudo random bytes
+    pub fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            let bytes = self.next_u64().to_be_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
@@ -1,37 +1,124 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use std::io;
+use core::iter::FromIterator;
 
+/// Provides io::Read over any byte buffer, eg. Vec<u8> or &[u8]
 pub struct Readable<T>
 where
-    T: IntoIterator<Item = u8>,
+    T: AsRef<[u8]>,
 {
-    it: T::IntoIter,
+    inner: T,
+    pos: usize,
 }
 
-impl<T: IntoIterator<Item = u8>> Readable<T> {
-    pub fn new(item: T) -> Self {
-        Self {
-            it: item.into_iter(),
+impl<T: AsRef<[u8]>> Readable<T> {
+    pub fn new(inner: T) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    /// Returns the number of bytes that have not been read yet
+    pub fn remaining(&self) -> usize {
+        self.inner.as_ref().len() - self.pos
+    }
+
+    /// Consumes the Readable and returns the underlying buffer, including any bytes already read
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Returns the next at most size unread bytes without copying, advancing the cursor past them
+    ///
+    /// Panics if size is 0
+    pub fn next_chunk(&mut self, size: usize) -> Option<&[u8]> {
+        self.read_chunks(size).next()
+    }
+
+    /// Returns an iterator over the unread bytes in slices of size bytes, the last one may be shorter.
+    /// The cursor only advances past the chunks that have been yielded.
+    ///
+    /// Panics if size is 0
+    pub fn read_chunks(&mut self, size: usize) -> ReadChunks<'_> {
+        assert!(size != 0, "chunk size must not be zero");
+
+        ReadChunks {
+            data: self.inner.as_ref(),
+            pos: &mut self.pos,
+            size,
         }
     }
 }
 
-impl<T: IntoIterator<Item = u8>> io::Read for Readable<T> {
-    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        let mut read = 0;
+/// Iterator over the unread bytes of a Readable in slices of a preferred size, see `Readable::read_chunks`
+pub struct ReadChunks<'a> {
+    data: &'a [u8],
+    pos: &'a mut usize,
+    size: usize,
+}
 
-        for b in buf {
-            if let Some(v) = self.it.next() {
-                *b = v;
-                read += 1;
-            } else {
-                break;
-            }
+impl<'a> Iterator for ReadChunks<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if *self.pos == self.data.len() {
+            return None;
         }
 
+        let end = core::cmp::min(*self.pos + self.size, self.data.len());
+        let chunk = &self.data[*self.pos..end];
+        *self.pos = end;
+
+        Some(chunk)
+    }
+}
+
+impl Readable<Vec<u8>> {
+    /// Consumes the Readable and returns only the bytes that have not been read yet
+    pub fn into_remaining(mut self) -> Vec<u8> {
+        self.inner.drain(..self.pos);
+        self.inner
+    }
+}
+
+impl FromIterator<u8> for Readable<Vec<u8>> {
+    /// Allows finalizing a provider directly into a Readable
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AsRef<[u8]>> io::Read for Readable<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let unread = &self.inner.as_ref()[self.pos..];
+        let read = core::cmp::min(buf.len(), unread.len());
+
+        buf[..read].copy_from_slice(&unread[..read]);
+        self.pos += read;
+
         Ok(read)
     }
 }
 
+impl<T: AsRef<[u8]>> Iterator for Readable<T> {
+    type Item = u8;
+
+    /// Yields the unread bytes, advancing the same cursor as io::Read
+    fn next(&mut self) -> Option<u8> {
+        let byte = *self.inner.as_ref().get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining(), Some(self.remaining()))
+    }
+}
+
+impl<T: AsRef<[u8]>> ExactSizeIterator for Readable<T> {}
+
+#[cfg(feature = "std")]
 pub struct XofReadable<T>
 where
     T: IntoIterator<Item = u8>,
@@ -39,6 +126,7 @@ where
     it: T::IntoIter,
 }
 
+#[cfg(feature = "std")]
 impl<T: IntoIterator<Item = u8>> XofReadable<T> {
     pub fn new(item: T) -> Self {
         Self {
@@ -47,6 +135,7 @@ impl<T: IntoIterator<Item = u8>> XofReadable<T> {
     }
 }
 
+#[cfg(feature = "std")]
 impl<T: IntoIterator<Item = u8>> io::Read for XofReadable<T> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         let mut read = 0;
@@ -1,4 +1,5 @@
 use crate::mem::Zeroize;
+use alloc::vec::Vec;
 
 pub type SecureVec<T> = Vec<Zeroize<T>>;
 pub type SecureArray<T, const S: usize> = [Zeroize<T>; S];
\ No newline at end of file
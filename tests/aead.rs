@@ -0,0 +1,27 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::cipher::aead::bind_length;
+
+    #[test]
+    fn test_bind_length_layout() {
+        assert_eq!(decode("616164 00000000 00000005"), bind_length(b"aad", 5));
+        assert_eq!(decode("00000000 00000000"), bind_length(&[], 0));
+    }
+
+    // Splitting the same bytes differently between associated data and ciphertext yields different inputs
+    #[test]
+    fn test_bind_length_separates_aad_and_ciphertext() {
+        let mut first = bind_length(b"head", 4);
+        first.extend_from_slice(b"tail");
+        let mut second = bind_length(b"headta", 2);
+        second.extend_from_slice(b"il");
+
+        assert_ne!(first, second);
+    }
+}
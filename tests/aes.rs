@@ -1,11 +1,14 @@
+#![cfg(feature = "alloc")]
+
 mod common;
 
 #[cfg(test)]
 mod tests {
 
-    use super::common::decode;
+    use super::common::{decode, decode_into_array};
     use himitsu::{
         cipher::block::primitive::aes::*,
+        errors::blockcipher::BlockCipherError,
         traits::cipher::primitive::{BlockCipherPrimitiveDecryption, BlockCipherPrimitiveEncryption},
     };
 
@@ -21,7 +24,7 @@ mod tests {
             buf[i] = element;
         }
 
-        let aes = Aes::new(&key);
+        let aes = Aes::new(&key).unwrap();
         aes.encrypt(&mut buf);
 
         assert_eq!(expected, buf.as_ref());
@@ -39,7 +42,7 @@ mod tests {
             buf[i] = element;
         }
 
-        let aes = Aes::new(&key);
+        let aes = Aes::new(&key).unwrap();
         aes.encrypt(&mut buf);
 
         assert_eq!(expected, buf.as_ref());
@@ -57,7 +60,7 @@ mod tests {
             buf[i] = element;
         }
 
-        let aes = Aes::new(&key);
+        let aes = Aes::new(&key).unwrap();
         aes.encrypt(&mut buf);
 
         assert_eq!(expected, buf.as_ref());
@@ -75,9 +78,178 @@ mod tests {
             buf[i] = element;
         }
 
-        let aes = Aes::new(&key);
+        let aes = Aes::new(&key).unwrap();
         aes.decrypt(&mut buf);
 
         assert_eq!(expected, buf.as_ref());
     }
+
+    #[test]
+    fn test_aes_invalid_key_length() {
+        for len in [0, 15, 20, 33] {
+            match Aes::new(&vec![0; len]) {
+                Err(BlockCipherError::InvalidKeyLength { got, min, max }) => {
+                    assert_eq!(len, got);
+                    assert_eq!(AES_128_KEYLEN, min);
+                    assert_eq!(AES_256_KEYLEN, max);
+                }
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("accepted a {} byte key", len),
+            }
+        }
+    }
+
+    #[test]
+    fn test_aes_rekey_matches_fresh_instance() {
+        let key_128 = decode("000102030405060708090a0b0c0d0e0f");
+        let key_256 = decode("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        let plaintext = decode("00112233445566778899aabbccddeeff");
+
+        let mut aes = Aes::new(&key_128).unwrap();
+
+        for key in [&key_256, &key_128, &key_128, &key_256] {
+            aes.rekey(key).unwrap();
+
+            let (mut rekeyed, mut fresh) = ([0; AES_BLOCKSIZE], [0; AES_BLOCKSIZE]);
+            rekeyed.copy_from_slice(&plaintext);
+            fresh.copy_from_slice(&plaintext);
+
+            aes.encrypt(&mut rekeyed);
+            Aes::new(key).unwrap().encrypt(&mut fresh);
+
+            assert_eq!(fresh, rekeyed);
+
+            aes.decrypt(&mut rekeyed);
+            assert_eq!(plaintext, rekeyed.as_ref());
+        }
+    }
+
+    #[test]
+    fn test_aes_rekey_invalid_keeps_key() {
+        let key = decode("000102030405060708090a0b0c0d0e0f");
+        let expected = decode("69c4e0d86a7b0430d8cdb78070b4c55a");
+
+        let mut aes = Aes::new(&key).unwrap();
+        assert!(aes.rekey(&[0; 20]).is_err());
+
+        let mut buf = [0; AES_BLOCKSIZE];
+        buf.copy_from_slice(&decode("00112233445566778899aabbccddeeff"));
+        aes.encrypt(&mut buf);
+
+        assert_eq!(expected, buf.as_ref());
+    }
+
+    // Decryption must be the inverse of encryption for every key size, and not the forward cipher again
+    #[test]
+    fn test_aes_encrypt_decrypt_identity() {
+        let plaintext = decode("00112233445566778899aabbccddeeff");
+        let keys = [
+            decode("000102030405060708090a0b0c0d0e0f"),
+            decode("000102030405060708090a0b0c0d0e0f1011121314151617"),
+            decode("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ];
+
+        for key in keys {
+            let aes = Aes::new(&key).unwrap();
+
+            let mut buf = [0; AES_BLOCKSIZE];
+            buf.copy_from_slice(&plaintext);
+
+            aes.encrypt(&mut buf);
+            assert_ne!(plaintext, buf.as_ref());
+
+            let mut twice = buf;
+            aes.encrypt(&mut twice);
+            assert_ne!(plaintext, twice.as_ref());
+
+            aes.decrypt(&mut buf);
+            assert_eq!(plaintext, buf.as_ref());
+        }
+    }
+
+    // FIPS-197 Appendix C.1
+    #[test]
+    fn test_aes_encrypt_block() {
+        let aes = Aes::new(&decode("000102030405060708090a0b0c0d0e0f")).unwrap();
+        let plaintext: AesBlock = decode_into_array("00112233445566778899aabbccddeeff");
+        let expected: AesBlock = decode_into_array("69c4e0d86a7b0430d8cdb78070b4c55a");
+
+        let ciphertext = aes.encrypt_block(plaintext);
+        assert_eq!(expected, ciphertext);
+        assert_eq!(plaintext, aes.decrypt_block(ciphertext));
+
+        // Matches the in place primitive
+        let mut buf = plaintext;
+        aes.encrypt(&mut buf);
+        assert_eq!(ciphertext, buf);
+    }
+
+    // Primitives take &self, so a single key schedule can be shared between threads without locking
+    #[test]
+    fn test_aes_shared_across_threads() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let aes = Arc::new(Aes::new(&decode("000102030405060708090a0b0c0d0e0f")).unwrap());
+        let plaintext: AesBlock = decode_into_array("00112233445566778899aabbccddeeff");
+        let expected: AesBlock = decode_into_array("69c4e0d86a7b0430d8cdb78070b4c55a");
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let aes = Arc::clone(&aes);
+                thread::spawn(move || {
+                    let mut block = plaintext;
+                    aes.encrypt(&mut block);
+                    block
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(expected, handle.join().unwrap());
+        }
+    }
+
+    fn check_vector<T>(aes: &T, expected: &str)
+    where
+        T: BlockCipherPrimitiveEncryption<AES_BLOCKSIZE> + BlockCipherPrimitiveDecryption<AES_BLOCKSIZE>,
+    {
+        let plaintext: AesBlock = decode_into_array("00112233445566778899aabbccddeeff");
+
+        let mut buf = plaintext;
+        aes.encrypt(&mut buf);
+        assert_eq!(decode(expected), buf.as_ref());
+
+        aes.decrypt(&mut buf);
+        assert_eq!(plaintext, buf);
+    }
+
+    // FIPS-197 Appendix C
+    #[test]
+    fn test_aes_typed_variants() {
+        let plaintext: AesBlock = decode_into_array("00112233445566778899aabbccddeeff");
+
+        let aes128 = Aes128::new(&decode_into_array("000102030405060708090a0b0c0d0e0f"));
+        let aes192 = Aes192::new(&decode_into_array("000102030405060708090a0b0c0d0e0f1011121314151617"));
+        let aes256 =
+            Aes256::new(&decode_into_array("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"));
+
+        check_vector(&aes128, "69c4e0d86a7b0430d8cdb78070b4c55a");
+        check_vector(&aes192, "dda97ca4864cdfe06eaf70a0ec0d7191");
+        check_vector(&aes256, "8ea2b7ca516745bfeafc49904b496089");
+
+        // The generic Aes is reachable through Deref and conversion
+        assert_eq!(decode_into_array::<16>("69c4e0d86a7b0430d8cdb78070b4c55a"), aes128.encrypt_block(plaintext));
+        let generic: Aes = aes256.clone().into();
+        assert_eq!(aes256.encrypt_block(plaintext), generic.encrypt_block(plaintext));
+    }
+
+    #[test]
+    fn test_aes_typed_variants_key_bounds() {
+        use himitsu::traits::cipher::primitive::BlockCipherRuntimeInfo;
+
+        assert_eq!((16, 16), Aes128::new(&[0; 16]).key_bounds());
+        assert_eq!((24, 24), Aes192::new(&[0; 24]).key_bounds());
+        assert_eq!((32, 32), Aes256::new(&[0; 32]).key_bounds());
+    }
 }
@@ -0,0 +1,126 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::{
+        cipher::block::{
+            buffered::{BufferedCipherDecryptionProvider, BufferedCipherEncryptionProvider},
+            cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+            primitive::aria::*,
+        },
+        errors::blockcipher::BlockCipherError,
+        traits::cipher::primitive::{BlockCipherPrimitiveDecryption, BlockCipherPrimitiveEncryption},
+    };
+    use himitsu::traits::cipher::sink::ByteSink;
+
+    macro_rules! aria_test {
+        (
+            $fn_name: ident,
+            $key: literal,
+            $plaintext: literal,
+            $ciphertext: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let key = decode($key);
+                let plaintext: AriaBlock = decode_into_array($plaintext);
+                let ciphertext: AriaBlock = decode_into_array($ciphertext);
+
+                let aria = Aria::new(&key).unwrap();
+
+                let mut buf = plaintext;
+                aria.encrypt(&mut buf);
+                assert_eq!(ciphertext, buf);
+
+                aria.decrypt(&mut buf);
+                assert_eq!(plaintext, buf);
+            }
+        };
+    }
+
+    macro_rules! aria_cbc_test {
+        (
+            $fn_name: ident,
+            $key: literal,
+            $iv: literal,
+            $plaintext: literal,
+            $ciphertext: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let key = decode($key);
+                let iv = decode_into_array($iv);
+                let plaintext = decode($plaintext);
+                let expected = decode($ciphertext);
+
+                let mut cipher = Aria::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+                cipher.write_bytes(&plaintext).unwrap();
+                let ciphertext: Vec<u8> = cipher.finalize();
+                assert_eq!(expected, ciphertext);
+
+                let mut cipher = Aria::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+                cipher.write_bytes(&ciphertext).unwrap();
+                let output: Vec<u8> = cipher.finalize().unwrap();
+                assert_eq!(plaintext, output);
+            }
+        };
+    }
+
+    // Test vectors from [RFC 5794](https://www.rfc-editor.org/rfc/rfc5794#appendix-A)
+
+    aria_test!(
+        test_aria_128,
+        "00010203 04050607 08090a0b 0c0d0e0f",
+        "00112233 44556677 8899aabb ccddeeff",
+        "d718fbd6 ab644c73 9da95f3b e6451778"
+    );
+
+    aria_test!(
+        test_aria_192,
+        "00010203 04050607 08090a0b 0c0d0e0f 10111213 14151617",
+        "00112233 44556677 8899aabb ccddeeff",
+        "26449c18 05dbe7aa 25a468ce 263a9e79"
+    );
+
+    aria_test!(
+        test_aria_256,
+        "00010203 04050607 08090a0b 0c0d0e0f 10111213 14151617 18191a1b 1c1d1e1f",
+        "00112233 44556677 8899aabb ccddeeff",
+        "f92bd7c7 9fb72e2f 2b8f80c1 972d24fc"
+    );
+
+    aria_cbc_test!(
+        test_aria128_cbc,
+        "2B7E1516 28AED2A6 ABF71588 09CF4F3C",
+        "00010203 04050607 08090A0B 0C0D0E0F",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710",
+        "A9F7B5DC CADDF6B4 3A508169 291DD5FB 10393426 5C51B36F 27013139 75BC27D4 4741691D C6D50E03 CD1FEBA0 1C65C103 1F8820D5 56912EBF 2F033E45 9FE2D52A"
+    );
+
+    aria_cbc_test!(
+        test_aria256_cbc,
+        "603DEB10 15CA71BE 2B73AEF0 857D7781 1F352C07 3B6108D7 2D9810A3 0914DFF4",
+        "00010203 04050607 08090A0B 0C0D0E0F",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710",
+        "9B1DBA5F CCC6D5A5 9ADC17CA 70FF65B4 2C6FB9C8 F2B6116A 2BDB26EA 87AF6B47 D1A22F86 CC8433BF C6B9521D B29BA7C0 9F9BE530 A7DD13E4 8E7954CA 409EFA9A"
+    );
+
+    #[test]
+    fn test_aria_invalid_key_length() {
+        for len in [0, 15, 20, 33] {
+            match Aria::new(&vec![0; len]) {
+                Err(BlockCipherError::InvalidKeyLength { got, min, max }) => {
+                    assert_eq!(len, got);
+                    assert_eq!(ARIA_128_KEYLEN, min);
+                    assert_eq!(ARIA_256_KEYLEN, max);
+                }
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("accepted a {} byte key", len),
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// Exercises the std::io integration
+#![cfg(feature = "std")]
+
+#[cfg(test)]
+mod tests {
+
+    use himitsu::encode::{Base32Encoder, Base32Reader, Base32Writer};
+    use himitsu::errors::base32::Base32Error;
+    use std::io::{Read, Write};
+
+    // Test vectors from RFC 4648, section 10
+    const VECTORS: [(&str, &str); 7] = [
+        ("", ""),
+        ("f", "MY======"),
+        ("fo", "MZXQ===="),
+        ("foo", "MZXW6==="),
+        ("foob", "MZXW6YQ="),
+        ("fooba", "MZXW6YTB"),
+        ("foobar", "MZXW6YTBOI======"),
+    ];
+
+    #[test]
+    fn encode_rfc4648() {
+        for (data, expected) in VECTORS {
+            assert_eq!(expected, Base32Encoder::new().encode(data.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn decode_rfc4648() {
+        for (expected, data) in VECTORS {
+            match Base32Encoder::new().decode(data) {
+                Ok(v) => assert_eq!(expected.as_bytes(), v),
+                Err(e) => panic!("unexpected error: {}", e),
+            };
+        }
+    }
+
+    #[test]
+    fn decode_invalid_input_length() {
+        match Base32Encoder::new().decode("MZXW6") {
+            Err(Base32Error::InvalidInputLength(5)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        };
+    }
+
+    #[test]
+    fn decode_invalid_format() {
+        match Base32Encoder::new().decode("MZX=====") {
+            Err(Base32Error::InvalidFormat(3)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        };
+    }
+
+    // Writing in chunks that straddle the 5 byte groups must match the one-shot encoding
+    #[test]
+    fn writer_matches_encode_across_chunk_boundaries() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1003).collect();
+        let expected = Base32Encoder::new().encode(&data);
+
+        for chunk_size in [1, 3, 4, 5, 7, 64] {
+            let mut writer = Base32Writer::new(Vec::new());
+
+            for chunk in data.chunks(chunk_size) {
+                writer.write_all(chunk).unwrap();
+            }
+
+            let output = writer.finish().unwrap();
+            assert_eq!(expected.as_bytes(), &output[..], "chunk size {}", chunk_size);
+        }
+    }
+
+    #[test]
+    fn reader_round_trip() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1003).collect();
+        let encoded = Base32Encoder::new().encode(&data);
+
+        let mut reader = Base32Reader::new(encoded.as_bytes());
+        let mut decoded = Vec::new();
+        let mut buf = [0u8; 7];
+
+        loop {
+            match reader.read(&mut buf).unwrap() {
+                0 => break,
+                n => decoded.extend_from_slice(&buf[..n]),
+            }
+        }
+
+        assert_eq!(data, decoded);
+    }
+
+    #[test]
+    fn reader_invalid_final_group() {
+        let mut reader = Base32Reader::new("MZXW6YTBO=======".as_bytes());
+        let mut decoded = Vec::new();
+
+        let err = reader.read_to_end(&mut decoded).unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    }
+}
@@ -1,8 +1,10 @@
+#![cfg(feature = "alloc")]
+
 #[cfg(test)]
 mod tests {
 
-    use himitsu::encode::Base64Encoder;
-    use himitsu::errors::base64::Base64Error;
+    use himitsu::encode::{base64_encode_to, Base64Encoder, Kind};
+    use himitsu::errors::base64::{Base64Error, ParseKindError};
 
     // Encode some data that results in a Base64 String with 0 padding characters
     #[test]
@@ -36,7 +38,7 @@ mod tests {
 
         match Base64Encoder::default().decode(&encoded) {
             Ok(v) => assert_eq!(data.as_bytes(), v),
-            Err(_) => assert!(false),
+            Err(e) => panic!("unexpected error: {}", e),
         };
     }
 
@@ -48,7 +50,7 @@ mod tests {
 
         match Base64Encoder::default().decode(&encoded) {
             Ok(v) => assert_eq!(data.as_bytes(), v),
-            Err(_) => assert!(false),
+            Err(e) => panic!("unexpected error: {}", e),
         };
     }
 
@@ -60,7 +62,7 @@ mod tests {
 
         match Base64Encoder::default().decode(&encoded) {
             Ok(v) => assert_eq!(data.as_bytes(), v),
-            Err(_) => assert!(false),
+            Err(e) => panic!("unexpected error: {}", e),
         };
     }
 
@@ -69,10 +71,11 @@ mod tests {
     fn decode_basic_invalid_length() {
         let data = "a";
 
-        match Base64Encoder::default().decode(&data) {
-            Ok(_) => assert!(false),
+        match Base64Encoder::default().decode(data) {
+            Ok(_) => panic!("decoding should have failed"),
             Err(e) => match e {
-                Base64Error::InvalidFormat(_) => assert!(false),
+                Base64Error::InvalidFormat(_) => panic!("expected InvalidInputLength"),
+                Base64Error::InvalidCharacter { .. } => panic!("expected InvalidInputLength"),
                 Base64Error::InvalidInputLength(s) => assert_eq!(s, 1),
             },
         }
@@ -83,12 +86,139 @@ mod tests {
     fn decode_basic_invalid_fmt() {
         let data = "A=AA==AA";
 
-        match Base64Encoder::default().decode(&data) {
-            Ok(_) => assert!(false),
+        match Base64Encoder::default().decode(data) {
+            Ok(_) => panic!("decoding should have failed"),
             Err(e) => match e {
-                Base64Error::InvalidInputLength(_) => assert!(false),
-                Base64Error::InvalidFormat(_) => assert!(true),
+                Base64Error::InvalidInputLength(_) => panic!("expected InvalidFormat"),
+                Base64Error::InvalidCharacter { .. } => panic!("expected InvalidFormat"),
+                Base64Error::InvalidFormat(_) => {}
             },
         }
     }
+
+    // Decode a valid Base64 String in strict mode
+    #[test]
+    fn decode_strict_valid() {
+        for data in ["aaa", "aa", "a", ""] {
+            let encoded = Base64Encoder::default().encode(data.as_bytes());
+
+            match Base64Encoder::default().decode_strict(&encoded) {
+                Ok(v) => assert_eq!(data.as_bytes(), v),
+                Err(e) => panic!("unexpected error: {}", e),
+            };
+        }
+    }
+
+    // Strict mode reports where the input is corrupted instead of skipping characters
+    #[test]
+    fn decode_strict_invalid_character() {
+        let cases = [
+            ("YWFhYWFh YWF", 8, ' '),
+            ("YWFhYWFhYW*h", 10, '*'),
+            ("YWFhYW=hYWE=", 6, '='),
+            ("YWFhYWFhY===", 9, '='),
+            ("YWFh-WFh", 4, '-'),
+        ];
+
+        for (data, expected_index, expected_char) in cases.iter() {
+            match Base64Encoder::default().decode_strict(data) {
+                Err(Base64Error::InvalidCharacter { index, found }) => {
+                    assert_eq!(*expected_index, index);
+                    assert_eq!(*expected_char, found);
+                }
+                other => panic!("unexpected result for {}: {:?}", data, other),
+            }
+        }
+    }
+
+    // Offsets are byte offsets, so they can be used to slice the input
+    #[test]
+    fn decode_strict_invalid_character_offset_is_byte_offset() {
+        let data = "YWFhäWFh";
+
+        match Base64Encoder::new_url().decode_strict(&data[..8]) {
+            Err(Base64Error::InvalidCharacter { index, found }) => {
+                assert_eq!(4, index);
+                assert_eq!('ä', found);
+                assert!(data[index..].starts_with('ä'));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn kind_from_str() {
+        let cases = [
+            ("basic", Kind::Basic),
+            ("BASIC", Kind::Basic),
+            ("url-safe", Kind::UrlSafe),
+            ("Url-Safe", Kind::UrlSafe),
+            ("url", Kind::UrlSafe),
+            ("URL", Kind::UrlSafe),
+        ];
+
+        for (s, expected) in cases.iter() {
+            assert_eq!(Ok(*expected), s.parse::<Kind>());
+        }
+    }
+
+    #[test]
+    fn kind_from_str_invalid() {
+        for s in ["", "base64", "urlsafe", "url_safe", " basic"] {
+            assert_eq!(Err(ParseKindError(s.to_string())), s.parse::<Kind>());
+        }
+    }
+
+    #[test]
+    fn kind_display_round_trip() {
+        for kind in [Kind::Basic, Kind::UrlSafe] {
+            assert_eq!(Ok(kind), kind.to_string().parse::<Kind>());
+        }
+
+        assert_eq!("basic", Kind::Basic.to_string());
+        assert_eq!("url-safe", Kind::UrlSafe.to_string());
+    }
+
+    #[test]
+    fn encoder_with_parsed_kind() {
+        let kind: Kind = "url".parse().unwrap();
+        let encoder = Base64Encoder::with_kind(kind);
+
+        assert_eq!(Kind::UrlSafe, encoder.kind());
+        assert_eq!("-_8=", encoder.encode(&[0xFB, 0xFF]));
+    }
+
+    #[test]
+    fn encode_to_matches_encode() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        for kind in [Kind::Basic, Kind::UrlSafe] {
+            let encoder = Base64Encoder::with_kind(kind);
+
+            for len in 0..8 {
+                // Appends to whatever the buffer already holds
+                let mut written = String::from("prefix:");
+                base64_encode_to(kind, &data[..len], &mut written).unwrap();
+                assert_eq!(format!("prefix:{}", encoder.encode(&data[..len])), written);
+            }
+
+            let mut written = String::new();
+            encoder.encode_to(&data, &mut written).unwrap();
+            assert_eq!(encoder.encode(&data), written);
+        }
+    }
+
+    #[test]
+    fn encode_to_formatter() {
+        struct Encoded<'a>(&'a [u8]);
+
+        impl std::fmt::Display for Encoded<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                base64_encode_to(Kind::Basic, self.0, f)
+            }
+        }
+
+        assert_eq!("Zm9vYmFy", Encoded(b"foobar").to_string());
+        assert_eq!("Zm9vYg==", Encoded(b"foob").to_string());
+    }
 }
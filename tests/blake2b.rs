@@ -0,0 +1,128 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::hash::blake2b::Blake2b;
+    use himitsu::traits::hash::Hash;
+
+    macro_rules! blake2b_kat {
+        (
+            $fn_name: ident,
+            $key_len: literal,
+            $input_len: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let key: Vec<u8> = (0..$key_len).collect();
+                let input: Vec<u8> = (0..$input_len).collect();
+                let expected = decode($expected);
+
+                let mut hash = Blake2b::with_key(&key, 64);
+                hash.update(&input);
+
+                assert_eq!(expected, hash.finalize());
+            }
+        };
+    }
+
+    // Example from [RFC 7693](https://www.rfc-editor.org/rfc/rfc7693#appendix-A)
+    #[test]
+    fn test_blake2b_abc() {
+        let expected = decode("BA80A53F 981C4D0D 6A2797B6 9F12F6E9 4C212F14 685AC4B7 4B12BB6F DBFFA2D1 7D87C539 2AAB792D C252D5DE 4533CC95 18D38AA8 DBF1925A B92386ED D4009923");
+
+        assert_eq!(expected, Blake2b::digest(b"abc"));
+    }
+
+    // Known answer tests from the BLAKE2 reference implementation, the input is 0x00, 0x01, ...
+    // and the key (if any) is 0x00, 0x01, ..., 0x3f
+
+    blake2b_kat!(
+        test_blake2b_kat_unkeyed_0,
+        0u8,
+        0u8,
+        "786A02F7 42015903 C6C6FD85 2552D272 912F4740 E1584761 8A86E217 F71F5419 D25E1031 AFEE5853 13896444 934EB04B 903A685B 1448B755 D56F701A FE9BE2CE"
+    );
+
+    blake2b_kat!(
+        test_blake2b_kat_unkeyed_1,
+        0u8,
+        1u8,
+        "2FA3F686 DF876995 167E7C2E 5D74C4C7 B6E48F80 68FE0E44 208344D4 80F7904C 36963E44 115FE3EB 2A3AC869 4C28BCB4 F5A0F327 6F2E7948 7D821905 7A506E4B"
+    );
+
+    blake2b_kat!(
+        test_blake2b_kat_unkeyed_255,
+        0u8,
+        255u8,
+        "5B21C5FD 88683676 12474FA2 E70E9CFA 2201FFEE E8FAFAB5 797AD58F EFA17C9B 5B107DA4 A3DB6320 BAAF2C86 17D5A51D F914AE88 DA3867C2 D41F0CC1 4FA67928"
+    );
+
+    blake2b_kat!(
+        test_blake2b_kat_keyed_0,
+        64u8,
+        0u8,
+        "10EBB677 00B1868E FB441798 7ACF4690 AE9D972F B7A590C2 F0287179 9AAA4786 B5E996E8 F0F4EB98 1FC214B0 05F42D2F F4233499 391653DF 7AEFCBC1 3FC51568"
+    );
+
+    blake2b_kat!(
+        test_blake2b_kat_keyed_1,
+        64u8,
+        1u8,
+        "961F6DD1 E4DD30F6 3901690C 512E78E4 B45E4742 ED197C3C 5E45C549 FD25F2E4 187B0BC9 FE30492B 16B0D0BC 4EF9B0F3 4C7003FA C09A5EF1 532E6943 0234CEBD"
+    );
+
+    // Exactly one block, which must be compressed as the last block
+    blake2b_kat!(
+        test_blake2b_kat_keyed_128,
+        64u8,
+        128u8,
+        "72065EE4 DD91C2D8 509FA1FC 28A37C7F C9FA7D5B 3F8AD3D0 D7A25626 B57B1B44 788D4CAF 80629042 5F9890A3 A2A35A90 5AB4B37A CFD0DA6E 4517B252 5C9651E4"
+    );
+
+    blake2b_kat!(
+        test_blake2b_kat_keyed_255,
+        64u8,
+        255u8,
+        "142709D6 2E28FCCC D0AF97FA D0F8465B 971E8220 1DC51070 FAA0372A A43E9248 4BE1C1E7 3BA10906 D5D1853D B6A4106E 0A7BF980 0D373D6D EE2D46D6 2EF2A461"
+    );
+
+    #[test]
+    fn test_blake2b_output_len() {
+        let mut hash = Blake2b::with_key(&[], 32);
+        hash.update(b"abc");
+        assert_eq!(decode("BDDD813C 63423972 3171EF3F EE98579B 94964E3B B1CB3E42 7262C8C0 68D52319"), hash.finalize());
+
+        let mut mac = Blake2b::with_key(b"key", 16);
+        mac.update(b"abc");
+        assert_eq!(decode("2CF02AE1 6AC225EE 731F9D35 9D8A1C38"), mac.finalize());
+    }
+
+    #[test]
+    fn test_blake2b_incremental_update() {
+        let input: Vec<u8> = (0..255).collect();
+
+        let mut hash = Blake2b::new();
+        for chunk in input.chunks(64) {
+            hash.update(chunk);
+        }
+
+        assert_eq!(Blake2b::digest(&input), hash.finalize());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_blake2b_key_too_long() {
+        Blake2b::with_key(&[0; 65], 64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_blake2b_output_len_zero() {
+        Blake2b::with_key(&[], 0);
+    }
+}
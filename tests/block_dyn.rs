@@ -0,0 +1,136 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::{
+        cipher::block::{
+            buffered::BufferedCipherEncryptionProvider,
+            cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+            ecb::{EcbDecryptionProvider, EcbEncryptionProvider},
+            primitive::{
+                aes::{Aes, AES_BLOCKSIZE},
+                blowfish::Blowfish,
+                des::Des,
+            },
+        },
+        errors::blockcipher::BlockCipherError,
+        traits::cipher::{
+            block::{BlockCipherDecryption, BlockCipherEncryption},
+            primitive::BlockCipherRuntimeInfo,
+            sink::ByteSink,
+        },
+    };
+
+    enum Selected {
+        Ecb,
+        Cbc([u8; AES_BLOCKSIZE]),
+    }
+
+    impl Selected {
+        fn encryption(&self, aes: Aes) -> Box<dyn BlockCipherEncryption<AES_BLOCKSIZE>> {
+            match self {
+                Selected::Ecb => Box::new(aes.with_ecb_encryption()),
+                Selected::Cbc(iv) => Box::new(aes.with_cbc_encryption(*iv)),
+            }
+        }
+
+        fn decryption(&self, aes: Aes) -> Box<dyn BlockCipherDecryption<AES_BLOCKSIZE>> {
+            match self {
+                Selected::Ecb => Box::new(aes.with_ecb_decryption()),
+                Selected::Cbc(iv) => Box::new(aes.with_cbc_decryption(*iv)),
+            }
+        }
+    }
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+    #[test]
+    fn test_dyn_encrypt_slice() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+        let expected = [
+            decode("3AD77BB4 0D7A3660 A89ECAF3 2466EF97 F5D3D585 03B9699D E785895A 96FDBAAF"),
+            decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2"),
+        ];
+
+        let selected = [Selected::Ecb, Selected::Cbc(iv)];
+        let mut ciphers: Vec<Box<dyn BlockCipherEncryption<AES_BLOCKSIZE>>> = selected
+            .iter()
+            .map(|s| s.encryption(Aes::new(&key).unwrap()))
+            .collect();
+
+        for ((cipher, selected), expected) in ciphers.iter_mut().zip(selected.iter()).zip(expected.iter()) {
+            let mut data = plaintext.clone();
+            for block in data.chunks_exact_mut(AES_BLOCKSIZE) {
+                cipher.encrypt_slice(block).unwrap();
+            }
+            assert_eq!(expected, &data);
+
+            let mut decryption = selected.decryption(Aes::new(&key).unwrap());
+            for block in data.chunks_exact_mut(AES_BLOCKSIZE) {
+                decryption.decrypt_slice(block).unwrap();
+            }
+            assert_eq!(plaintext, data);
+        }
+    }
+
+    #[test]
+    fn test_dyn_buffered() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2");
+
+        let mut cipher = Selected::Cbc(iv).encryption(Aes::new(&key).unwrap()).buffered();
+        cipher.write_bytes(&plaintext).unwrap();
+        let output: Vec<u8> = cipher.finalize();
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_encrypt_slice_invalid_length() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let mut cipher = Selected::Ecb.encryption(Aes::new(&key).unwrap());
+
+        for len in [0, 15, 17, 32] {
+            match cipher.encrypt_slice(&mut vec![0; len]) {
+                Err(BlockCipherError::InvalidBlockLength { got, expected }) => {
+                    assert_eq!(len, got);
+                    assert_eq!(AES_BLOCKSIZE, expected);
+                }
+                other => panic!("unexpected result for {} bytes: {:?}", len, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_dyn_runtime_info() {
+        let ciphers: Vec<Box<dyn BlockCipherRuntimeInfo>> = vec![
+            Box::new(Aes::new(&[0; 16]).unwrap().with_cbc_encryption([0; 16])),
+            Box::new(Des::new(&[0x13; 8]).unwrap().with_ecb_encryption().buffered()),
+            Box::new(Blowfish::new(&[0; 4]).unwrap()),
+        ];
+        let expected = [(16, (16, 32)), (8, (8, 8)), (8, (1, 56))];
+
+        for (cipher, (block_size, key_bounds)) in ciphers.iter().zip(expected.iter()) {
+            assert_eq!(*block_size, cipher.block_size());
+            assert_eq!(*key_bounds, cipher.key_bounds());
+        }
+    }
+
+    // The bounds allow rejecting a key before constructing the primitive
+    #[test]
+    fn test_dyn_key_bounds_validation() {
+        let cipher: Box<dyn BlockCipherRuntimeInfo> = Box::new(Aes::new(&[0; 32]).unwrap().with_ecb_encryption());
+        let (min, max) = cipher.key_bounds();
+
+        for len in [8, 16, 24, 32, 40] {
+            assert_eq!((min..=max).contains(&len), Aes::new(&vec![0; len]).is_ok());
+        }
+    }
+}
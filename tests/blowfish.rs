@@ -0,0 +1,117 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::{
+        cipher::block::{
+            buffered::{BufferedCipherDecryptionProvider, BufferedCipherEncryptionProvider},
+            cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+            primitive::blowfish::*,
+        },
+        errors::blockcipher::BlockCipherError,
+        traits::cipher::primitive::{BlockCipherPrimitiveDecryption, BlockCipherPrimitiveEncryption},
+    };
+    use himitsu::traits::cipher::sink::ByteSink;
+
+    macro_rules! blowfish_test {
+        (
+            $fn_name: ident,
+            $key: literal,
+            $plaintext: literal,
+            $ciphertext: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let key = decode($key);
+                let plaintext: BlowfishBlock = decode_into_array($plaintext);
+                let ciphertext: BlowfishBlock = decode_into_array($ciphertext);
+
+                let blowfish = Blowfish::new(&key).unwrap();
+
+                let mut buf = plaintext;
+                blowfish.encrypt(&mut buf);
+                assert_eq!(ciphertext, buf);
+
+                blowfish.decrypt(&mut buf);
+                assert_eq!(plaintext, buf);
+            }
+        };
+    }
+
+    // Test vectors by Eric Young
+
+    blowfish_test!(test_blowfish_zero, "00000000 00000000", "00000000 00000000", "4EF99745 6198DD78");
+    blowfish_test!(test_blowfish_ones, "FFFFFFFF FFFFFFFF", "FFFFFFFF FFFFFFFF", "51866FD5 B85ECB8A");
+    blowfish_test!(test_blowfish_3, "30000000 00000000", "10000000 00000001", "7D856F9A 613063F2");
+    blowfish_test!(test_blowfish_4, "11111111 11111111", "11111111 11111111", "2466DD87 8B963C9D");
+    blowfish_test!(test_blowfish_5, "01234567 89ABCDEF", "11111111 11111111", "61F9C380 2281B096");
+    blowfish_test!(test_blowfish_6, "11111111 11111111", "01234567 89ABCDEF", "7D0CC630 AFDA1EC7");
+    blowfish_test!(test_blowfish_7, "FEDCBA98 76543210", "01234567 89ABCDEF", "0ACEAB0F C6A0A28D");
+    blowfish_test!(test_blowfish_8, "7CA11045 4A1A6E57", "01A1D6D0 39776742", "59C68245 EB05282B");
+    blowfish_test!(test_blowfish_9, "0131D961 9DC1376E", "5CD54CA8 3DEF57DA", "B1B8CC0B 250F09A0");
+
+    #[test]
+    fn test_blowfish_variable_key_length() {
+        let key = decode("F0E1D2C3 B4A59687 78695A4B 3C2D1E0F 00112233 44556677");
+        let plaintext: BlowfishBlock = decode_into_array("FEDCBA98 76543210");
+        let expected = [
+            "F9AD597C 49DB005E", "E91D21C1 D961A6D6", "E9C2B70A 1BC65CF3", "BE1E6394 08640F05",
+            "B39E4448 1BDB1E6E", "9457AA83 B1928C0D", "8BB77032 F960629D", "E87A244E 2CC85E82",
+            "15750E7A 4F4EC577", "122BA70B 3AB64AE0", "3A833C9A FFC537F6", "9409DA87 A90F6BF2",
+            "884F8062 5060B8B4", "1F85031C 19E11968", "79D9373A 714CA34F", "93142887 EE3BE15C",
+            "03429E83 8CE2D14B", "A4299E27 469FF67B", "AFD5AED1 C1BC96A8", "10851C0E 3858DA9F",
+            "E6F51ED7 9B9DB21F", "64A6E14A FD36B46F", "80C7D7D4 5A5479AD", "05044B62 FA52D080",
+        ];
+
+        for (len, expected) in (1..=key.len()).zip(expected) {
+            let blowfish = Blowfish::new(&key[..len]).unwrap();
+
+            let mut buf = plaintext;
+            blowfish.encrypt(&mut buf);
+            assert_eq!(decode(expected), buf, "key length {}", len);
+
+            blowfish.decrypt(&mut buf);
+            assert_eq!(plaintext, buf);
+        }
+    }
+
+    #[test]
+    fn test_blowfish_invalid_key_length() {
+        for len in [0, 57, 72] {
+            match Blowfish::new(&vec![0; len]) {
+                Err(BlockCipherError::InvalidKeyLength { got, min, max }) => {
+                    assert_eq!(len, got);
+                    assert_eq!(BLOWFISH_KEYLEN_MIN, min);
+                    assert_eq!(BLOWFISH_KEYLEN_MAX, max);
+                }
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("accepted a {} byte key", len),
+            }
+        }
+
+        assert!(Blowfish::new(&[0; BLOWFISH_KEYLEN_MAX]).is_ok());
+    }
+
+    #[test]
+    fn test_blowfish_cbc_round_trip() {
+        let key = decode("01234567 89ABCDEF F0E1D2C3 B4A59687");
+        let iv = decode_into_array("FEDCBA98 76543210");
+        // "7654321 Now is the time for " padded with zeros
+        let plaintext = decode("37363534 33323120 4E6F7720 69732074 68652074 696D6520 666F7220 00000000");
+        let expected = decode("6B77B4D6 3006DEE6 05B156E2 74039793 58DEB9E7 154616D9 59F1652B D5FF92CC");
+
+        let mut cipher = Blowfish::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_bytes(&plaintext).unwrap();
+        let ciphertext: Vec<u8> = cipher.finalize();
+        assert_eq!(expected, ciphertext);
+
+        let mut cipher = Blowfish::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_bytes(&ciphertext).unwrap();
+        let output: Vec<u8> = cipher.finalize().unwrap();
+        assert_eq!(plaintext, output);
+    }
+}
@@ -1,13 +1,18 @@
+#![cfg(feature = "alloc")]
+
 #[cfg(test)]
 mod tests {
 
-    use himitsu::util::buffer::ArrayBuffer;
+    use himitsu::util::buffer::{ArrayBuffer, FixedBuffer};
+    use std::convert::TryFrom;
+    #[cfg(feature = "std")]
+    use std::io::{self, Read};
 
     #[test]
     fn test_push() {
         let mut buf_size_4: ArrayBuffer<u8, 4> = ArrayBuffer::new();
 
-        let data = vec![1u8, 2, 3, 4];
+        let data = [1u8, 2, 3, 4];
         let data_iter = data.iter();
 
         for b in data_iter {
@@ -20,7 +25,7 @@ mod tests {
     #[test]
     fn test_extract() {
         let mut buf: ArrayBuffer<u8, 4> = ArrayBuffer::new();
-        let data = vec![1, 2, 3, 4];
+        let data = [1, 2, 3, 4];
         let exp = vec![1, 2, 3, 4];
 
         let data_iter = data.iter();
@@ -35,7 +40,7 @@ mod tests {
     #[test]
     fn test_index() {
         let mut buf: ArrayBuffer<u8, 4> = ArrayBuffer::new();
-        let data = vec![1, 2, 3, 4];
+        let data = [1, 2, 3, 4];
 
         for el in data.iter() {
             buf.push_ref(el);
@@ -46,4 +51,158 @@ mod tests {
         buf[1] = 55;
         assert_eq!(55, buf[1]);
     }
+
+    // Reader that hands out at most 3 bytes per read
+    #[cfg(feature = "std")]
+    struct ShortReader {
+        data: Vec<u8>,
+        pos: usize,
+    }
+
+    #[cfg(feature = "std")]
+    impl Read for ShortReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(3).min(self.data.len() - self.pos);
+            buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_fill_from_short_reads() {
+        let mut reader = ShortReader { data: (1u8..=10).collect(), pos: 0 };
+        let mut buf: ArrayBuffer<u8, 8> = ArrayBuffer::new();
+
+        assert_eq!(8, buf.fill_from(&mut reader).unwrap());
+        assert!(buf.is_full());
+        assert_eq!([1, 2, 3, 4, 5, 6, 7, 8], buf.extract());
+
+        // Only 2 bytes are left before EOF
+        assert_eq!(2, buf.fill_from(&mut reader).unwrap());
+        assert_eq!(6, buf.remaining());
+        assert_eq!(0, buf.fill_from(&mut reader).unwrap());
+    }
+
+    #[test]
+    fn test_filled() {
+        let mut buf: ArrayBuffer<u8, 4> = ArrayBuffer::new();
+        assert!(buf.filled().is_empty());
+
+        buf.push_slice(&[1, 2]);
+        assert_eq!(&[1, 2], buf.filled());
+
+        buf.push_slice(&[3, 4, 5]);
+        assert_eq!(&[1, 2, 3, 4], buf.filled());
+
+        buf.extract();
+        assert!(buf.filled().is_empty());
+    }
+
+    #[test]
+    fn test_override_contents() {
+        let mut buf: ArrayBuffer<u8, 4> = ArrayBuffer::new();
+        buf.push_slice(&[9, 9, 9]);
+
+        assert_eq!(2, buf.override_contents(&[1, 2]));
+        assert_eq!(&[1, 2], buf.filled());
+        assert_eq!(&[1, 2, 0, 0], buf.as_ref());
+
+        assert_eq!(4, buf.override_contents(&[1, 2, 3, 4, 5]));
+        assert!(buf.is_full());
+    }
+
+    #[test]
+    fn test_merged_api() {
+        let mut buf: FixedBuffer<u8, 4> = FixedBuffer::new();
+        assert_eq!(4, buf.remaining());
+        assert!(buf.is_empty());
+
+        assert_eq!(3, buf.push_slice(&[1, 2, 3]));
+        assert_eq!(1, buf.remaining());
+        assert!(!buf.is_full());
+
+        buf.as_mut()[0] = 7;
+        assert_eq!(1, buf.push_slice(&[4, 5]));
+        assert!(buf.is_full());
+
+        assert_eq!([7, 2, 3, 4], buf.extract());
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_from_array() {
+        let buf: FixedBuffer<u8, 4> = FixedBuffer::from([1, 2, 3, 4]);
+        assert!(buf.is_full());
+        assert_eq!(&[1, 2, 3, 4], buf.filled());
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let iv = [7u8; 16];
+
+        let buf = FixedBuffer::<u8, 16>::try_from(&iv[..]).unwrap();
+        assert!(buf.is_full());
+        assert_eq!(&iv, buf.as_ref());
+
+        assert!(FixedBuffer::<u8, 16>::try_from(&iv[..15]).is_err());
+        assert!(FixedBuffer::<u8, 8>::try_from(&iv[..]).is_err());
+        assert!(FixedBuffer::<u8, 4>::try_from(&[][..]).is_err());
+    }
+
+    // capacity is the total size, len the filled count and remaining the unfilled count
+    #[test]
+    fn test_capacity_len_remaining() {
+        let mut buf: ArrayBuffer<u8, 4> = ArrayBuffer::new();
+        assert_eq!((4, 0, 4), (buf.capacity(), buf.len(), buf.remaining()));
+
+        buf.push(1);
+        assert_eq!((4, 1, 3), (buf.capacity(), buf.len(), buf.remaining()));
+
+        buf.push_slice(&[2, 3]);
+        assert_eq!((4, 3, 1), (buf.capacity(), buf.len(), buf.remaining()));
+        assert_eq!(buf.len(), buf.filled().len());
+
+        buf.push_slice(&[4, 5]);
+        assert_eq!((4, 4, 0), (buf.capacity(), buf.len(), buf.remaining()));
+
+        buf.extract();
+        assert_eq!((4, 0, 4), (buf.capacity(), buf.len(), buf.remaining()));
+
+        let full = ArrayBuffer::from([1u8, 2, 3]);
+        assert_eq!((3, 3, 0), (full.capacity(), full.len(), full.remaining()));
+    }
+
+    // A slice that does not fit is rejected as a whole with the overflow count
+    #[test]
+    fn test_try_push_slice() {
+        let mut buf: ArrayBuffer<u8, 4> = ArrayBuffer::new();
+
+        assert_eq!(Ok(()), buf.try_push_slice(&[1, 2, 3]));
+        assert_eq!(Err(2), buf.try_push_slice(&[4, 5, 6]));
+        assert_eq!(&[1, 2, 3], buf.filled());
+
+        assert_eq!(Ok(()), buf.try_push_slice(&[4]));
+        assert_eq!(Ok(()), buf.try_push_slice(&[]));
+        assert_eq!(Err(1), buf.try_push_slice(&[5]));
+        assert_eq!([1, 2, 3, 4], buf.extract());
+    }
+
+    // Both names construct an empty buffer by default, also as part of a derived struct
+    #[test]
+    fn test_default() {
+        #[derive(Default)]
+        struct Embedded {
+            array: ArrayBuffer<u8, 4>,
+            fixed: FixedBuffer<u16, 8>,
+        }
+
+        let embedded = Embedded::default();
+        assert!(embedded.array.is_empty() && embedded.fixed.is_empty());
+        assert_eq!((4, 8), (embedded.array.capacity(), embedded.fixed.capacity()));
+
+        let buf: ArrayBuffer<u8, 16> = Default::default();
+        assert_eq!(0, buf.len());
+    }
 }
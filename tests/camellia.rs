@@ -0,0 +1,118 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::{
+        cipher::block::{
+            buffered::{BufferedCipherDecryptionProvider, BufferedCipherEncryptionProvider},
+            cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+            ecb::{EcbDecryptionProvider, EcbEncryptionProvider},
+            primitive::camellia::*,
+        },
+        errors::blockcipher::BlockCipherError,
+        traits::cipher::primitive::{BlockCipherPrimitiveDecryption, BlockCipherPrimitiveEncryption},
+    };
+    use himitsu::traits::cipher::sink::ByteSink;
+
+    macro_rules! camellia_test {
+        (
+            $fn_name: ident,
+            $key: literal,
+            $plaintext: literal,
+            $ciphertext: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let key = decode($key);
+                let plaintext: CamelliaBlock = decode_into_array($plaintext);
+                let ciphertext: CamelliaBlock = decode_into_array($ciphertext);
+
+                let camellia = Camellia::new(&key).unwrap();
+
+                let mut buf = plaintext;
+                camellia.encrypt(&mut buf);
+                assert_eq!(ciphertext, buf);
+
+                camellia.decrypt(&mut buf);
+                assert_eq!(plaintext, buf);
+            }
+        };
+    }
+
+    // Test vectors from [RFC 3713](https://www.rfc-editor.org/rfc/rfc3713#appendix-A)
+
+    camellia_test!(
+        test_camellia_128,
+        "01234567 89abcdef fedcba98 76543210",
+        "01234567 89abcdef fedcba98 76543210",
+        "67673138 54966973 08570656 48eabe43"
+    );
+
+    camellia_test!(
+        test_camellia_192,
+        "01234567 89abcdef fedcba98 76543210 00112233 44556677",
+        "01234567 89abcdef fedcba98 76543210",
+        "b4993401 b3e996f8 4ee5cee7 d79b09b9"
+    );
+
+    camellia_test!(
+        test_camellia_256,
+        "01234567 89abcdef fedcba98 76543210 00112233 44556677 8899aabb ccddeeff",
+        "01234567 89abcdef fedcba98 76543210",
+        "9acc237d ff16d76c 20ef7c91 9e3a7509"
+    );
+
+    #[test]
+    fn test_camellia_invalid_key_length() {
+        for len in [0, 15, 20, 33] {
+            match Camellia::new(&vec![0; len]) {
+                Err(BlockCipherError::InvalidKeyLength { got, min, max }) => {
+                    assert_eq!(len, got);
+                    assert_eq!(CAMELLIA_128_KEYLEN, min);
+                    assert_eq!(CAMELLIA_256_KEYLEN, max);
+                }
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("accepted a {} byte key", len),
+            }
+        }
+    }
+
+    #[test]
+    fn test_camellia_ecb_round_trip() {
+        let key = decode("01234567 89abcdef fedcba98 76543210");
+        let plaintext = decode("01234567 89abcdef fedcba98 76543210 01234567 89abcdef fedcba98 76543210");
+        let expected = decode("67673138 54966973 08570656 48eabe43 67673138 54966973 08570656 48eabe43");
+
+        let mut cipher = Camellia::new(&key).unwrap().with_ecb_encryption().buffered();
+        cipher.write_bytes(&plaintext).unwrap();
+        let ciphertext: Vec<u8> = cipher.finalize();
+        assert_eq!(expected, ciphertext);
+
+        let mut cipher = Camellia::new(&key).unwrap().with_ecb_decryption().buffered();
+        cipher.write_bytes(&ciphertext).unwrap();
+        let output: Vec<u8> = cipher.finalize().unwrap();
+        assert_eq!(plaintext, output);
+    }
+
+    #[test]
+    fn test_camellia_cbc_round_trip() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710");
+        let expected = decode("1607CF49 4B36BBF0 0DAEB0B5 03C831AB A2F2CF67 1629EF78 40C5A5DF B5074887 0F061650 08CF8B8B 5A635863 62543E54 E7208A2C A89CC21A ACD56AAA 6FB98259");
+
+        let mut cipher = Camellia::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_bytes(&plaintext).unwrap();
+        let ciphertext: Vec<u8> = cipher.finalize();
+        assert_eq!(expected, ciphertext);
+
+        let mut cipher = Camellia::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_bytes(&ciphertext).unwrap();
+        let output: Vec<u8> = cipher.finalize().unwrap();
+        assert_eq!(plaintext, output);
+    }
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::{
+        cipher::block::{
+            cbc::{CbcCtsDecryptionProvider, CbcCtsEncryptionProvider},
+            primitive::aes::Aes,
+        },
+        errors::blockcipher::BlockCipherError,
+    };
+
+    const KEY: &str = "63686963 6B656E20 74657269 79616B69";
+
+    macro_rules! cts_test {
+        (
+            $fn_name: ident,
+            $input: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let input = decode($input);
+                let expected = decode($expected);
+                let iv = [0; 16];
+
+                let cipher = Aes::new(&decode(KEY)).unwrap().with_cbc_cts_encryption(iv);
+                let mut output = input.clone();
+                cipher.encrypt(&mut output).unwrap();
+
+                assert_eq!(expected, output);
+
+                let cipher = Aes::new(&decode(KEY)).unwrap().with_cbc_cts_decryption(iv);
+                cipher.decrypt(&mut output).unwrap();
+
+                assert_eq!(input, output);
+            }
+        };
+    }
+
+    // Test vectors from RFC 3962 Appendix B
+    cts_test!(
+        cts_rfc3962_17,
+        "4920776F 756C6420 6C696B65 20746865 20",
+        "C6353568 F2BF8CB4 D8A58036 2DA7FF7F 97"
+    );
+
+    cts_test!(
+        cts_rfc3962_31,
+        "4920776F 756C6420 6C696B65 20746865 2047656E 6572616C 20476175 277320",
+        "FC00783E 0EFDB2C1 D445D4C8 EFF7ED22 97687268 D6ECCCC0 C07B25E2 5ECFE5"
+    );
+
+    cts_test!(
+        cts_rfc3962_32,
+        "4920776F 756C6420 6C696B65 20746865 2047656E 6572616C 20476175 27732043",
+        "39312523 A78662D5 BE7FCBCC 98EBF5A8 97687268 D6ECCCC0 C07B25E2 5ECFE584"
+    );
+
+    cts_test!(
+        cts_rfc3962_47,
+        "4920776F 756C6420 6C696B65 20746865 2047656E 6572616C 20476175 27732043
+         6869636B 656E2C20 706C6561 73652C",
+        "97687268 D6ECCCC0 C07B25E2 5ECFE584 B3FFFD94 0C16A18C 1B5549D2 F838029E
+         39312523 A78662D5 BE7FCBCC 98EBF5"
+    );
+
+    cts_test!(
+        cts_rfc3962_48,
+        "4920776F 756C6420 6C696B65 20746865 2047656E 6572616C 20476175 27732043
+         6869636B 656E2C20 706C6561 73652C20",
+        "97687268 D6ECCCC0 C07B25E2 5ECFE584 9DAD8BBB 96C4CDC0 3BC103E1 A194BBD8
+         39312523 A78662D5 BE7FCBCC 98EBF5A8"
+    );
+
+    cts_test!(
+        cts_rfc3962_64,
+        "4920776F 756C6420 6C696B65 20746865 2047656E 6572616C 20476175 27732043
+         6869636B 656E2C20 706C6561 73652C20 616E6420 776F6E74 6F6E2073 6F75702E",
+        "97687268 D6ECCCC0 C07B25E2 5ECFE584 39312523 A78662D5 BE7FCBCC 98EBF5A8
+         4807EFE8 36EE89A5 26730DBC 2F7BC840 9DAD8BBB 96C4CDC0 3BC103E1 A194BBD8"
+    );
+
+    // A single block is not swapped and matches plain CBC
+    #[test]
+    fn cts_single_block() {
+        let mut data: [u8; 16] = decode_into_array("4920776F 756C6420 6C696B65 20746865");
+
+        let cipher = Aes::new(&decode(KEY)).unwrap().with_cbc_cts_encryption([0; 16]);
+        cipher.encrypt(&mut data).unwrap();
+
+        assert_eq!(decode("97687268 D6ECCCC0 C07B25E2 5ECFE584"), data);
+    }
+
+    #[test]
+    fn cts_too_short() {
+        let cipher = Aes::new(&decode(KEY)).unwrap().with_cbc_cts_encryption([0; 16]);
+
+        match cipher.encrypt(&mut [0; 15]) {
+            Err(BlockCipherError::IncompleteBlock(missing)) => assert_eq!(1, missing),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// IV reuse detection is only available with std
+#![cfg(feature = "std")]
+
+mod common;
+
+// Reuse is only tracked in debug builds
+#[cfg(all(test, debug_assertions))]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::cipher::block::{
+        cbc::{iv_reuse, syncronous::CbcEncryption, CbcEncryptionProvider},
+        primitive::aes::Aes,
+    };
+
+    const KEY: &str = "2B7E1516 28AED2A6 ABF71588 09CF4F3C";
+    const IV: &str = "00010203 04050607 08090A0B 0C0D0E0F";
+
+    #[test]
+    fn test_iv_reuse_detected() {
+        iv_reuse::reset();
+        iv_reuse::enable(true);
+        let iv = decode_into_array(IV);
+
+        let _first = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption(iv);
+        assert!(!iv_reuse::reuse_detected());
+
+        let _second = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption(iv);
+        assert!(iv_reuse::reuse_detected());
+
+        iv_reuse::reset();
+        assert!(!iv_reuse::reuse_detected());
+    }
+
+    #[test]
+    fn test_iv_reuse_disabled_by_default() {
+        iv_reuse::reset();
+        let iv = decode_into_array(IV);
+
+        let _first = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption(iv);
+        let _second = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption(iv);
+        assert!(!iv_reuse::reuse_detected());
+
+        iv_reuse::enable(true);
+        let _third = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption(iv);
+        assert!(!iv_reuse::reuse_detected());
+    }
+
+    #[test]
+    fn test_iv_reuse_distinct_pairs() {
+        iv_reuse::reset();
+        iv_reuse::enable(true);
+        let iv = decode_into_array(IV);
+
+        let _same_iv = Aes::new(&[0; 16]).unwrap().with_cbc_encryption(iv);
+        let _same_key = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption([0; 16]);
+        let _ = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption(iv);
+
+        assert!(!iv_reuse::reuse_detected());
+    }
+
+    #[test]
+    fn test_iv_reuse_reset_iv() {
+        iv_reuse::reset();
+        iv_reuse::enable(true);
+
+        let mut cipher = CbcEncryption::new(Aes::new(&decode(KEY)).unwrap(), decode_into_array(IV));
+        cipher.reset_iv(&decode(IV)).unwrap();
+
+        assert!(iv_reuse::reuse_detected());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_iv_reuse_panics() {
+        iv_reuse::reset();
+        iv_reuse::enable(true);
+        iv_reuse::panic_on_reuse(true);
+        let iv = decode_into_array(IV);
+
+        let _first = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption(iv);
+        let _second = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption(iv);
+    }
+}
@@ -1,3 +1,6 @@
+// Exercises the std::io integration
+#![cfg(feature = "std")]
+
 mod common;
 
 #[cfg(test)]
@@ -33,7 +36,7 @@ mod tests {
                 let iv = decode_into_array($iv);
                 let expected = decode($expected);
 
-                let mut cipher = <$cipher>::new(&key).with_cbc_encryption(iv).buffered();
+                let mut cipher = <$cipher>::new(&key).unwrap().with_cbc_encryption(iv).buffered();
                 cipher.write_all(&input).unwrap();
 
                 let output: Vec<u8> = cipher.finalize();
@@ -59,10 +62,10 @@ mod tests {
                 let iv = decode_into_array($iv);
                 let expected = decode($expected);
 
-                let mut cipher = <$cipher>::new(&key).with_cbc_decryption(iv).buffered();
+                let mut cipher = <$cipher>::new(&key).unwrap().with_cbc_decryption(iv).buffered();
                 cipher.write_all(&input).unwrap();
 
-                let output: Vec<u8> = cipher.finalize();
+                let output: Vec<u8> = cipher.finalize().unwrap();
 
                 assert_eq!(expected, output);
             }
@@ -124,4 +127,452 @@ mod tests {
         "F58C4C04 D6E5F1BA 779EABFB 5F7BFBD6 9CFC4E96 7EDB808D 679F777B C6702C7D 39F23369 A9D9BACF A530E263 04231461 B2EB05E2 C39BE9FC DA6C1907 8C6A9D1B",
         "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710"
     );
+
+    #[test]
+    fn test_cbc_seeded_iv_is_reproducible() {
+        use himitsu::cipher::block::cbc::syncronous::CbcEncryption;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+
+        let (_, iv1) = CbcEncryption::with_seeded_iv(aes::Aes::new(&key).unwrap(), 42);
+        let (_, iv2) = CbcEncryption::with_seeded_iv(aes::Aes::new(&key).unwrap(), 42);
+        let (_, iv3) = CbcEncryption::with_seeded_iv(aes::Aes::new(&key).unwrap(), 43);
+
+        assert_eq!(iv1, iv2);
+        assert_ne!(iv1, iv3);
+    }
+
+    #[test]
+    fn test_cbc_seeded_iv_is_used() {
+        use himitsu::cipher::block::cbc::syncronous::CbcEncryption;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let input = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+
+        let (cipher, iv) = CbcEncryption::with_seeded_iv(aes::Aes::new(&key).unwrap(), 7);
+        let mut seeded = cipher.buffered();
+        seeded.write_all(&input).unwrap();
+
+        let mut explicit = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        explicit.write_all(&input).unwrap();
+
+        let (seeded, explicit): (Vec<u8>, Vec<u8>) = (seeded.finalize(), explicit.finalize());
+        assert_eq!(explicit, seeded);
+    }
+
+    // The provider trait and the plain constructor must be interchangeable,
+    // regardless of whether the result is collected into a Vec or a Readable
+    #[test]
+    fn test_cbc_entry_points_agree() {
+        use himitsu::cipher::block::cbc::syncronous::CbcEncryption;
+        use himitsu::util::readable::Readable;
+        use std::io::Read;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let input = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710");
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2 73BED6B8 E3C1743B 7116E69E 22229516 3FF1CAA1 681FAC09 120ECA30 7586E1A7");
+
+        let mut provided = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        provided.write_all(&input).unwrap();
+
+        let mut constructed = CbcEncryption::new(aes::Aes::new(&key).unwrap(), iv).buffered();
+        constructed.write_all(&input).unwrap();
+
+        let provided: Vec<u8> = provided.finalize();
+        let mut readable: Readable<Vec<u8>> = constructed.finalize();
+
+        let mut constructed = Vec::new();
+        readable.read_to_end(&mut constructed).unwrap();
+
+        assert_eq!(expected, provided);
+        assert_eq!(expected, constructed);
+    }
+
+    // Truncated ciphertext must not be accepted silently
+    #[test]
+    fn test_cbc_dec_incomplete_block() {
+        use himitsu::errors::blockcipher::BlockCipherError;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let input = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_all(&input).unwrap();
+
+        match cipher.finalize::<Vec<u8>>() {
+            Err(BlockCipherError::IncompleteBlock(missing)) => assert_eq!(4, missing),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_all(&input).unwrap();
+
+        let expected = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A");
+        let output: Vec<u8> = cipher.finalize_ignore_partial();
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_cbc_iv_length() {
+        use himitsu::cipher::block::cbc::syncronous::{CbcDecryption, CbcEncryption};
+        use himitsu::errors::blockcipher::BlockCipherError;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode("00010203 04050607 08090A0B 0C0D0E0F");
+
+        for len in [15, 17] {
+            let iv = vec![0; len];
+
+            match CbcEncryption::try_new(aes::Aes::new(&key).unwrap(), &iv) {
+                Err(BlockCipherError::InvalidIvLength { got, expected: 16 }) => assert_eq!(len, got),
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("accepted a {} byte iv", len),
+            }
+
+            assert!(CbcDecryption::try_new(aes::Aes::new(&key).unwrap(), &iv).is_err());
+        }
+
+        let input = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A");
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D");
+
+        let mut cipher = CbcEncryption::try_new(aes::Aes::new(&key).unwrap(), &iv).unwrap().buffered();
+        cipher.write_all(&input).unwrap();
+
+        let output: Vec<u8> = cipher.finalize();
+        assert_eq!(expected, output);
+    }
+
+    // The truncating constructor keeps the lenient behavior of using up to one block of IV
+    #[test]
+    fn test_cbc_iv_truncating() {
+        use himitsu::cipher::block::cbc::syncronous::CbcEncryption;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let long_iv = decode("00010203 04050607 08090A0B 0C0D0E0F FFFFFFFF");
+        let short_iv = decode("00010203 04050607");
+        let padded_iv = decode_into_array("00010203 04050607 00000000 00000000");
+        let input = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A");
+
+        let mut long = CbcEncryption::new_truncating(aes::Aes::new(&key).unwrap(), &long_iv).buffered();
+        long.write_all(&input).unwrap();
+        let long: Vec<u8> = long.finalize();
+        assert_eq!(decode("7649ABAC 8119B246 CEE98E9B 12E9197D"), long);
+
+        let mut short = CbcEncryption::new_truncating(aes::Aes::new(&key).unwrap(), &short_iv).buffered();
+        short.write_all(&input).unwrap();
+        let short: Vec<u8> = short.finalize();
+
+        let mut padded = aes::Aes::new(&key).unwrap().with_cbc_encryption(padded_iv).buffered();
+        padded.write_all(&input).unwrap();
+        let padded: Vec<u8> = padded.finalize();
+
+        assert_eq!(padded, short);
+    }
+
+    #[test]
+    fn test_cbc_reset_iv() {
+        use himitsu::cipher::block::cbc::syncronous::CbcEncryption;
+        use himitsu::prelude::*;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode("00010203 04050607 08090A0B 0C0D0E0F");
+        let input = decode_into_array("6BC1BEE2 2E409F96 E93D7E11 7393172A");
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D");
+
+        let mut cipher = CbcEncryption::new(aes::Aes::new(&key).unwrap(), [0xFF; 16]);
+        let mut block = input;
+        cipher.encrypt(&mut block);
+
+        cipher.reset_iv(&iv).unwrap();
+        let mut block = input;
+        cipher.encrypt(&mut block);
+
+        assert_eq!(expected, block);
+        assert!(cipher.reset_iv(&iv[..8]).is_err());
+    }
+
+    // In place decryption of block aligned chunks must match the io::Write and finalize path
+    #[test]
+    fn test_cbc_decrypt_in_place() {
+        use himitsu::errors::blockcipher::BlockCipherError;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let input = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2 73BED6B8 E3C1743B 7116E69E 22229516 3FF1CAA1 681FAC09 120ECA30 7586E1A7");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_all(&input).unwrap();
+        let expected: Vec<u8> = cipher.finalize().unwrap();
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        let mut data = input.clone();
+
+        let (head, tail) = data.split_at_mut(32);
+        assert_eq!(32, cipher.decrypt_in_place(head).unwrap());
+        assert_eq!(32, cipher.decrypt_in_place(tail).unwrap());
+        assert_eq!(expected, data);
+
+        let output: Vec<u8> = cipher.finalize().unwrap();
+        assert!(output.is_empty());
+
+        // A ragged tail is buffered and reported on finalize
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        let mut data = input[..20].to_vec();
+
+        assert_eq!(16, cipher.decrypt_in_place(&mut data).unwrap());
+        assert_eq!(expected[..16], data[..16]);
+        assert!(matches!(cipher.decrypt_in_place(&mut [0; 16]), Err(BlockCipherError::IncompleteBlock(12))));
+        assert!(matches!(cipher.finalize::<Vec<u8>>(), Err(BlockCipherError::IncompleteBlock(12))));
+    }
+
+    // Encrypting block aligned data directly must match the io::Write path
+    #[test]
+    fn test_cbc_encrypt_blocks() {
+        use himitsu::errors::blockcipher::BlockCipherError;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let input = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_all(&input).unwrap();
+        let expected: Vec<u8> = cipher.finalize();
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.encrypt_blocks(&input[..32]).unwrap();
+        cipher.write_all(&input[32..48]).unwrap();
+        cipher.encrypt_blocks(&input[48..]).unwrap();
+        assert_eq!(4, cipher.blocks_processed());
+
+        let output: Vec<u8> = cipher.finalize();
+        assert_eq!(expected, output);
+
+        // Unaligned input and a pending incomplete block are rejected
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        assert!(matches!(cipher.encrypt_blocks(&input[..20]), Err(BlockCipherError::IncompleteBlock(12))));
+        assert_eq!(0, cipher.blocks_processed());
+
+        cipher.write_all(&input[..4]).unwrap();
+        assert!(matches!(cipher.encrypt_blocks(&input[..16]), Err(BlockCipherError::IncompleteBlock(12))));
+    }
+
+    // Padded output verified with openssl enc -aes-128-cbc
+    #[test]
+    fn test_cbc_pkcs7_padding() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57");
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 2E013F89 0472D822 17B17F45 F6E7F539");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_all(&plaintext).unwrap();
+        let ciphertext: Vec<u8> = cipher.finalize_padded().unwrap();
+        assert_eq!(expected, ciphertext);
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_all(&ciphertext).unwrap();
+        let output: Vec<u8> = cipher.finalize_unpadded().unwrap();
+        assert_eq!(plaintext, output);
+
+        // Block aligned input gains a full block of padding
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_all(&plaintext[..16]).unwrap();
+        let ciphertext: Vec<u8> = cipher.finalize_padded().unwrap();
+        assert_eq!(32, ciphertext.len());
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_all(&ciphertext).unwrap();
+        let output: Vec<u8> = cipher.finalize_unpadded().unwrap();
+        assert_eq!(plaintext[..16], output);
+    }
+
+    // Truncation and tampering must be reported as distinct padding errors
+    #[test]
+    fn test_cbc_bad_padding() {
+        use himitsu::errors::blockcipher::{BlockCipherError, PaddingErrorKind};
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let ciphertext = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 2E013F89 0472D822 17B17F45 F6E7F539");
+
+        let unpad = |input: &[u8]| {
+            let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+            cipher.write_all(input).unwrap();
+            cipher.finalize_unpadded::<Vec<u8>>()
+        };
+
+        for truncated in [&ciphertext[..31], &ciphertext[..17], &[][..]] {
+            match unpad(truncated) {
+                Err(BlockCipherError::BadPadding { kind: PaddingErrorKind::Length }) => {}
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+
+        // Flipping a bit of the previous block flips the same bit of the padding byte
+        let mut flipped = ciphertext.clone();
+        flipped[15] ^= 0x01;
+        match unpad(&flipped) {
+            Err(BlockCipherError::BadPadding { kind: PaddingErrorKind::Content }) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        // The first of the eleven padding bytes is checked as well
+        let mut flipped = ciphertext.clone();
+        flipped[5] ^= 0x01;
+        match unpad(&flipped) {
+            Err(BlockCipherError::BadPadding { kind: PaddingErrorKind::Content }) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        // Flipping a byte of the final block garbles its whole plaintext
+        let mut flipped = ciphertext;
+        flipped[31] ^= 0xFF;
+        match unpad(&flipped) {
+            Err(BlockCipherError::BadPadding { kind: PaddingErrorKind::Content }) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+    #[test]
+    fn test_cbc_finalize_into() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2");
+
+        // The contents are appended after what the caller already has
+        let mut out = b"header".to_vec();
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_all(&plaintext).unwrap();
+        cipher.finalize_into(&mut out);
+
+        assert_eq!(b"header", &out[..6]);
+        assert_eq!(expected, &out[6..]);
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_all(&out[6..]).unwrap();
+        cipher.finalize_into(&mut out).unwrap();
+
+        assert_eq!(plaintext, &out[6 + 32..]);
+    }
+
+    #[test]
+    fn test_cbc_finalize_into_slice() {
+        use himitsu::errors::blockcipher::BlockCipherError;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2");
+
+        let mut out = [0u8; 40];
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_all(&plaintext).unwrap();
+        assert_eq!(32, cipher.finalize_into_slice(&mut out).unwrap());
+        assert_eq!(expected, &out[..32]);
+        assert_eq!([0; 8], out[32..]);
+
+        // A slice that is too small is left untouched
+        let mut out = [0u8; 31];
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_all(&plaintext).unwrap();
+
+        match cipher.finalize_into_slice(&mut out) {
+            Err(BlockCipherError::OutputTooSmall { got, expected }) => {
+                assert_eq!(31, got);
+                assert_eq!(32, expected);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!([0; 31], out);
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_all(&expected).unwrap();
+        assert!(matches!(cipher.finalize_into_slice(&mut [0; 16]), Err(BlockCipherError::OutputTooSmall { .. })));
+
+        // An incomplete block is reported before the length of the slice
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_all(&expected[..20]).unwrap();
+        assert!(matches!(cipher.finalize_into_slice(&mut [0; 64]), Err(BlockCipherError::IncompleteBlock(12))));
+    }
+
+    #[test]
+    fn test_cbc_encrypt_reader() {
+        use himitsu::cipher::block::buffered::{decrypt_reader, encrypt_reader};
+        use std::io::{Cursor, Read};
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2");
+
+        let cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv);
+        let mut output = Vec::new();
+        encrypt_reader(cipher, Cursor::new(&plaintext)).unwrap().read_to_end(&mut output).unwrap();
+        assert_eq!(expected, output);
+
+        let cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv);
+        let mut output = Vec::new();
+        decrypt_reader(cipher, Cursor::new(&expected)).unwrap().read_to_end(&mut output).unwrap();
+        assert_eq!(plaintext, output);
+    }
+
+    #[test]
+    fn test_cbc_encrypt_reader_incomplete_block() {
+        use himitsu::cipher::block::buffered::encrypt_reader;
+        use himitsu::errors::blockcipher::BlockCipherError;
+        use std::io::{self, Cursor};
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+
+        let cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv);
+        let err = match encrypt_reader(cipher, Cursor::new(vec![0u8; 37])) {
+            Err(err) => err,
+            Ok(_) => panic!("encrypted non-aligned input"),
+        };
+
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+        match err.into_inner().unwrap().downcast_ref::<BlockCipherError>() {
+            Some(BlockCipherError::IncompleteBlock(11)) => {}
+            other => panic!("unexpected inner error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_cbc_buffered_drain() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext: Vec<u8> = (0..1000u32).map(|i| (i * 13) as u8).collect();
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_all(&plaintext).unwrap();
+        let expected: Vec<u8> = cipher.finalize_padded().unwrap();
+
+        // Draining between writes keeps the chaining state and the buffered bytes of an incomplete block
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        let mut ciphertext = Vec::new();
+        for chunk in plaintext.chunks(37) {
+            cipher.write_all(chunk).unwrap();
+            let drained = cipher.drain();
+            assert_eq!(0, drained.len() % 16);
+            ciphertext.extend(drained);
+        }
+        assert!(cipher.drain().is_empty());
+        ciphertext.extend(cipher.finalize_padded::<Vec<u8>>().unwrap());
+        assert_eq!(expected, ciphertext);
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        let mut decrypted = Vec::new();
+        for chunk in expected.chunks(100) {
+            cipher.write_all(chunk).unwrap();
+            decrypted.extend(cipher.drain());
+        }
+        decrypted.extend(cipher.finalize::<Vec<u8>>().unwrap());
+        decrypted.truncate(plaintext.len());
+        assert_eq!(plaintext, decrypted);
+    }
 }
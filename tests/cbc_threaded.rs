@@ -1,3 +1,6 @@
+// The threaded modes are only available with std
+#![cfg(feature = "std")]
+
 mod common;
 
 #[cfg(test)]
@@ -6,10 +9,38 @@ mod tests {
     use super::common::{decode, decode_into_array};
     use himitsu::cipher::block::{
         primitive::aes,
-        cbc::ThreadedCbcDecryptionProvider
+        cbc::{CbcEncryptionProvider, ThreadedCbcDecryptionProvider, ThreadedCbcEncryptionProvider}
     };
+    use himitsu::errors::blockcipher::BlockCipherError;
+    use himitsu::prelude::*;
     use std::io::Write;
 
+    macro_rules! cbc_test_enc {
+        (
+            $fn_name: ident,
+            $cipher: ty,
+            $key: literal,
+            $iv: literal,
+            $input: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let input = decode($input);
+                let key = decode($key);
+                let iv = decode_into_array($iv);
+                let expected = decode($expected);
+
+                let mut cipher = <$cipher>::new(&key).unwrap().with_threaded_cbc_encryption(iv);
+                cipher.write_all(&input).unwrap();
+
+                let output: Vec<u8> = cipher.finalize().unwrap();
+
+                assert_eq!(expected, output);
+            }
+        };
+    }
+
     macro_rules! cbc_test_dec {
         (
             $fn_name: ident,
@@ -26,7 +57,7 @@ mod tests {
                 let iv = decode_into_array($iv);
                 let expected = decode($expected);
 
-                let mut cipher = <$cipher>::new(&key).with_threaded_cbc_decryption(iv, 4);
+                let mut cipher = <$cipher>::new(&key).unwrap().with_threaded_cbc_decryption(iv, 4);
                 cipher.write_all(&input).unwrap();
 
                 let output: Vec<u8> = cipher.finalize();
@@ -64,4 +95,103 @@ mod tests {
         "F58C4C04 D6E5F1BA 779EABFB 5F7BFBD6 9CFC4E96 7EDB808D 679F777B C6702C7D 39F23369 A9D9BACF A530E263 04231461 B2EB05E2 C39BE9FC DA6C1907 8C6A9D1B",
         "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710"
     );
+
+    cbc_test_enc!(
+        test_threaded_cbc_aes128_enc,
+        aes::Aes,
+        "2B7E1516 28AED2A6 ABF71588 09CF4F3C",
+        "00010203 04050607 08090A0B 0C0D0E0F",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710",
+        "7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2 73BED6B8 E3C1743B 7116E69E 22229516 3FF1CAA1 681FAC09 120ECA30 7586E1A7"
+    );
+
+    cbc_test_enc!(
+        test_threaded_cbc_aes192_enc,
+        aes::Aes,
+        "8E73B0F7 DA0E6452 C810F32B 809079E5 62F8EAD2 522C6B7B",
+        "00010203 04050607 08090A0B 0C0D0E0F",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710",
+        "4F021DB2 43BC633D 7178183A 9FA071E8 B4D9ADA9 AD7DEDF4 E5E73876 3F69145A 571B2420 12FB7AE0 7FA9BAAC 3DF102E0 08B0E279 88598881 D920A9E6 4F5615CD"
+    );
+
+    cbc_test_enc!(
+        test_threaded_cbc_aes256_enc,
+        aes::Aes,
+        "603DEB10 15CA71BE 2B73AEF0 857D7781 1F352C07 3B6108D7 2D9810A3 0914DFF4",
+        "00010203 04050607 08090A0B 0C0D0E0F",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710",
+        "F58C4C04 D6E5F1BA 779EABFB 5F7BFBD6 9CFC4E96 7EDB808D 679F777B C6702C7D 39F23369 A9D9BACF A530E263 04231461 B2EB05E2 C39BE9FC DA6C1907 8C6A9D1B"
+    );
+
+    // A larger input written in uneven chunks must match the synchronous implementation
+    #[test]
+    fn test_threaded_cbc_enc_matches_syncronous() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let input: Vec<u8> = (0..4096u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        let mut threaded = aes::Aes::new(&key).unwrap().with_threaded_cbc_encryption(iv);
+        for chunk in input.chunks(37) {
+            threaded.write_all(chunk).unwrap();
+        }
+        assert_eq!(None, threaded.missing());
+        let output: Vec<u8> = threaded.finalize().unwrap();
+
+        let mut expected = input.clone();
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_encryption(iv);
+        for block in expected.chunks_mut(16) {
+            let mut buf = [0u8; 16];
+            buf.copy_from_slice(block);
+            cipher.encrypt(&mut buf);
+            block.copy_from_slice(&buf);
+        }
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_threaded_cbc_enc_incomplete_block() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let mut cipher = aes::Aes::new(&key).unwrap().with_threaded_cbc_encryption([0; 16]);
+        cipher.write_all(&[0; 20]).unwrap();
+
+        match cipher.finalize::<Vec<u8>>() {
+            Err(BlockCipherError::IncompleteBlock(12)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    // Primitive whose worker thread dies on the second block
+    struct Crashing(std::sync::atomic::AtomicUsize);
+
+    impl BlockCipherPrimitiveInfo for Crashing {
+        const BLOCKSIZE: usize = 16;
+        const KEYLEN_MIN: usize = 16;
+        const KEYLEN_MAX: usize = 16;
+    }
+
+    impl BlockCipherPrimitiveEncryption<16> for Crashing {
+        fn encrypt(&self, _block: &mut [u8; 16]) {
+            if self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 1 {
+                panic!("primitive crashed");
+            }
+        }
+    }
+
+    // A panic of the worker must surface as error on write and on finalize
+    #[test]
+    fn test_threaded_cbc_enc_worker_panic() {
+        let mut cipher = Crashing(Default::default()).with_threaded_cbc_encryption([0; 16]);
+        cipher.write_all(&[0; 16]).unwrap();
+
+        match cipher.write_bytes(&[0; 16]) {
+            Err(BlockCipherError::BackendFailure(_)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        match cipher.finalize::<Vec<u8>>() {
+            Err(BlockCipherError::BackendFailure(_)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
 }
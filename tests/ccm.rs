@@ -0,0 +1,186 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::cipher::aead::ccm::Ccm;
+    use himitsu::cipher::block::primitive::aes::Aes;
+    use himitsu::errors::blockcipher::BlockCipherError;
+
+    const KEY: &str = "C0C1C2C3 C4C5C6C7 C8C9CACB CCCDCECF";
+
+    fn ccm(nonce_len: usize, tag_len: usize) -> Ccm<Aes> {
+        Ccm::new(Aes::new(&decode(KEY)).unwrap(), nonce_len, tag_len).unwrap()
+    }
+
+    macro_rules! ccm_test {
+        (
+            $fn_name: ident,
+            $tag_len: literal,
+            $nonce: literal,
+            $packet_len: literal,
+            $aad_len: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let nonce = decode($nonce);
+                let ccm = ccm(nonce.len(), $tag_len);
+
+                // The packets count up from zero, the first bytes are authenticated only
+                let packet: Vec<u8> = (0..$packet_len).collect();
+                let (aad, plaintext) = packet.split_at($aad_len);
+
+                // The published output is the unencrypted header followed by the ciphertext and the tag
+                let expected = decode($expected);
+                let (expected_ct, expected_tag) = expected[$aad_len..].split_at(plaintext.len());
+
+                let (ciphertext, tag) = ccm.encrypt(&nonce, aad, plaintext).unwrap();
+                assert_eq!(aad, &expected[..$aad_len]);
+                assert_eq!(expected_ct, ciphertext.as_slice());
+                assert_eq!(expected_tag, tag.as_slice());
+
+                assert_eq!(plaintext, ccm.decrypt(&nonce, aad, &ciphertext, &tag).unwrap().as_slice());
+            }
+        };
+    }
+
+    // RFC 3610 section 8, packet vectors #1 to #4 and #9
+    ccm_test!(
+        test_ccm_packet_1,
+        8,
+        "00000003 020100A0 A1A2A3A4 A5",
+        31,
+        8,
+        "00010203 04050607 588C979A 61C663D2 F066D0C2 C0F98980 6D5F6B61 DAC38417 E8D12CFD F926E0"
+    );
+
+    ccm_test!(
+        test_ccm_packet_2,
+        8,
+        "00000004 030201A0 A1A2A3A4 A5",
+        32,
+        8,
+        "00010203 04050607 72C91A36 E135F8CF 291CA894 085C87E3 CC15C439 C9E43A3B A091D56E 10400916"
+    );
+
+    ccm_test!(
+        test_ccm_packet_3,
+        8,
+        "00000005 040302A0 A1A2A3A4 A5",
+        33,
+        8,
+        "00010203 04050607 51B1E5F4 4A197D1D A46B0F8E 2D282AE8 71E838BB 64DA8596 574ADAA7 6FBD9FB0 C5"
+    );
+
+    ccm_test!(
+        test_ccm_packet_4,
+        8,
+        "00000006 050403A0 A1A2A3A4 A5",
+        31,
+        12,
+        "00010203 04050607 08090A0B A28C6865 939A9A79 FAAA5C4C 2A9D4A91 CDAC8C96 C861B9C9 E61EF1"
+    );
+
+    ccm_test!(
+        test_ccm_packet_9,
+        10,
+        "00000009 080706A0 A1A2A3A4 A5",
+        31,
+        8,
+        "00010203 04050607 0135D1B2 C95F41D5 D1D4FEC1 85D166B8 094E999D FED96C04 8C56602C 97ACBB74 90"
+    );
+
+    // The associated data length is encoded in 2 bytes below 2^16 - 2^8 and in 6 bytes from there on.
+    // Verified with the AESCCM implementation of the python cryptography package
+    #[test]
+    fn test_ccm_aad_length_encoding() {
+        let ccm = ccm(13, 8);
+        let nonce = decode("00000003 020100A0 A1A2A3A4 A5");
+        let plaintext: Vec<u8> = (0..23).collect();
+
+        let cases = [
+            (0, "A7D7C840 7459EF96"),
+            (0xFEFF, "DE041973 C6663E74"),
+            (0xFF00, "C30AB996 DDBC37B9"),
+            (0x10000, "FA7F9D26 88AE4AC8"),
+        ];
+
+        for (len, expected) in cases.iter() {
+            let aad: Vec<u8> = (0..*len).map(|i| i as u8).collect();
+
+            let (ciphertext, tag) = ccm.encrypt(&nonce, &aad, &plaintext).unwrap();
+            assert_eq!(decode("50849F92 69CE6BDA E87EC8DA D8E19198 65576369 D2CB8C"), ciphertext);
+            assert_eq!(decode(expected), tag, "aad length {:#x}", len);
+
+            assert_eq!(plaintext, ccm.decrypt(&nonce, &aad, &ciphertext, &tag).unwrap());
+        }
+    }
+
+    // Verified with the AESCCM implementation of the python cryptography package
+    #[test]
+    fn test_ccm_short_nonce() {
+        let nonce = decode("10111213 141516");
+
+        let (ciphertext, tag) = ccm(7, 16).encrypt(&nonce, b"header", b"attack at dawn").unwrap();
+        assert_eq!(decode("6B585D0B F42AC6E5 9C97C62D 105F"), ciphertext);
+        assert_eq!(decode("C66A3A01 08045C27 D4C63E87 3A9E25B4"), tag);
+
+        let (ciphertext, tag) = ccm(7, 4).encrypt(&nonce, b"header", b"").unwrap();
+        assert!(ciphertext.is_empty());
+        assert_eq!(decode("A769032A"), tag);
+    }
+
+    #[test]
+    fn test_ccm_tampered() {
+        let ccm = ccm(13, 8);
+        let nonce = decode("00000003 020100A0 A1A2A3A4 A5");
+
+        let (ciphertext, tag) = ccm.encrypt(&nonce, b"header", b"attack at dawn").unwrap();
+
+        let mut tampered = ciphertext.clone();
+        tampered[0] ^= 1;
+        assert!(matches!(
+            ccm.decrypt(&nonce, b"header", &tampered, &tag),
+            Err(BlockCipherError::AuthenticationFailed)
+        ));
+
+        assert!(matches!(
+            ccm.decrypt(&nonce, b"other", &ciphertext, &tag),
+            Err(BlockCipherError::AuthenticationFailed)
+        ));
+
+        assert!(matches!(
+            ccm.decrypt(&nonce, b"header", &ciphertext, &tag[..6]),
+            Err(BlockCipherError::AuthenticationFailed)
+        ));
+    }
+
+    #[test]
+    fn test_ccm_invalid_parameters() {
+        let aes = Aes::new(&decode(KEY)).unwrap();
+
+        for (nonce_len, tag_len) in [(6, 8), (14, 8), (13, 2), (13, 5), (13, 18)] {
+            assert!(matches!(
+                Ccm::new(aes.clone(), nonce_len, tag_len),
+                Err(BlockCipherError::InvalidParameter(_))
+            ));
+        }
+
+        assert!(matches!(
+            ccm(13, 8).encrypt(&[0; 12], &[], &[]),
+            Err(BlockCipherError::InvalidIvLength { got: 12, expected: 13 })
+        ));
+
+        // A 13 byte nonce leaves 2 bytes for the message length
+        let ccm = ccm(13, 8);
+        assert!(ccm.encrypt(&[0; 13], &[], &[0; 0xFFFF]).is_ok());
+        assert!(matches!(
+            ccm.encrypt(&[0; 13], &[], &[0; 0x10000]),
+            Err(BlockCipherError::InvalidParameter(_))
+        ));
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::cipher::stream::chacha20::ChaCha20;
+    use himitsu::prelude::*;
+
+    const SUNSCREEN: &[u8] = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+    const CIPHERTEXT: &str = "6E2E359A 2568F980 41BA0728 DD0D6981 E97E7AEC 1D4360C2 0A27AFCC FD9FAE0B F91B65C5 524733AB 8F593DAB CD62B357 1639D624 E65152AB 8F530C35 9F0861D8 07CA0DBF 500D6A61 56A38E08 8A22B65E 52BC514D 16CCF806 818CE91A B7793736 5AF90BBF 74A35BE6 B40B8EED F2785E42 874D";
+
+    // RFC 8439, 2.4.2
+    #[test]
+    fn test_chacha20_rfc8439_enc() {
+        let key = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F 10111213 14151617 18191A1B 1C1D1E1F");
+        let nonce = decode_into_array("00000000 0000004A 00000000");
+
+        let mut data = SUNSCREEN.to_vec();
+        ChaCha20::new(&key, &nonce, 1).encrypt(&mut data);
+
+        assert_eq!(decode(CIPHERTEXT), data);
+    }
+
+    #[test]
+    fn test_chacha20_rfc8439_dec_chunked() {
+        let key = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F 10111213 14151617 18191A1B 1C1D1E1F");
+        let nonce = decode_into_array("00000000 0000004A 00000000");
+
+        let mut data = decode(CIPHERTEXT);
+        let mut cipher = ChaCha20::new(&key, &nonce, 1);
+
+        for chunk in data.chunks_mut(7) {
+            cipher.decrypt(chunk);
+        }
+
+        assert_eq!(SUNSCREEN, &data[..]);
+    }
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::cipher::aead::chacha20poly1305::ChaCha20Poly1305;
+    use himitsu::errors::blockcipher::BlockCipherError;
+    use himitsu::prelude::*;
+    #[cfg(feature = "std")]
+    use std::io::Write;
+
+    const SUNSCREEN: &[u8] = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+    const KEY: &str = "80818283 84858687 88898A8B 8C8D8E8F 90919293 94959697 98999A9B 9C9D9E9F";
+    const NONCE: &str = "07000000 40414243 44454647";
+    const AAD: &str = "50515253 C0C1C2C3 C4C5C6C7";
+    const CIPHERTEXT: &str = "D31A8D34 648E60DB 7B86AFBC 53EF7EC2 A4ADED51 296E08FE A9E2B5A7 36EE62D6 3DBEA45E 8CA96712 82FAFB69 DA92728B 1A71DE0A 9E060B29 05D6A5B6 7ECD3B36 92DDBD7F 2D778B8C 9803AEE3 28091B58 FAB324E4 FAD67594 5585808B 4831D7BC 3FF4DEF0 8E4B7A9D E576D265 86CEC64B 6116";
+    const TAG: &str = "1AE10B59 4F09E26A 7E902ECB D0600691";
+
+    // RFC 8439, 2.8.2
+    #[test]
+    fn test_chacha20poly1305_rfc8439_enc() {
+        let aead = ChaCha20Poly1305::new(&decode_into_array(KEY));
+        let (ciphertext, tag) = aead.encrypt(&decode_into_array(NONCE), &decode(AAD), SUNSCREEN);
+
+        assert_eq!(decode(CIPHERTEXT), ciphertext);
+        assert_eq!(decode(TAG), tag);
+    }
+
+    #[test]
+    fn test_chacha20poly1305_rfc8439_dec() {
+        let aead = ChaCha20Poly1305::new(&decode_into_array(KEY));
+        let plaintext = aead
+            .decrypt(&decode_into_array(NONCE), &decode(AAD), &decode(CIPHERTEXT), &decode_into_array(TAG))
+            .unwrap();
+
+        assert_eq!(SUNSCREEN, &plaintext[..]);
+    }
+
+    #[test]
+    fn test_chacha20poly1305_tampered() {
+        let aead = ChaCha20Poly1305::new(&decode_into_array(KEY));
+        let nonce = decode_into_array(NONCE);
+        let tag = decode_into_array(TAG);
+
+        let mut ciphertext = decode(CIPHERTEXT);
+        ciphertext[42] ^= 1;
+
+        let mut aad = decode(AAD);
+        aad[0] ^= 1;
+
+        assert!(matches!(
+            aead.decrypt(&nonce, &decode(AAD), &ciphertext, &tag),
+            Err(BlockCipherError::AuthenticationFailed)
+        ));
+        assert!(matches!(
+            aead.decrypt(&nonce, &aad, &decode(CIPHERTEXT), &tag),
+            Err(BlockCipherError::AuthenticationFailed)
+        ));
+    }
+
+    // Generic over any AEAD, encrypting and decrypting must round trip and reject a modified tag
+    fn round_trip<A: Aead>(aead: &A) {
+        let mut nonce = A::Nonce::default();
+        nonce.as_mut()[0] = 1;
+
+        let (ciphertext, mut tag) = aead.encrypt(&nonce, b"header", SUNSCREEN);
+
+        assert_eq!(SUNSCREEN.len(), ciphertext.len());
+        assert_eq!(A::NONCE_LEN, nonce.as_ref().len());
+        assert_eq!(A::TAG_LEN, tag.as_ref().len());
+        assert_eq!(SUNSCREEN, &aead.decrypt(&nonce, b"header", &ciphertext, &tag).unwrap()[..]);
+
+        tag.as_mut()[0] ^= 1;
+        assert!(aead.decrypt(&nonce, b"header", &ciphertext, &tag).is_err());
+    }
+
+    #[test]
+    fn test_aead_round_trip() {
+        round_trip(&ChaCha20Poly1305::new(&decode_into_array(KEY)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_chacha20poly1305_streaming_dec() {
+        let aead = ChaCha20Poly1305::new(&decode_into_array(KEY));
+        let mut cipher = aead.streaming_decryption(&decode_into_array(NONCE), &decode(AAD));
+
+        for chunk in decode(CIPHERTEXT).chunks(13) {
+            cipher.write_all(chunk).unwrap();
+        }
+
+        let plaintext: Vec<u8> = cipher.finalize(&decode_into_array(TAG)).unwrap();
+        assert_eq!(SUNSCREEN, &plaintext[..]);
+    }
+
+    // A corrupted chunk in the middle must fail on finalize, without releasing any plaintext
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_chacha20poly1305_streaming_dec_corrupted_chunk() {
+        let aead = ChaCha20Poly1305::new(&decode_into_array(KEY));
+        let mut cipher = aead.streaming_decryption(&decode_into_array(NONCE), &decode(AAD));
+
+        let ciphertext = decode(CIPHERTEXT);
+        let chunks: Vec<&[u8]> = ciphertext.chunks(16).collect();
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            let mut chunk = chunk.to_vec();
+
+            if i == chunks.len() / 2 {
+                chunk[3] ^= 0x80;
+            }
+
+            cipher.write_all(&chunk).unwrap();
+        }
+
+        match cipher.finalize::<Vec<u8>>(&decode_into_array(TAG)) {
+            Err(BlockCipherError::AuthenticationFailed) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    // The deferred streaming path must accept what the one-shot encryption produced and nothing else
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_chacha20poly1305_streaming_dec_bind_length() {
+        let aead = ChaCha20Poly1305::new(&decode_into_array(KEY)).with_bind_length(true);
+        let nonce = decode_into_array(NONCE);
+
+        let (ciphertext, tag) = aead.encrypt(&nonce, &decode(AAD), SUNSCREEN);
+        assert_ne!(decode(TAG), tag);
+
+        let mut cipher = aead.streaming_decryption(&nonce, &decode(AAD));
+        for chunk in ciphertext.chunks(13) {
+            cipher.write_all(chunk).unwrap();
+        }
+        let plaintext: Vec<u8> = cipher.finalize(&tag).unwrap();
+        assert_eq!(SUNSCREEN, &plaintext[..]);
+
+        let plain = ChaCha20Poly1305::new(&decode_into_array(KEY));
+        let mut cipher = plain.streaming_decryption(&nonce, &decode(AAD));
+        cipher.write_all(&ciphertext).unwrap();
+        assert!(cipher.finalize::<Vec<u8>>(&tag).is_err());
+    }
+}
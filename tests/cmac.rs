@@ -0,0 +1,82 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::cipher::block::primitive::aes;
+    use himitsu::mac::cmac::Cmac;
+
+    macro_rules! cmac_test {
+        (
+            $fn_name: ident,
+            $primitive: ty,
+            $key: literal,
+            $input: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let input = decode($input);
+                let key = decode($key);
+                let expected = decode($expected);
+
+                let mut mac = Cmac::new(<$primitive>::new(&key).unwrap());
+                mac.update(&input);
+
+                assert_eq!(expected, mac.finalize());
+            }
+        };
+    }
+
+    // Example values from NIST SP 800-38B, D.1
+
+    cmac_test!(
+        test_cmac_aes128_empty,
+        aes::Aes,
+        "2B7E1516 28AED2A6 ABF71588 09CF4F3C",
+        "",
+        "BB1D6929 E9593728 7FA37D12 9B756746"
+    );
+
+    cmac_test!(
+        test_cmac_aes128_one_block,
+        aes::Aes,
+        "2B7E1516 28AED2A6 ABF71588 09CF4F3C",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A",
+        "070A16B4 6B4D4144 F79BDD9D D04A287C"
+    );
+
+    cmac_test!(
+        test_cmac_aes128_partial_block,
+        aes::Aes,
+        "2B7E1516 28AED2A6 ABF71588 09CF4F3C",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411",
+        "DFA66747 DE9AE630 30CA3261 1497C827"
+    );
+
+    cmac_test!(
+        test_cmac_aes128_four_blocks,
+        aes::Aes,
+        "2B7E1516 28AED2A6 ABF71588 09CF4F3C",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710",
+        "51F0BEBF 7E3B9D92 FC497417 79363CFE"
+    );
+
+    // Feeding the message in odd sized chunks must produce the same tag as a single update
+    #[test]
+    fn test_cmac_incremental_update() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let input = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710");
+        let expected = decode("51F0BEBF 7E3B9D92 FC497417 79363CFE");
+
+        let mut mac = Cmac::new(aes::Aes::new(&key).unwrap());
+        for chunk in input.chunks(7) {
+            mac.update(chunk);
+        }
+
+        assert_eq!(expected, mac.finalize());
+    }
+}
@@ -0,0 +1,94 @@
+// The threaded modes are only available with std
+#![cfg(feature = "std")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::prelude::*;
+    use himitsu::cipher::{
+        block::primitive::aes,
+        stream::ctr::{syncronous::CounterWidth, CtrCipherProvider, ParallelCtrProvider}
+    };
+    use himitsu::errors::blockcipher::BlockCipherError;
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+    #[test]
+    fn test_parallel_ctr_aes128_enc() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF");
+        let mut input = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710");
+        let expected = decode("874D6191 B620E326 1BEF6864 990DB6CE 9806F66B 7970FDFF 8617187B B9FFFDFF 5AE4DF3E DBD5D35E 5B4F0902 0DB03EAB 1E031DDA 2FBE03D1 792170A0 F3009CEE");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_parallel_ctr(iv).with_threads(4);
+        cipher.encrypt(&mut input);
+
+        assert_eq!(expected, input);
+    }
+
+    // Uneven writes over a counter that carries across bytes must match the single-threaded output
+    #[test]
+    fn test_parallel_ctr_matches_syncronous() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEF0");
+        let plain: Vec<u8> = (0..10_000u32).map(|i| (i * 7 % 253) as u8).collect();
+
+        let mut expected = plain.clone();
+        aes::Aes::new(&key).unwrap().with_ctr(iv).encrypt(&mut expected);
+
+        for threads in [1, 2, 3, 8] {
+            let mut cipher = aes::Aes::new(&key).unwrap().with_parallel_ctr(iv).with_threads(threads);
+            let mut output = plain.clone();
+
+            for chunk in output.chunks_mut(1021) {
+                cipher.encrypt(chunk);
+            }
+
+            assert_eq!(expected, output, "{} threads", threads);
+
+            let mut cipher = aes::Aes::new(&key).unwrap().with_parallel_ctr(iv).with_threads(threads);
+            cipher.decrypt(&mut output);
+
+            assert_eq!(plain, output, "{} threads", threads);
+        }
+    }
+
+    #[test]
+    fn test_parallel_ctr_counter_width() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FFFFFFF0");
+
+        // 16 counter values are left before the low 32 bits wrap around
+        let mut cipher = aes::Aes::new(&key).unwrap().with_parallel_ctr(iv).with_threads(4).with_counter_width(CounterWidth::Bits32);
+        let mut data = [0u8; 17 * 16];
+        assert!(matches!(cipher.try_encrypt(&mut data), Err(BlockCipherError::CounterExhausted)));
+        assert_eq!([0u8; 17 * 16], data);
+
+        let mut data = [0u8; 16 * 16];
+        cipher.try_encrypt(&mut data).unwrap();
+
+        let mut expected = [0u8; 16 * 16];
+        aes::Aes::new(&key).unwrap().with_ctr(iv).encrypt(&mut expected);
+        assert_eq!(expected, data);
+
+        assert!(cipher.try_decrypt(&mut [0u8; 1]).is_err());
+    }
+
+    // At full width the counter wraps around to zero instead of panicking
+    #[test]
+    fn test_parallel_ctr_full_width_wraps_around() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFE");
+        let plain: Vec<u8> = (0..100u8).collect();
+
+        let mut expected = plain.clone();
+        aes::Aes::new(&key).unwrap().with_ctr(iv).encrypt(&mut expected);
+
+        let mut output = plain.clone();
+        aes::Aes::new(&key).unwrap().with_parallel_ctr(iv).with_threads(3).encrypt(&mut output);
+
+        assert_eq!(expected, output);
+    }
+}
@@ -0,0 +1,373 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::prelude::*;
+    use himitsu::cipher::{
+        block::primitive::aes,
+        stream::ctr::{syncronous::CounterWidth, CtrCipherProvider, DualChannelCtrProvider}
+    };
+    use himitsu::errors::blockcipher::BlockCipherError;
+
+    /// Keystream of the given counter blocks, computed with the primitive directly
+    fn keystream(key: &[u8], counters: &[&str]) -> Vec<u8> {
+        let aes = aes::Aes::new(key).unwrap();
+
+        counters.iter().flat_map(|counter| {
+            let mut block = decode_into_array(counter);
+            BlockCipherPrimitiveEncryption::encrypt(&aes, &mut block);
+            block
+        }).collect()
+    }
+
+    macro_rules! ctr_test_enc {
+        (
+            $fn_name: ident,
+            $cipher: ty,
+            $key: literal,
+            $iv: literal,
+            $input: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let mut input = decode($input);
+                let key = decode($key);
+                let iv = decode_into_array($iv);
+                let expected = decode($expected);
+
+                let mut cipher = <$cipher>::new(&key).unwrap().with_ctr(iv);
+                cipher.encrypt(&mut input);
+
+                assert_eq!(expected, input);
+            }
+        };
+    }
+
+    macro_rules! ctr_test_dec {
+        (
+            $fn_name: ident,
+            $cipher: ty,
+            $key: literal,
+            $iv: literal,
+            $input: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let mut input = decode($input);
+                let key = decode($key);
+                let iv = decode_into_array($iv);
+                let expected = decode($expected);
+
+                let mut cipher = <$cipher>::new(&key).unwrap().with_ctr(iv);
+                cipher.decrypt(&mut input);
+
+                assert_eq!(expected, input);
+            }
+        };
+    }
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+
+    ctr_test_enc!(
+        test_ctr_aes128_enc,
+        aes::Aes,
+        "2B7E1516 28AED2A6 ABF71588 09CF4F3C",
+        "F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710",
+        "874D6191 B620E326 1BEF6864 990DB6CE 9806F66B 7970FDFF 8617187B B9FFFDFF 5AE4DF3E DBD5D35E 5B4F0902 0DB03EAB 1E031DDA 2FBE03D1 792170A0 F3009CEE"
+    );
+
+    ctr_test_dec!(
+        test_ctr_aes128_dec,
+        aes::Aes,
+        "2B7E1516 28AED2A6 ABF71588 09CF4F3C",
+        "F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF",
+        "874D6191 B620E326 1BEF6864 990DB6CE 9806F66B 7970FDFF 8617187B B9FFFDFF 5AE4DF3E DBD5D35E 5B4F0902 0DB03EAB 1E031DDA 2FBE03D1 792170A0 F3009CEE",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710"
+    );
+
+    ctr_test_enc!(
+        test_ctr_aes256_enc,
+        aes::Aes,
+        "603DEB10 15CA71BE 2B73AEF0 857D7781 1F352C07 3B6108D7 2D9810A3 0914DFF4",
+        "F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710",
+        "601EC313 775789A5 B7A7F504 BBF3D228 F443E3CA 4D62B59A CA84E990 CACAF5C5 2B0930DA A23DE94C E87017BA 2D84988D DFC9C58D B67AADA6 13C2DD08 457941A6"
+    );
+
+    ctr_test_dec!(
+        test_ctr_aes256_dec,
+        aes::Aes,
+        "603DEB10 15CA71BE 2B73AEF0 857D7781 1F352C07 3B6108D7 2D9810A3 0914DFF4",
+        "F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF",
+        "601EC313 775789A5 B7A7F504 BBF3D228 F443E3CA 4D62B59A CA84E990 CACAF5C5 2B0930DA A23DE94C E87017BA 2D84988D DFC9C58D B67AADA6 13C2DD08 457941A6",
+        "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710"
+    );
+
+    // Interleaved writes to both channels must match two independent CTR streams
+    #[test]
+    fn test_dual_channel_ctr_interleaved() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv_a = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF");
+        let iv_b = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let plain_a = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46");
+        let plain_b = decode("30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710 6BC1BEE2");
+
+        let mut dual = aes::Aes::new(&key).unwrap().with_dual_channel_ctr(iv_a, iv_b);
+        let (mut cipher_a, mut cipher_b) = (plain_a.clone(), plain_b.clone());
+
+        let (mut chunks_a, mut chunks_b) = (cipher_a.chunks_mut(5), cipher_b.chunks_mut(7));
+
+        loop {
+            match (chunks_a.next(), chunks_b.next()) {
+                (None, None) => break,
+                (chunk_a, chunk_b) => {
+                    if let Some(chunk) = chunk_a {
+                        dual.channel_a().encrypt(chunk);
+                    }
+
+                    if let Some(chunk) = chunk_b {
+                        dual.channel_b().encrypt(chunk);
+                    }
+                }
+            }
+        }
+
+        let (mut expected_a, mut expected_b) = (plain_a.clone(), plain_b.clone());
+        aes::Aes::new(&key).unwrap().with_ctr(iv_a).encrypt(&mut expected_a);
+        aes::Aes::new(&key).unwrap().with_ctr(iv_b).encrypt(&mut expected_b);
+
+        assert_eq!(expected_a, cipher_a);
+        assert_eq!(expected_b, cipher_b);
+
+        let mut dual = aes::Aes::new(&key).unwrap().with_dual_channel_ctr(iv_a, iv_b);
+        dual.channel_b().decrypt(&mut cipher_b);
+        dual.channel_a().decrypt(&mut cipher_a);
+
+        assert_eq!(plain_a, cipher_a);
+        assert_eq!(plain_b, cipher_b);
+    }
+
+    #[test]
+    fn test_ctr_counter_after_blocks() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr(iv);
+        assert_eq!(0xF0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF, cipher.counter());
+
+        let mut data = [0u8; 3 * 16];
+        cipher.encrypt(&mut data);
+
+        assert_eq!(0xF0F1F2F3F4F5F6F7F8F9FAFBFCFDFF02, cipher.counter());
+    }
+
+    #[test]
+    fn test_ctr_set_counter() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF");
+        let resumed_iv = decode_into_array("00000000 00000000 00000000 0000002A");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr(iv);
+        let mut data = [0u8; 5];
+        cipher.encrypt(&mut data);
+        cipher.set_counter(42).unwrap();
+
+        let mut resumed = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57");
+        let mut expected = resumed.clone();
+
+        cipher.encrypt(&mut resumed);
+        aes::Aes::new(&key).unwrap().with_ctr(resumed_iv).encrypt(&mut expected);
+
+        assert_eq!(expected, resumed);
+        assert_eq!(44, cipher.counter());
+    }
+
+    #[test]
+    fn test_ctr_reset_iv() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode("F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF");
+        let expected = decode("874D6191 B620E326 1BEF6864 990DB6CE 9806F66B");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr([0; 16]);
+        let mut data = [0u8; 7];
+        cipher.encrypt(&mut data);
+
+        cipher.reset_iv(&iv).unwrap();
+        let mut data = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57");
+        cipher.encrypt(&mut data);
+
+        assert_eq!(expected, data);
+        assert!(cipher.reset_iv(&iv[1..]).is_err());
+    }
+
+    #[test]
+    fn test_ctr_counter_wrap_32() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FFFFFFFE");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr(iv).with_counter_width(CounterWidth::Bits32);
+        let mut data = [0u8; 2 * 16 - 3];
+        cipher.try_encrypt(&mut data).unwrap();
+
+        assert_eq!(keystream(&key, &["F0F1F2F3 F4F5F6F7 F8F9FAFB FFFFFFFE", "F0F1F2F3 F4F5F6F7 F8F9FAFB FFFFFFFF"])[..29], data[..]);
+
+        // Only the low 32 bits wrapped, the nonce part is untouched
+        assert_eq!(0xF0F1F2F3F4F5F6F7F8F9FAFB00000000, cipher.counter());
+
+        // The rest of the last keystream block can still be used
+        let mut data = [0u8; 3];
+        cipher.try_encrypt(&mut data).unwrap();
+
+        let mut data = [0u8; 1];
+        assert!(matches!(cipher.try_encrypt(&mut data), Err(BlockCipherError::CounterExhausted)));
+        assert_eq!([0], data);
+    }
+
+    #[test]
+    fn test_ctr_counter_wrap_64_checked_upfront() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 FFFFFFFF FFFFFFFD");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr(iv).with_counter_width(CounterWidth::Bits64);
+
+        // Four blocks do not fit into the remaining three counter values, nothing is encrypted
+        let mut data = [0u8; 4 * 16];
+        assert!(matches!(cipher.try_encrypt(&mut data), Err(BlockCipherError::CounterExhausted)));
+        assert_eq!([0u8; 4 * 16], data);
+        assert_eq!(0xF0F1F2F3F4F5F6F7FFFFFFFFFFFFFFFD, cipher.counter());
+
+        let mut data = [0u8; 3 * 16];
+        cipher.try_encrypt(&mut data).unwrap();
+
+        let expected = keystream(&key, &[
+            "F0F1F2F3 F4F5F6F7 FFFFFFFF FFFFFFFD",
+            "F0F1F2F3 F4F5F6F7 FFFFFFFF FFFFFFFE",
+            "F0F1F2F3 F4F5F6F7 FFFFFFFF FFFFFFFF",
+        ]);
+        assert_eq!(expected, data);
+    }
+
+    #[test]
+    fn test_ctr_counter_full_width_carries() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FFFFFFFF");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr(iv);
+        let mut data = [0u8; 2 * 16];
+        cipher.encrypt(&mut data);
+
+        let expected = keystream(&key, &["F0F1F2F3 F4F5F6F7 F8F9FAFB FFFFFFFF", "F0F1F2F3 F4F5F6F7 F8F9FAFC 00000000"]);
+        assert_eq!(expected, data);
+    }
+
+    #[test]
+    fn test_ctr_counter_full_width_wraps_around() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr(iv);
+        let mut data = [0u8; 2 * 16];
+        cipher.try_encrypt(&mut data).unwrap();
+
+        let expected = keystream(&key, &["FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF", "00000000 00000000 00000000 00000000"]);
+        assert_eq!(expected, data);
+        assert_eq!(1, cipher.counter());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ctr_counter_exhausted_panics() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr(iv).with_counter_width(CounterWidth::Bits32);
+        cipher.encrypt(&mut [0u8; 17]);
+    }
+
+    #[test]
+    fn test_ctr_set_counter_respects_width() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr(iv).with_counter_width(CounterWidth::Bits32);
+
+        // The nonce part of the counter block is kept
+        cipher.set_counter(0xFFFFFFFF).unwrap();
+        assert_eq!(0xF0F1F2F3F4F5F6F7F8F9FAFBFFFFFFFF, cipher.counter());
+
+        assert!(matches!(cipher.set_counter(1 << 32), Err(BlockCipherError::InvalidParameter(_))));
+        assert_eq!(0xF0F1F2F3F4F5F6F7F8F9FAFBFFFFFFFF, cipher.counter());
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr(iv).with_counter_width(CounterWidth::Bits64);
+        assert!(cipher.set_counter(1 << 64).is_err());
+        cipher.set_counter(u64::MAX as u128).unwrap();
+        assert_eq!(0xF0F1F2F3F4F5F6F7FFFFFFFFFFFFFFFF, cipher.counter());
+    }
+
+    #[test]
+    fn test_dual_channel_ctr_counter_width() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv_a = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FFFFFFFF");
+        let iv_b = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FFFFFFFE");
+
+        let mut dual = aes::Aes::new(&key).unwrap().with_dual_channel_ctr(iv_a, iv_b).with_counter_width(CounterWidth::Bits32);
+
+        let mut data = [0u8; 2 * 16];
+        assert!(matches!(dual.channel_a().try_encrypt(&mut data), Err(BlockCipherError::CounterExhausted)));
+        assert_eq!([0u8; 2 * 16], data);
+
+        dual.channel_b().try_encrypt(&mut data).unwrap();
+        assert_eq!(keystream(&key, &["F0F1F2F3 F4F5F6F7 F8F9FAFB FFFFFFFE", "F0F1F2F3 F4F5F6F7 F8F9FAFB FFFFFFFF"]), data);
+        assert!(dual.channel_b().try_decrypt(&mut [0u8; 1]).is_err());
+    }
+
+    #[test]
+    fn test_ctr_set_counter_after_exhaustion() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00000000 00000000 00000000 FFFFFFFF");
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ctr(iv).with_counter_width(CounterWidth::Bits32);
+        cipher.try_encrypt(&mut [0u8; 16]).unwrap();
+        assert!(cipher.try_encrypt(&mut [0u8; 1]).is_err());
+
+        // Resetting the stream makes it usable again, with the same counter width
+        cipher.reset_iv(&decode("00000000 00000000 00000000 FFFFFFFF")).unwrap();
+        cipher.try_encrypt(&mut [0u8; 16]).unwrap();
+        assert!(cipher.try_encrypt(&mut [0u8; 1]).is_err());
+
+        cipher.set_counter(7).unwrap();
+        assert!(cipher.try_encrypt(&mut [0u8; 16]).is_ok());
+    }
+
+    // NIST SP 800-38A F.5.1, truncated to end inside the third block
+    #[test]
+    fn test_ctr_buffered_finalize_stream() {
+        use himitsu::cipher::block::buffered::{BufferedCipherDecryption, BufferedCipherEncryption};
+        use himitsu::cipher::mode::StreamBlocks;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5");
+        let expected = decode("874D6191 B620E326 1BEF6864 990DB6CE 9806F66B 7970FDFF 8617187B B9FFFDFF 5AE4DF3E DBD5D35E 5B");
+
+        let ctr = aes::Aes::new(&key).unwrap().with_ctr(iv);
+        let mut cipher: BufferedCipherEncryption<{ aes::AES_BLOCKSIZE }, _> = BufferedCipherEncryption::new(StreamBlocks::new(ctr));
+        for chunk in plaintext.chunks(3) {
+            cipher.write_bytes(chunk).unwrap();
+        }
+        let ciphertext: Vec<u8> = cipher.finalize_stream();
+        assert_eq!(expected, ciphertext);
+
+        let ctr = aes::Aes::new(&key).unwrap().with_ctr(iv);
+        let mut cipher: BufferedCipherDecryption<{ aes::AES_BLOCKSIZE }, _> = BufferedCipherDecryption::new(StreamBlocks::new(ctr));
+        cipher.write_bytes(&ciphertext).unwrap();
+        assert_eq!(plaintext, cipher.finalize_stream::<Vec<u8>>());
+    }
+}
@@ -0,0 +1,145 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::{
+        cipher::block::{
+            buffered::{BufferedCipherDecryptionProvider, BufferedCipherEncryptionProvider},
+            cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+            primitive::des::*,
+        },
+        errors::blockcipher::BlockCipherError,
+        traits::cipher::primitive::{BlockCipherPrimitiveDecryption, BlockCipherPrimitiveEncryption},
+    };
+    use himitsu::traits::cipher::sink::ByteSink;
+
+    const WEAK_KEYS: [&str; 4] = ["01010101 01010101", "FEFEFEFE FEFEFEFE", "E0E0E0E0 F1F1F1F1", "1F1F1F1F 0E0E0E0E"];
+
+    // Pairs of keys that decrypt what the other one encrypted
+    const SEMI_WEAK_KEYS: [(&str, &str); 6] = [
+        ("01FE01FE 01FE01FE", "FE01FE01 FE01FE01"),
+        ("1FE01FE0 0EF10EF1", "E01FE01F F10EF10E"),
+        ("01E001E0 01F101F1", "E001E001 F101F101"),
+        ("1FFE1FFE 0EFE0EFE", "FE1FFE1F FE0EFE0E"),
+        ("011F011F 010E010E", "1F011F01 0E010E01"),
+        ("E0FEE0FE F1FEF1FE", "FEE0FEE0 FEF1FEF1"),
+    ];
+
+    macro_rules! des_test {
+        (
+            $fn_name: ident,
+            $key: literal,
+            $plaintext: literal,
+            $ciphertext: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let key = decode($key);
+                let plaintext: DesBlock = decode_into_array($plaintext);
+                let ciphertext: DesBlock = decode_into_array($ciphertext);
+
+                let des = Des::new(&key).unwrap();
+
+                let mut buf = plaintext;
+                des.encrypt(&mut buf);
+                assert_eq!(ciphertext, buf);
+
+                des.decrypt(&mut buf);
+                assert_eq!(plaintext, buf);
+            }
+        };
+    }
+
+    // Ciphertexts verified with openssl enc -des-ecb
+    des_test!(des_1, "13345779 9BBCDFF1", "01234567 89ABCDEF", "85E81354 0F0AB405");
+    des_test!(des_2, "01234567 89ABCDEF", "4E6F7720 69732074", "3FA40E8A 984D4815");
+    des_test!(des_3, "0E329232 EA6D0D73", "87878787 87878787", "00000000 00000000");
+    des_test!(des_4, "7CA11045 4A1A6E57", "01A1D6D0 39776742", "690F5B0D 9A26939B");
+    des_test!(des_5, "0131D961 9DC1376E", "5CD54CA8 3DEF57DA", "7A389D10 354BD271");
+
+    #[test]
+    fn des_invalid_key_length() {
+        for len in [0, 7, 9, 16, 24] {
+            match Des::new(&vec![0x55; len]) {
+                Err(BlockCipherError::InvalidKeyLength { got, min, max }) => {
+                    assert_eq!(len, got);
+                    assert_eq!(DES_KEYLEN, min);
+                    assert_eq!(DES_KEYLEN, max);
+                }
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("accepted a {} byte key", len),
+            }
+        }
+    }
+
+    #[test]
+    fn des_weak_keys_rejected() {
+        let plaintext: DesBlock = decode_into_array("01234567 89ABCDEF");
+
+        for key in WEAK_KEYS.iter().map(|k| decode(k)) {
+            match Des::new_checked(&key) {
+                Err(BlockCipherError::WeakKey) => {}
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("accepted weak key {:02X?}", key),
+            }
+
+            // Encrypting twice with a weak key gives back the plaintext
+            let des = Des::new(&key).unwrap();
+            let mut buf = plaintext;
+            des.encrypt(&mut buf);
+            des.encrypt(&mut buf);
+            assert_eq!(plaintext, buf);
+        }
+    }
+
+    #[test]
+    fn des_semi_weak_keys_rejected() {
+        let plaintext: DesBlock = decode_into_array("01234567 89ABCDEF");
+
+        for (first, second) in SEMI_WEAK_KEYS.iter().map(|(a, b)| (decode(a), decode(b))) {
+            for key in [&first, &second] {
+                match Des::new_checked(key) {
+                    Err(BlockCipherError::WeakKey) => {}
+                    Err(e) => panic!("unexpected error: {}", e),
+                    Ok(_) => panic!("accepted semi-weak key {:02X?}", key),
+                }
+            }
+
+            let mut buf = plaintext;
+            Des::new(&first).unwrap().encrypt(&mut buf);
+            Des::new(&second).unwrap().encrypt(&mut buf);
+            assert_eq!(plaintext, buf);
+        }
+    }
+
+    // Weak keys are detected regardless of the parity bits
+    #[test]
+    fn des_weak_key_parity_ignored() {
+        assert!(matches!(Des::new_checked(&[0; DES_KEYLEN]), Err(BlockCipherError::WeakKey)));
+        assert!(matches!(Des::new_checked(&[0xFF; DES_KEYLEN]), Err(BlockCipherError::WeakKey)));
+        assert!(Des::new_checked(&decode("13345779 9BBCDFF1")).is_ok());
+    }
+
+    // Example from FIPS 81, verified with openssl enc -des-cbc
+    #[test]
+    fn des_cbc() {
+        let key = decode("01234567 89ABCDEF");
+        let iv: DesBlock = decode_into_array("12345678 90ABCDEF");
+        let plaintext = decode("4E6F7720 69732074 68652074 696D6520 666F7220 616C6C20");
+        let expected = decode("E5C7CDDE 872BF27C 43E93400 8C389C0F 68378849 9A7C05F6");
+
+        let mut cipher = Des::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_bytes(&plaintext).unwrap();
+        let ciphertext: Vec<u8> = cipher.finalize();
+        assert_eq!(expected, ciphertext);
+
+        let mut cipher = Des::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_bytes(&ciphertext).unwrap();
+        let output: Vec<u8> = cipher.finalize().unwrap();
+        assert_eq!(plaintext, output);
+    }
+}
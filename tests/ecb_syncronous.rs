@@ -1,3 +1,6 @@
+// Exercises the std::io integration
+#![cfg(feature = "std")]
+
 mod common;
 
 #[cfg(test)]
@@ -16,6 +19,7 @@ mod tests {
         }
     };
     use std::io::Write;
+    use std::sync::{Arc, Mutex};
 
     macro_rules! ecb_test_enc {
         (
@@ -31,7 +35,7 @@ mod tests {
                 let key = decode($key);
                 let expected = decode($expected);
 
-                let mut cipher = <$primitive>::new(&key).with_ecb_encryption().buffered();
+                let mut cipher = <$primitive>::new(&key).unwrap().with_ecb_encryption().buffered();
                 cipher.write_all(&input).unwrap();
                 let output: Vec<u8> = cipher.finalize();
 
@@ -54,9 +58,9 @@ mod tests {
                 let key = decode($key);
                 let expected = decode($expected);
 
-                let mut cipher = <$primitive>::new(&key).with_ecb_decryption().buffered();
+                let mut cipher = <$primitive>::new(&key).unwrap().with_ecb_decryption().buffered();
                 cipher.write_all(&input).unwrap();
-                let output: Vec<u8> = cipher.finalize();
+                let output: Vec<u8> = cipher.finalize().unwrap();
 
                 assert_eq!(expected, output);
             }
@@ -112,4 +116,57 @@ mod tests {
         "F3EED1BD B5D2A03C 064B5A7E 3DB181F8 591CCB10 D410ED26 DC5BA74A 31362870 B6ED21B9 9CA6F4F9 F153E7B1 BEAFED1D 23304B7A 39F9F3FF 067D8D8F 9E24ECC7",
         "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710"
     );
+
+    #[test]
+    fn test_buffered_block_count() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ecb_encryption().buffered();
+
+        assert_eq!(0, cipher.blocks_processed());
+        assert_eq!(0, cipher.bytes_buffered());
+
+        cipher.write_all(&[0u8; 16 * 5 + 3]).unwrap();
+        assert_eq!(5, cipher.blocks_processed());
+        assert_eq!(3, cipher.bytes_buffered());
+
+        cipher.write_all(&[0u8; 13]).unwrap();
+        assert_eq!(6, cipher.blocks_processed());
+        assert_eq!(0, cipher.bytes_buffered());
+
+        let output: Vec<u8> = cipher.finalize_and_reset();
+        assert_eq!(6 * 16, output.len());
+        assert_eq!(0, cipher.blocks_processed());
+    }
+
+    #[test]
+    fn test_buffered_on_block() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ecb_encryption().buffered();
+
+        let counts = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&counts);
+        cipher.on_block(move |blocks| recorded.lock().unwrap().push(blocks));
+
+        // Registering a callback keeps the cipher shareable between threads
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        assert_send_sync(&cipher);
+
+        // Only full blocks invoke the callback, in uneven pieces as well
+        cipher.write_all(&[0u8; 16 * 2 + 7]).unwrap();
+        cipher.write_all(&[0u8; 9]).unwrap();
+        cipher.write_all(&[0u8; 5]).unwrap();
+        assert_eq!(vec![1, 2, 3], *counts.lock().unwrap());
+
+        // Padding processes one more block
+        let _: Vec<u8> = cipher.finalize_padded().unwrap();
+        assert_eq!(vec![1, 2, 3, 4], *counts.lock().unwrap());
+
+        // Finalizing without a new block does not invoke it
+        let mut cipher = aes::Aes::new(&key).unwrap().with_ecb_encryption().buffered();
+        let recorded = Arc::clone(&counts);
+        cipher.on_block(move |blocks| recorded.lock().unwrap().push(blocks));
+        cipher.write_all(&[0u8; 16]).unwrap();
+        let _: Vec<u8> = cipher.finalize();
+        assert_eq!(vec![1, 2, 3, 4, 1], *counts.lock().unwrap());
+    }
 }
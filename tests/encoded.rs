@@ -0,0 +1,26 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+
+    use himitsu::encode::Encoded;
+
+    #[test]
+    fn serializes_as_base64_string() {
+        let encoded = Encoded(b"aaaa".to_vec());
+        assert_eq!("\"YWFhYQ==\"", serde_json::to_string(&encoded).unwrap());
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let json = serde_json::to_string(&Encoded::from(bytes.clone())).unwrap();
+
+        let decoded: Encoded = serde_json::from_str(&json).unwrap();
+        assert_eq!(bytes, decoded.into_inner());
+    }
+
+    #[test]
+    fn rejects_invalid_base64() {
+        assert!(serde_json::from_str::<Encoded>("\"YWFhYQ=\"").is_err());
+        assert!(serde_json::from_str::<Encoded>("[1, 2, 3]").is_err());
+    }
+}
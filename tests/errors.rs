@@ -0,0 +1,112 @@
+// Exercises the std::io integration
+#![cfg(feature = "std")]
+
+#[cfg(test)]
+mod tests {
+
+    use himitsu::errors::blockcipher::BlockCipherError;
+    use std::io;
+
+    fn kind_of(err: BlockCipherError) -> io::ErrorKind {
+        io::Error::from(err).kind()
+    }
+
+    #[test]
+    fn incomplete_block_maps_to_unexpected_eof() {
+        let kind = kind_of(BlockCipherError::IncompleteBlock(3));
+        assert_eq!(io::ErrorKind::UnexpectedEof, kind);
+    }
+
+    #[test]
+    fn invalid_key_length_maps_to_invalid_input() {
+        let kind = kind_of(BlockCipherError::InvalidKeyLength { got: 20, min: 16, max: 32 });
+        assert_eq!(io::ErrorKind::InvalidInput, kind);
+    }
+
+    #[test]
+    fn weak_key_maps_to_invalid_input() {
+        let kind = kind_of(BlockCipherError::WeakKey);
+        assert_eq!(io::ErrorKind::InvalidInput, kind);
+    }
+
+    #[test]
+    fn invalid_iv_length_maps_to_invalid_input() {
+        let kind = kind_of(BlockCipherError::InvalidIvLength { got: 8, expected: 16 });
+        assert_eq!(io::ErrorKind::InvalidInput, kind);
+    }
+
+    #[test]
+    fn counter_exhausted_maps_to_invalid_input() {
+        let kind = kind_of(BlockCipherError::CounterExhausted);
+        assert_eq!(io::ErrorKind::InvalidInput, kind);
+    }
+
+    #[test]
+    fn output_too_small_maps_to_invalid_input() {
+        let kind = kind_of(BlockCipherError::OutputTooSmall { got: 8, expected: 16 });
+        assert_eq!(io::ErrorKind::InvalidInput, kind);
+    }
+
+    #[test]
+    fn authentication_failure_maps_to_invalid_data() {
+        let kind = kind_of(BlockCipherError::AuthenticationFailed);
+        assert_eq!(io::ErrorKind::InvalidData, kind);
+    }
+
+    #[test]
+    fn invalid_parameter_maps_to_invalid_input() {
+        let kind = kind_of(BlockCipherError::InvalidParameter(String::from("tag length")));
+        assert_eq!(io::ErrorKind::InvalidInput, kind);
+    }
+
+    #[test]
+    fn bad_padding_maps_to_invalid_data() {
+        use himitsu::errors::blockcipher::PaddingErrorKind;
+
+        let kind = kind_of(BlockCipherError::BadPadding { kind: PaddingErrorKind::Content });
+        assert_eq!(io::ErrorKind::InvalidData, kind);
+    }
+
+    #[test]
+    fn display_includes_byte_counts() {
+        assert_eq!("incomplete block: 7 bytes missing", BlockCipherError::IncompleteBlock(7).to_string());
+        assert_eq!(
+            "invalid key length, expected between 16 and 32 bytes (found 20)",
+            BlockCipherError::InvalidKeyLength { got: 20, min: 16, max: 32 }.to_string()
+        );
+        assert_eq!(
+            "invalid iv length, expected 16 bytes (found 8)",
+            BlockCipherError::InvalidIvLength { got: 8, expected: 16 }.to_string()
+        );
+        assert_eq!(
+            "output buffer too small, expected at least 32 bytes (found 16)",
+            BlockCipherError::OutputTooSmall { got: 16, expected: 32 }.to_string()
+        );
+    }
+
+    // Modes can return block cipher errors from io functions with `?`
+    #[test]
+    fn question_mark_converts_in_io_context() {
+        fn write_block(data: &[u8]) -> io::Result<usize> {
+            if data.len() < 16 {
+                Err(BlockCipherError::IncompleteBlock(16 - data.len()))?;
+            }
+            Ok(data.len())
+        }
+
+        assert_eq!(16, write_block(&[0; 16]).unwrap());
+        assert_eq!(io::ErrorKind::UnexpectedEof, write_block(&[0; 9]).unwrap_err().kind());
+    }
+
+    // The original error must be recoverable from the io::Error
+    #[test]
+    fn io_error_keeps_source() {
+        let err = io::Error::from(BlockCipherError::IncompleteBlock(3));
+        let inner = err.into_inner().unwrap();
+
+        match inner.downcast_ref::<BlockCipherError>() {
+            Some(BlockCipherError::IncompleteBlock(3)) => {}
+            other => panic!("unexpected inner error: {:?}", other),
+        }
+    }
+}
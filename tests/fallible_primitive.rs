@@ -0,0 +1,221 @@
+// Exercises the std::io integration
+#![cfg(feature = "std")]
+
+#[cfg(test)]
+mod tests {
+
+    use himitsu::cipher::block::{
+        buffered::{BufferedCipherDecryptionProvider, BufferedCipherEncryption, BufferedCipherEncryptionProvider},
+        cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+        ecb::{EcbDecryptionProvider, EcbEncryptionProvider},
+    };
+    use himitsu::cipher::mode::StreamBlocks;
+    use himitsu::cipher::stream::{cfb::CfbEncryptionProvider, ctr::CtrCipherProvider, ofb::OfbCipherProvider};
+    use himitsu::errors::blockcipher::BlockCipherError;
+    use himitsu::prelude::*;
+    use std::cell::Cell;
+    use std::io::{self, Write};
+
+    // Mock of a hardware backend that has become unavailable
+    struct UnavailableBackend;
+
+    impl BlockCipherPrimitiveInfo for UnavailableBackend {
+        const BLOCKSIZE: usize = 16;
+        const KEYLEN_MIN: usize = 16;
+        const KEYLEN_MAX: usize = 16;
+    }
+
+    impl BlockCipherPrimitiveEncryption<16> for UnavailableBackend {
+        fn encrypt(&self, _block: &mut [u8; 16]) {
+            unreachable!("modes must use the fallible path")
+        }
+
+        fn try_encrypt_block(&self, _block: &mut [u8; 16]) -> Result<(), BlockCipherError> {
+            Err(BlockCipherError::BackendFailure(String::from("device unavailable")))
+        }
+    }
+
+    impl BlockCipherPrimitiveDecryption<16> for UnavailableBackend {
+        fn decrypt(&self, _block: &mut [u8; 16]) {
+            unreachable!("modes must use the fallible path")
+        }
+
+        fn try_decrypt_block(&self, _block: &mut [u8; 16]) -> Result<(), BlockCipherError> {
+            Err(BlockCipherError::BackendFailure(String::from("device unavailable")))
+        }
+    }
+
+    fn assert_backend_failure(err: io::Error) {
+        assert_eq!(io::ErrorKind::Other, err.kind());
+
+        match err.into_inner().unwrap().downcast_ref::<BlockCipherError>() {
+            Some(BlockCipherError::BackendFailure(_)) => {}
+            other => panic!("unexpected inner error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn ecb_propagates_backend_failure() {
+        let mut cipher = UnavailableBackend.with_ecb_encryption().buffered();
+        assert_backend_failure(cipher.write_all(&[0; 16]).unwrap_err());
+    }
+
+    #[test]
+    fn cbc_enc_propagates_backend_failure() {
+        let mut cipher = UnavailableBackend.with_cbc_encryption([0; 16]).buffered();
+        assert_backend_failure(cipher.write_all(&[0; 32]).unwrap_err());
+    }
+
+    #[test]
+    fn cbc_dec_propagates_backend_failure() {
+        let mut cipher = UnavailableBackend.with_cbc_decryption([0; 16]).buffered();
+        assert_backend_failure(cipher.write_all(&[0; 32]).unwrap_err());
+    }
+
+    // Nothing is processed before a block is full, so partial writes still succeed
+    #[test]
+    fn partial_block_does_not_touch_backend() {
+        let mut cipher = UnavailableBackend.with_ecb_encryption().buffered();
+        assert!(cipher.write_all(&[0; 15]).is_ok());
+    }
+
+    // Mock of a hardware backend that fails once its budget of blocks is used up, the cipher just inverts the bits
+    struct FlakyBackend {
+        budget: Cell<usize>,
+    }
+
+    impl FlakyBackend {
+        fn new(budget: usize) -> Self {
+            Self { budget: Cell::new(budget) }
+        }
+    }
+
+    impl BlockCipherPrimitiveInfo for FlakyBackend {
+        const BLOCKSIZE: usize = 16;
+        const KEYLEN_MIN: usize = 16;
+        const KEYLEN_MAX: usize = 16;
+    }
+
+    impl BlockCipherPrimitiveEncryption<16> for &FlakyBackend {
+        fn encrypt(&self, _block: &mut [u8; 16]) {
+            unreachable!("modes must use the fallible path")
+        }
+
+        fn try_encrypt_block(&self, block: &mut [u8; 16]) -> Result<(), BlockCipherError> {
+            match self.budget.get() {
+                0 => Err(BlockCipherError::BackendFailure(String::from("device busy"))),
+                budget => {
+                    self.budget.set(budget - 1);
+                    block.iter_mut().for_each(|b| *b = !*b);
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    impl BlockCipherPrimitiveDecryption<16> for &FlakyBackend {
+        fn decrypt(&self, _block: &mut [u8; 16]) {
+            unreachable!("modes must use the fallible path")
+        }
+
+        fn try_decrypt_block(&self, block: &mut [u8; 16]) -> Result<(), BlockCipherError> {
+            self.try_encrypt_block(block)
+        }
+    }
+
+    impl BlockCipherPrimitiveInfo for &FlakyBackend {
+        const BLOCKSIZE: usize = 16;
+        const KEYLEN_MIN: usize = 16;
+        const KEYLEN_MAX: usize = 16;
+    }
+
+    #[test]
+    fn stream_modes_propagate_backend_failure() {
+        let mut data = [0; 20];
+
+        assert!(matches!(
+            UnavailableBackend.with_ctr([0; 16]).try_encrypt(&mut data),
+            Err(BlockCipherError::BackendFailure(_))
+        ));
+        assert!(matches!(
+            UnavailableBackend.with_cfb_encryption([0; 16]).try_encrypt(&mut data),
+            Err(BlockCipherError::BackendFailure(_))
+        ));
+        assert!(matches!(
+            UnavailableBackend.with_ofb([0; 16]).try_decrypt(&mut data),
+            Err(BlockCipherError::BackendFailure(_))
+        ));
+
+        let ctr = StreamBlocks::new(UnavailableBackend.with_ctr([0; 16]));
+        let mut cipher: BufferedCipherEncryption<16, _> = BufferedCipherEncryption::new(ctr);
+        assert_backend_failure(cipher.write_all(&[0; 16]).unwrap_err());
+
+        let cfb = StreamBlocks::new(UnavailableBackend.with_cfb_encryption([0; 16]));
+        let mut cipher: BufferedCipherEncryption<16, _> = BufferedCipherEncryption::new(cfb);
+        cipher.write_all(&[0; 5]).unwrap();
+        assert!(matches!(cipher.finalize_partial::<Vec<u8>>(), Err(BlockCipherError::BackendFailure(_))));
+    }
+
+    // A failed block is not consumed, so the caller can write it again without losing or duplicating data
+    #[test]
+    fn failed_block_is_not_consumed() {
+        let backend = FlakyBackend::new(1);
+        let mut cipher = (&backend).with_ecb_encryption().buffered();
+
+        cipher.write_all(&[1; 10]).unwrap();
+
+        // The first block succeeds, the second fails and only the bytes up to it are reported as written
+        let data: Vec<u8> = (0..38).collect();
+        assert_eq!(6, cipher.write(&data).unwrap());
+        assert_eq!((1, 0), (cipher.blocks_processed(), cipher.bytes_buffered()));
+
+        // Nothing can be consumed while the backend fails
+        assert_backend_failure(cipher.write(&data[6..]).unwrap_err());
+        assert_eq!((1, 0), (cipher.blocks_processed(), cipher.bytes_buffered()));
+
+        backend.budget.set(usize::MAX);
+        cipher.write_all(&data[6..]).unwrap();
+
+        let mut expected = [1; 10].to_vec();
+        expected.extend(&data);
+        let expected: Vec<u8> = expected.iter().map(|b| !b).collect();
+        assert_eq!(expected, cipher.finalize::<Vec<u8>>());
+    }
+
+    #[test]
+    fn failed_block_keeps_previous_bytes_buffered() {
+        let backend = FlakyBackend::new(0);
+        let mut cipher = (&backend).with_ecb_encryption().buffered();
+
+        cipher.write_all(&[7; 10]).unwrap();
+        assert_backend_failure(cipher.write(&[8; 10]).unwrap_err());
+        assert_eq!(10, cipher.bytes_buffered());
+
+        backend.budget.set(1);
+        assert_eq!(6, cipher.write(&[8; 6]).unwrap());
+
+        let mut expected = vec![!7; 10];
+        expected.extend([!8; 6]);
+        assert_eq!(expected, cipher.finalize::<Vec<u8>>());
+    }
+
+    // In place decryption stops in front of the failed block, leaving its ciphertext and the tail as they were
+    #[test]
+    fn decrypt_in_place_reports_decrypted_length() {
+        let backend = FlakyBackend::new(2);
+        let mut cipher = (&backend).with_ecb_decryption().buffered();
+
+        let mut data = [5; 60];
+        assert_eq!(32, cipher.decrypt_in_place(&mut data).unwrap());
+        assert_eq!([!5; 32], data[..32]);
+        assert_eq!([5; 28], data[32..]);
+        assert_eq!((2, 0), (cipher.blocks_processed(), cipher.bytes_buffered()));
+
+        assert!(matches!(cipher.decrypt_in_place(&mut data[32..]), Err(BlockCipherError::BackendFailure(_))));
+        assert_eq!([5; 28], data[32..]);
+
+        backend.budget.set(1);
+        assert_eq!(16, cipher.decrypt_in_place(&mut data[32..]).unwrap());
+        assert_eq!(12, cipher.bytes_buffered());
+    }
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+
+    use himitsu::cipher::block::primitive::feistel::feistel_rounds;
+
+    const ROUNDS: usize = 8;
+    const KEYS: [u32; ROUNDS] = [
+        0x0123_4567, 0x89AB_CDEF, 0xDEAD_BEEF, 0x0BAD_F00D, 0xCAFE_BABE, 0x1234_5678, 0x9ABC_DEF0, 0x0F1E_2D3C,
+    ];
+
+    // Not invertible on its own, which a Feistel network does not need
+    fn toy_round(round: usize, x: u32) -> u32 {
+        (x ^ KEYS[round]).wrapping_mul(0x9E37_79B9).rotate_left(7) & 0xFFFF_00FF
+    }
+
+    fn encrypt(l: u32, r: u32) -> (u32, u32) {
+        feistel_rounds(l, r, ROUNDS, toy_round)
+    }
+
+    fn decrypt(l: u32, r: u32) -> (u32, u32) {
+        feistel_rounds(l, r, ROUNDS, |i, x| toy_round(ROUNDS - 1 - i, x))
+    }
+
+    #[test]
+    fn test_feistel_inverse() {
+        for seed in 0..1000u32 {
+            let (l, r) = (seed.wrapping_mul(0x0101_0101), !seed.rotate_left(13));
+            let encrypted = encrypt(l, r);
+
+            assert_ne!((l, r), encrypted);
+            assert_eq!((l, r), decrypt(encrypted.0, encrypted.1));
+            assert_eq!((l, r), {
+                let decrypted = decrypt(l, r);
+                encrypt(decrypted.0, decrypted.1)
+            });
+        }
+    }
+
+    // A single round leaves the right half in place and mixes it into the left half, the swap is undone
+    #[test]
+    fn test_feistel_single_round() {
+        let (l, r) = feistel_rounds(0xAAAA_AAAAu32, 0x5555_5555, 1, |_, x| x ^ 0xFFFF_0000);
+        assert_eq!((0xAAAA_AAAA ^ 0xAAAA_5555, 0x5555_5555), (l, r));
+
+        // The round index counts from 0
+        let mut seen = Vec::new();
+        feistel_rounds(0u64, 0, ROUNDS, |i, x| {
+            seen.push(i);
+            x
+        });
+        assert_eq!((0..ROUNDS).collect::<Vec<_>>(), seen);
+    }
+}
@@ -0,0 +1,54 @@
+// Exercises the std::io integration
+#![cfg(feature = "std")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::cipher::block::{
+        buffered::BufferedCipherEncryptionProvider,
+        ecb::{EcbEncryptionProvider, ThreadedEcbEncryptionProvider},
+        primitive::aes,
+    };
+    use himitsu::errors::blockcipher::BlockCipherError;
+    use himitsu::prelude::*;
+    use std::io::{Read, Write};
+
+    // Generic over any provider that can be written to and finalized
+    fn run<F: Finalizable + Write>(mut provider: F, input: &[u8]) -> Result<Vec<u8>, BlockCipherError> {
+        provider.write_all(input).unwrap();
+
+        let mut output = Vec::new();
+        provider.finalize_readable()?.read_to_end(&mut output).unwrap();
+
+        Ok(output)
+    }
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+    #[test]
+    fn test_finalize_readable_over_providers() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let input = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+        let expected = decode("3AD77BB4 0D7A3660 A89ECAF3 2466EF97 F5D3D585 03B9699D E785895A 96FDBAAF");
+
+        let buffered = aes::Aes::new(&key).unwrap().with_ecb_encryption().buffered();
+        let threaded = aes::Aes::new(&key).unwrap().with_threaded_ecb_encryption(4);
+
+        assert_eq!(expected, run(buffered, &input).unwrap());
+        assert_eq!(expected, run(threaded, &input).unwrap());
+    }
+
+    #[test]
+    fn test_finalize_readable_incomplete_block() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let input = [0u8; 20];
+
+        let buffered = aes::Aes::new(&key).unwrap().with_ecb_encryption().buffered();
+        let threaded = aes::Aes::new(&key).unwrap().with_threaded_ecb_encryption(4);
+
+        assert!(matches!(run(buffered, &input), Err(BlockCipherError::IncompleteBlock(12))));
+        assert!(matches!(run(threaded, &input), Err(BlockCipherError::IncompleteBlock(12))));
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+
+    use himitsu::errors::frame::FrameError;
+    use himitsu::util::frame::Frame;
+
+    #[test]
+    fn test_frame_round_trip() {
+        let (iv, ct, tag) = ([1u8; 16], [2u8; 37], [3u8; 12]);
+
+        let packed = Frame::pack(&[&iv, &ct, &tag]).unwrap();
+        assert_eq!(3 * 4 + 16 + 37 + 12, packed.len());
+        assert_eq!([0, 0, 0, 16], packed[..4]);
+
+        let segments = Frame::unpack(&packed).unwrap();
+        assert_eq!(vec![&iv[..], &ct[..], &tag[..]], segments);
+    }
+
+    #[test]
+    fn test_frame_push() {
+        let mut frame = Frame::new();
+        frame.push(b"iv").unwrap().push(b"").unwrap().push(b"tag").unwrap();
+
+        let packed = frame.into_inner();
+        assert_eq!(vec![&b"iv"[..], &b""[..], &b"tag"[..]], Frame::unpack(&packed).unwrap());
+    }
+
+    #[test]
+    fn test_frame_empty() {
+        assert!(Frame::pack(&[]).unwrap().is_empty());
+        assert!(Frame::unpack(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_frame_truncated_segment() {
+        let packed = Frame::pack(&[&[1; 5], &[2; 9]]).unwrap();
+        let truncated = &packed[..packed.len() - 2];
+
+        assert_eq!(
+            Err(FrameError::Truncated { offset: 13, needed: 9, available: 7 }),
+            Frame::unpack(truncated)
+        );
+    }
+
+    #[test]
+    fn test_frame_truncated_prefix() {
+        let packed = Frame::pack(&[&[1; 5]]).unwrap();
+        let mut truncated = packed.clone();
+        truncated.extend_from_slice(&[0, 0]);
+
+        assert_eq!(
+            Err(FrameError::Truncated { offset: 9, needed: 4, available: 2 }),
+            Frame::unpack(&truncated)
+        );
+    }
+
+    #[test]
+    fn test_frame_segments_stop_after_error() {
+        let mut segments = Frame::segments(&[0, 0, 0, 2, 7, 7, 0, 0, 0, 3, 1]);
+
+        assert_eq!(Some(Ok(&[7, 7][..])), segments.next());
+        assert!(matches!(segments.next(), Some(Err(FrameError::Truncated { .. }))));
+        assert_eq!(None, segments.next());
+    }
+}
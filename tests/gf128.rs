@@ -0,0 +1,88 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode_into_array;
+    use himitsu::util::gf128::*;
+
+    const A: &str = "66E94BD4 EF8A2C3B 884CFA59 CA342B2E";
+    const B: &str = "0388DACE 60B6A392 F328C2B9 71B2FE78";
+
+    #[test]
+    fn test_mul_by_one() {
+        let a = decode_into_array(A);
+        let one = decode_into_array("00000000 00000000 00000000 00000001");
+        let one_reflected = decode_into_array("80000000 00000000 00000000 00000000");
+
+        assert_eq!(a, gf128_mul(&a, &one));
+        assert_eq!(a, gf128_mul(&one, &a));
+        assert_eq!(a, gf128_mul_reflected(&a, &one_reflected));
+        assert_eq!(a, gf128_mul_reflected(&one_reflected, &a));
+    }
+
+    #[test]
+    fn test_mul_commutes() {
+        let (a, b) = (decode_into_array(A), decode_into_array(B));
+
+        assert_eq!(gf128_mul(&a, &b), gf128_mul(&b, &a));
+        assert_eq!(gf128_mul_reflected(&a, &b), gf128_mul_reflected(&b, &a));
+    }
+
+    // x^127 * x = x^128 = x^7 + x^2 + x + 1
+    #[test]
+    fn test_mul_reduces() {
+        let x127 = decode_into_array("80000000 00000000 00000000 00000000");
+        let x = decode_into_array("00000000 00000000 00000000 00000002");
+        let expected = decode_into_array("00000000 00000000 00000000 00000087");
+
+        assert_eq!(expected, gf128_mul(&x127, &x));
+    }
+
+    #[test]
+    fn test_double_matches_mul_by_x() {
+        let a = decode_into_array(A);
+        let x = decode_into_array("00000000 00000000 00000000 00000002");
+        let x_reflected = decode_into_array("40000000 00000000 00000000 00000000");
+
+        let mut doubled = a;
+        gf128_double(&mut doubled);
+        assert_eq!(gf128_mul(&a, &x), doubled);
+
+        let mut doubled = a;
+        gf128_double_reflected(&mut doubled);
+        assert_eq!(gf128_mul_reflected(&a, &x_reflected), doubled);
+    }
+
+    #[test]
+    fn test_double_carry() {
+        let mut a = decode_into_array("80000000 00000000 00000000 00000000");
+        gf128_double(&mut a);
+        assert_eq!(decode_into_array::<16>("00000000 00000000 00000000 00000087"), a);
+
+        let mut a = decode_into_array("00000000 00000000 00000000 00000080");
+        gf128_double_le(&mut a);
+        assert_eq!(decode_into_array::<16>("87000000 00000000 00000000 00000000"), a);
+
+        let mut a = decode_into_array("00000000 00000000 00000000 00000001");
+        gf128_double_reflected(&mut a);
+        assert_eq!(decode_into_array::<16>("E1000000 00000000 00000000 00000000"), a);
+    }
+
+    // GHASH of a single ciphertext block from the NIST GCM test case 2: (C * H ^ len) * H
+    #[test]
+    fn test_mul_reflected_ghash() {
+        let h = decode_into_array(A);
+        let mut x = gf128_mul_reflected(&decode_into_array(B), &h);
+
+        let len: [u8; 16] = decode_into_array("00000000 00000000 00000000 00000080");
+        for (x, l) in x.iter_mut().zip(len) {
+            *x ^= l;
+        }
+
+        let expected = decode_into_array::<16>("F38CBB1A D69223DC C3457AE5 B6B0F885");
+        assert_eq!(expected, gf128_mul_reflected(&x, &h));
+    }
+}
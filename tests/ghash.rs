@@ -0,0 +1,83 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::mac::ghash::Ghash;
+
+    macro_rules! ghash_test {
+        (
+            $fn_name: ident,
+            $h: literal,
+            $aad: literal,
+            $data: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let (aad, data) = (decode($aad), decode($data));
+                let expected = decode($expected);
+
+                let mut ghash = Ghash::new(&decode_into_array($h));
+                ghash.update_aad(&aad);
+                ghash.update(&data);
+                assert_eq!(expected, ghash.finalize());
+
+                // Feeding both sections in uneven pieces must not change the output
+                let mut ghash = Ghash::new(&decode_into_array($h));
+                for chunk in aad.chunks(7) {
+                    ghash.update_aad(chunk);
+                }
+                for chunk in data.chunks(5) {
+                    ghash.update(chunk);
+                }
+                assert_eq!(expected, ghash.finalize());
+            }
+        };
+    }
+
+    // Intermediate GHASH(H, A, C) values of the GCM specification test cases
+    ghash_test!(
+        test_ghash_case_2,
+        "66E94BD4 EF8A2C3B 884CFA59 CA342B2E",
+        "",
+        "0388DACE 60B6A392 F328C2B9 71B2FE78",
+        "F38CBB1A D69223DC C3457AE5 B6B0F885"
+    );
+
+    ghash_test!(
+        test_ghash_case_3,
+        "B83B5337 08BF535D 0AA6E529 80D53B78",
+        "",
+        "42831EC2 21777424 4B7221B7 84D0D49C E3AA212F 2C02A4E0 35C17E23 29ACA12E
+         21D514B2 5466931C 7D8F6A5A AC84AA05 1BA30B39 6A0AAC97 3D58E091 473F5985",
+        "7F1B32B8 1B820D02 614F8895 AC1D4EAC"
+    );
+
+    ghash_test!(
+        test_ghash_case_4,
+        "B83B5337 08BF535D 0AA6E529 80D53B78",
+        "FEEDFACE DEADBEEF FEEDFACE DEADBEEF ABADDAD2",
+        "42831EC2 21777424 4B7221B7 84D0D49C E3AA212F 2C02A4E0 35C17E23 29ACA12E
+         21D514B2 5466931C 7D8F6A5A AC84AA05 1BA30B39 6A0AAC97 3D58E091",
+        "698E57F7 0E6ECC7F D9463B72 60A9AE5F"
+    );
+
+    // Without any input only the zero length block is hashed
+    #[test]
+    fn test_ghash_empty() {
+        let ghash = Ghash::new(&decode_into_array("66E94BD4 EF8A2C3B 884CFA59 CA342B2E"));
+        assert_eq!([0; 16], ghash.finalize());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ghash_aad_after_data() {
+        let mut ghash = Ghash::new(&[1; 16]);
+        ghash.update(&[0; 3]);
+        ghash.update_aad(&[0; 3]);
+    }
+}
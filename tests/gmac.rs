@@ -0,0 +1,80 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::cipher::block::primitive::aes::Aes;
+    use himitsu::errors::blockcipher::BlockCipherError;
+    use himitsu::mac::gmac::Gmac;
+
+    macro_rules! gmac_test {
+        (
+            $fn_name: ident,
+            $key: literal,
+            $nonce: literal,
+            $aad: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let (key, nonce, aad) = (decode($key), decode($nonce), decode($aad));
+                let expected = decode($expected);
+
+                let mut mac = Gmac::new(Aes::new(&key).unwrap(), &nonce).unwrap();
+                for chunk in aad.chunks(7) {
+                    mac.update(chunk);
+                }
+
+                assert_eq!(expected, mac.finalize());
+                assert_eq!(expected, Gmac::mac(Aes::new(&key).unwrap(), &nonce, &aad).unwrap());
+            }
+        };
+    }
+
+    // NIST gcmEncryptExtIV128.rsp, PTlen = 0, AADlen = 128, Count = 0
+    gmac_test!(
+        test_gmac_nist_128,
+        "77BE6370 8971C4E2 40D1CB79 E8D77FEB",
+        "E0E00F19 FED7BA01 36A797F3",
+        "7A43EC1D 9C0A5A78 A0B16533 A6213CAB",
+        "209FCC8D 3675ED93 8E9C7166 709DD946"
+    );
+
+    // GCM specification test case 1, neither associated data nor plaintext
+    gmac_test!(
+        test_gmac_empty,
+        "00000000 00000000 00000000 00000000",
+        "00000000 00000000 00000000",
+        "",
+        "58E2FCCE FA7E3061 367F1D57 A4E7455A"
+    );
+
+    // The following tags were verified with the AESGCM implementation of the python cryptography package
+    gmac_test!(
+        test_gmac_256,
+        "FEFFE992 8665731C 6D6A8F94 67308308 FEFFE992 8665731C 6D6A8F94 67308308",
+        "CAFEBABE FACEDBAD DECAF888",
+        "FEEDFACE DEADBEEF FEEDFACE DEADBEEF ABADDAD2",
+        "9F6BE076 03C0B0BD 12728540 63E9C9BA"
+    );
+
+    gmac_test!(
+        test_gmac_short_nonce,
+        "FEFFE992 8665731C 6D6A8F94 67308308",
+        "CAFEBABE FACEDBAD",
+        "FEEDFACE DEADBEEF FEEDFACE DEADBEEF ABADDAD2",
+        "EF6995E5 31E81A01 F5B2F776 2CC60BD2"
+    );
+
+    #[test]
+    fn test_gmac_empty_nonce() {
+        match Gmac::new(Aes::new(&[0; 16]).unwrap(), &[]) {
+            Err(BlockCipherError::InvalidIvLength { got: 0, .. }) => {}
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("accepted an empty nonce"),
+        }
+    }
+}
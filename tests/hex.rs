@@ -1,7 +1,10 @@
+#![cfg(feature = "alloc")]
+
 #[cfg(test)]
 mod tests {
 
     use himitsu::encode::HexEncoder;
+    use himitsu::errors::hex::HexError;
 
     // Decode a hex string
     #[test]
@@ -51,4 +54,52 @@ mod tests {
         let exp = "01020304";
         assert_eq!(encoded, exp)
     }
+
+    // Encode in the style of the NIST vectors and decode it back
+    #[test]
+    fn encode_grouping_roundtrip() {
+        let v: Vec<u8> = (1..=10).collect();
+        let encoded = HexEncoder::builder().with_grouping(4, " ").encode(&v);
+        let exp = "01020304 05060708 090A";
+        assert_eq!(encoded, exp);
+
+        assert_eq!(HexEncoder::default().decode(&encoded), v)
+    }
+
+    // Without a groupsize no seperator is inserted
+    #[test]
+    fn encode_groupsize_0() {
+        let v = vec![1u8, 2, 3, 4];
+        let encoded = HexEncoder::builder().with_seperator(" ").encode(&v);
+        let exp = "01020304";
+        assert_eq!(encoded, exp)
+    }
+
+    // Strict mode decodes plain Base16 in either case
+    #[test]
+    fn try_decode_strict_valid() {
+        let decoded = HexEncoder::builder().set_strict(true).try_decode("0102ABcdEF");
+        assert_eq!(decoded, Ok(vec![0x01, 0x02, 0xAB, 0xCD, 0xEF]));
+
+        let encoded = HexEncoder::builder().set_strict(true).encode(&[0xAB, 0xCD]);
+        assert_eq!(encoded, "ABCD")
+    }
+
+    // Strict mode rejects whitespace with its position, the lenient default skips it
+    #[test]
+    fn try_decode_whitespace() {
+        let hx_s = "01020304 05060708";
+
+        let strict = HexEncoder::builder().set_strict(true).try_decode(hx_s);
+        assert_eq!(strict, Err(HexError::InvalidCharacter { index: 8, found: ' ' }));
+
+        let lenient = HexEncoder::builder().try_decode(hx_s);
+        assert_eq!(lenient, Ok(vec![1, 2, 3, 4, 5, 6, 7, 8]))
+    }
+
+    #[test]
+    fn try_decode_strict_odd_length() {
+        let decoded = HexEncoder::builder().set_strict(true).try_decode("01020");
+        assert_eq!(decoded, Err(HexError::InvalidInputLength(5)))
+    }
 }
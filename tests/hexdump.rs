@@ -0,0 +1,39 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+
+    use himitsu::encode::hexdump;
+
+    // Two full lines with printable and non-printable bytes
+    #[test]
+    fn hexdump_two_lines() {
+        let mut bytes = b"himitsu hexdump\n".to_vec();
+        bytes.extend(0x7A..0x8A);
+
+        let exp = concat!(
+            "00000000  68 69 6d 69 74 73 75 20  68 65 78 64 75 6d 70 0a  |himitsu hexdump.|\n",
+            "00000010  7a 7b 7c 7d 7e 7f 80 81  82 83 84 85 86 87 88 89  |z{|}~...........|\n",
+            "00000020\n"
+        );
+        assert_eq!(hexdump(&bytes), exp)
+    }
+
+    // The hex columns of a partial last line are padded to keep the gutter aligned
+    #[test]
+    fn hexdump_partial_line() {
+        let bytes = b"Hello, world!\x00\xff\x7f\x10abc";
+
+        let exp = concat!(
+            "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 00 ff 7f  |Hello, world!...|\n",
+            "00000010  10 61 62 63                                       |.abc|\n",
+            "00000014\n"
+        );
+        assert_eq!(hexdump(bytes), exp)
+    }
+
+    #[test]
+    fn hexdump_empty() {
+        assert_eq!(hexdump(&[]), "")
+    }
+}
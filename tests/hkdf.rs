@@ -0,0 +1,115 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::errors::kdf::KdfError;
+    use himitsu::hash::sha256::Sha256;
+    use himitsu::kdf::hkdf;
+
+    // Expand step of RFC 5869, test case 1
+    #[test]
+    fn test_hkdf_expand_sha256() {
+        let prk = decode("077709362C2E32DF0DDC3F0DC47BBA6390B6C73BB50F9C3122EC844AD7C2B3E5");
+        let info = decode("F0F1F2F3F4F5F6F7F8F9");
+        let expected = decode("3CB25F25FAACD57A90434F64D0362F2A2D2D0A90CF1A5A4C5DB02D56ECC4C5BF34007208D5B887185865");
+
+        let okm = hkdf::hkdf_expand::<Sha256>(&prk, &info, 42).unwrap();
+        assert_eq!(expected, okm);
+    }
+
+    macro_rules! hkdf_test {
+        (
+            $fn_name: ident,
+            $ikm: literal,
+            $salt: literal,
+            $info: literal,
+            $prk: literal,
+            $okm: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let ikm = decode($ikm);
+                let salt = decode($salt);
+                let info = decode($info);
+                let expected_prk = decode($prk);
+                let expected_okm = decode($okm);
+
+                let prk = hkdf::hkdf_extract::<Sha256>(&salt, &ikm);
+                assert_eq!(expected_prk, prk);
+
+                let okm = hkdf::hkdf_expand::<Sha256>(&prk, &info, expected_okm.len()).unwrap();
+                assert_eq!(expected_okm, okm);
+
+                let okm = hkdf::hkdf::<Sha256>(&salt, &ikm, &info, expected_okm.len()).unwrap();
+                assert_eq!(expected_okm, okm);
+            }
+        };
+    }
+
+    // Test cases 1 to 3 from RFC 5869
+
+    hkdf_test!(
+        test_hkdf_sha256_case_1,
+        "0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B",
+        "000102030405060708090A0B0C",
+        "F0F1F2F3F4F5F6F7F8F9",
+        "077709362C2E32DF0DDC3F0DC47BBA6390B6C73BB50F9C3122EC844AD7C2B3E5",
+        "3CB25F25FAACD57A90434F64D0362F2A2D2D0A90CF1A5A4C5DB02D56ECC4C5BF34007208D5B887185865"
+    );
+
+    hkdf_test!(
+        test_hkdf_sha256_case_2,
+        "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F202122232425262728292A2B2C2D2E2F303132333435363738393A3B3C3D3E3F404142434445464748494A4B4C4D4E4F",
+        "606162636465666768696A6B6C6D6E6F707172737475767778797A7B7C7D7E7F808182838485868788898A8B8C8D8E8F909192939495969798999A9B9C9D9E9FA0A1A2A3A4A5A6A7A8A9AAABACADAEAF",
+        "B0B1B2B3B4B5B6B7B8B9BABBBCBDBEBFC0C1C2C3C4C5C6C7C8C9CACBCCCDCECFD0D1D2D3D4D5D6D7D8D9DADBDCDDDEDFE0E1E2E3E4E5E6E7E8E9EAEBECEDEEEFF0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF",
+        "06A6B88C5853361A06104C9CEB35B45CEF760014904671014A193F40C15FC244",
+        "B11E398DC80327A1C8E7F78C596A49344F012EDA2D4EFAD8A050CC4C19AFA97C59045A99CAC7827271CB41C65E590E09DA3275600C2F09B8367793A9ACA3DB71CC30C58179EC3E87C14C01D5C1F3434F1D87"
+    );
+
+    // Zero-length salt and info
+    hkdf_test!(
+        test_hkdf_sha256_case_3,
+        "0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B0B",
+        "",
+        "",
+        "19EF24A32C717B167F33A91D6F648BDF96596776AFDB6377AC434C1C293CCB04",
+        "8DA4E775A563C18F715F802A063C5A31B8A11F5C5EE1879EC3454E5F3C738D2D9D201395FAA4B61A96C8"
+    );
+
+    #[test]
+    fn test_hkdf_expand_too_long() {
+        let prk = [0u8; 32];
+
+        match hkdf::hkdf_expand::<Sha256>(&prk, b"", 255 * 32 + 1) {
+            Err(KdfError::InvalidOutputLength { got, max }) => {
+                assert_eq!(255 * 32 + 1, got);
+                assert_eq!(255 * 32, max);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_confirm_matching_keys() {
+        let prk = decode("077709362C2E32DF0DDC3F0DC47BBA6390B6C73BB50F9C3122EC844AD7C2B3E5");
+        let tag = hkdf::confirm(&prk, b"initiator");
+
+        assert!(hkdf::verify_confirm(&prk, b"initiator", &tag));
+    }
+
+    #[test]
+    fn test_confirm_differing_keys() {
+        let prk = decode("077709362C2E32DF0DDC3F0DC47BBA6390B6C73BB50F9C3122EC844AD7C2B3E5");
+        let mut other = prk.clone();
+        other[0] ^= 1;
+
+        let tag = hkdf::confirm(&prk, b"initiator");
+
+        assert!(!hkdf::verify_confirm(&other, b"initiator", &tag));
+        assert!(!hkdf::verify_confirm(&prk, b"responder", &tag));
+    }
+}
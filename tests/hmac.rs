@@ -0,0 +1,112 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::hash::sha1::Sha1;
+    use himitsu::hash::sha256::Sha256;
+    use himitsu::hash::sha512::{Sha384, Sha512};
+    use himitsu::mac::hmac::Hmac;
+
+    macro_rules! hmac_test {
+        (
+            $fn_name: ident,
+            $hash: ty,
+            $key: expr,
+            $input: expr,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let key: Vec<u8> = $key;
+                let input: &[u8] = $input;
+                let expected = decode($expected);
+
+                assert_eq!(expected, Hmac::<$hash>::mac(&key, input));
+            }
+        };
+    }
+
+    // Test cases from RFC 4231
+
+    hmac_test!(
+        test_hmac_sha256_case_1,
+        Sha256,
+        vec![0x0b; 20],
+        b"Hi There",
+        "B0344C61 D8DB3853 5CA8AFCE AF0BF12B 881DC200 C9833DA7 26E9376C 2E32CFF7"
+    );
+
+    hmac_test!(
+        test_hmac_sha384_case_1,
+        Sha384,
+        vec![0x0b; 20],
+        b"Hi There",
+        "AFD03944 D8489562 6B0825F4 AB46907F 15F9DADB E4101EC6 82AA034C 7CEBC59C FAEA9EA9 076EDE7F 4AF152E8 B2FA9CB6"
+    );
+
+    hmac_test!(
+        test_hmac_sha512_case_1,
+        Sha512,
+        vec![0x0b; 20],
+        b"Hi There",
+        "87AA7CDE A5EF619D 4FF0B424 1A1D6CB0 2379F4E2 CE4EC278 7AD0B305 45E17CDE DAA833B7 D6B8A702 038B274E AEA3F4E4 BE9D914E EB61F170 2E696C20 3A126854"
+    );
+
+    hmac_test!(
+        test_hmac_sha256_case_2,
+        Sha256,
+        b"Jefe".to_vec(),
+        b"what do ya want for nothing?",
+        "5BDCC146 BF60754E 6A042426 089575C7 5A003F08 9D273983 9DEC58B9 64EC3843"
+    );
+
+    hmac_test!(
+        test_hmac_sha256_case_6,
+        Sha256,
+        vec![0xaa; 131],
+        b"Test Using Larger Than Block-Size Key - Hash Key First",
+        "60E43159 1EE0B67F 0D8A26AA CBF5B77F 8E0BC621 3728C514 0546040F 0EE37F54"
+    );
+
+    // Test cases from RFC 2202
+
+    hmac_test!(
+        test_hmac_sha1_case_1,
+        Sha1,
+        vec![0x0b; 20],
+        b"Hi There",
+        "B6173186 55057264 E28BC0B6 FB378C8E F146BE00"
+    );
+
+    hmac_test!(
+        test_hmac_sha1_case_2,
+        Sha1,
+        b"Jefe".to_vec(),
+        b"what do ya want for nothing?",
+        "EFFCDF6A E5EB2FA2 D27416D5 F184DF9C 259A7C79"
+    );
+
+    hmac_test!(
+        test_hmac_sha1_case_6,
+        Sha1,
+        vec![0xaa; 80],
+        b"Test Using Larger Than Block-Size Key - Hash Key First",
+        "AA4AE5E1 5272D00E 95705637 CE8A3B55 ED402112"
+    );
+
+    #[test]
+    fn test_hmac_incremental_update() {
+        let key = b"Jefe";
+        let expected = Hmac::<Sha256>::mac(key, b"what do ya want for nothing?");
+
+        let mut hmac = Hmac::<Sha256>::new(key);
+        hmac.update(b"what do ya ");
+        hmac.update(b"want for nothing?");
+
+        assert_eq!(expected, hmac.finalize());
+    }
+}
@@ -0,0 +1,185 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::cipher::block::primitive::aes::Aes;
+    use himitsu::cipher::keywrap::KeyWrap;
+    use himitsu::errors::blockcipher::BlockCipherError;
+
+    fn key_wrap(kek: &str) -> KeyWrap<Aes> {
+        KeyWrap::new(Aes::new(&decode(kek)).unwrap())
+    }
+
+    macro_rules! kw_test {
+        (
+            $fn_name: ident,
+            $kek: literal,
+            $key: literal,
+            $wrapped: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let kw = key_wrap($kek);
+                let (key, wrapped) = (decode($key), decode($wrapped));
+
+                assert_eq!(wrapped, kw.wrap_key(&key).unwrap());
+                assert_eq!(key, kw.unwrap_key(&wrapped).unwrap());
+            }
+        };
+    }
+
+    // RFC 3394 section 4
+    kw_test!(
+        test_kw_128_kek_128_key,
+        "00010203 04050607 08090A0B 0C0D0E0F",
+        "00112233 44556677 8899AABB CCDDEEFF",
+        "1FA68B0A 8112B447 AEF34BD8 FB5A7B82 9D3E8623 71D2CFE5"
+    );
+
+    kw_test!(
+        test_kw_128_kek_192_key,
+        "00010203 04050607 08090A0B 0C0D0E0F 10111213 14151617",
+        "00112233 44556677 8899AABB CCDDEEFF",
+        "96778B25 AE6CA435 F92B5B97 C050AED2 468AB8A1 7AD84E5D"
+    );
+
+    kw_test!(
+        test_kw_128_kek_256_key,
+        "00010203 04050607 08090A0B 0C0D0E0F 10111213 14151617 18191A1B 1C1D1E1F",
+        "00112233 44556677 8899AABB CCDDEEFF",
+        "64E8C3F9 CE0F5BA2 63E97779 05818A2A 93C8191E 7D6E8AE7"
+    );
+
+    kw_test!(
+        test_kw_192_kek_192_key,
+        "00010203 04050607 08090A0B 0C0D0E0F 10111213 14151617",
+        "00112233 44556677 8899AABB CCDDEEFF 00010203 04050607",
+        "031D3326 4E15D332 68F24EC2 60743EDC E1C6C7DD EE725A93 6BA81491 5C6762D2"
+    );
+
+    kw_test!(
+        test_kw_192_kek_256_key,
+        "00010203 04050607 08090A0B 0C0D0E0F 10111213 14151617 18191A1B 1C1D1E1F",
+        "00112233 44556677 8899AABB CCDDEEFF 00010203 04050607",
+        "A8F9BC16 12C68B3F F6E6F4FB E30E71E4 769C8B80 A32CB895 8CD5D17D 6B254DA1"
+    );
+
+    kw_test!(
+        test_kw_256_kek_256_key,
+        "00010203 04050607 08090A0B 0C0D0E0F 10111213 14151617 18191A1B 1C1D1E1F",
+        "00112233 44556677 8899AABB CCDDEEFF 00010203 04050607 08090A0B 0C0D0E0F",
+        "28C9F404 C4B810F4 CBCCB35C FB87F826 3F5786E2 D80ED326 CBC7F0E7 1A99F43B FB988B9B 7A02DD21"
+    );
+
+    #[test]
+    fn test_kw_tampered() {
+        let kw = key_wrap("00010203 04050607 08090A0B 0C0D0E0F");
+        let wrapped = decode("1FA68B0A 8112B447 AEF34BD8 FB5A7B82 9D3E8623 71D2CFE5");
+
+        for i in [0, 8, 23] {
+            let mut tampered = wrapped.clone();
+            tampered[i] ^= 1;
+            assert!(matches!(kw.unwrap_key(&tampered), Err(BlockCipherError::AuthenticationFailed)));
+        }
+
+        // The wrong key encryption key does not recover the integrity check value either
+        let kw = key_wrap("00010203 04050607 08090A0B 0C0D0E0E");
+        assert!(matches!(kw.unwrap_key(&wrapped), Err(BlockCipherError::AuthenticationFailed)));
+    }
+
+    #[test]
+    fn test_kw_invalid_length() {
+        let kw = key_wrap("00010203 04050607 08090A0B 0C0D0E0F");
+
+        for len in [0, 8, 15, 17, 20] {
+            assert!(matches!(kw.wrap_key(&vec![0; len]), Err(BlockCipherError::InvalidParameter(_))));
+        }
+
+        for len in [0, 16, 23, 25] {
+            assert!(matches!(kw.unwrap_key(&vec![0; len]), Err(BlockCipherError::InvalidParameter(_))));
+        }
+    }
+
+    macro_rules! kwp_test {
+        (
+            $fn_name: ident,
+            $kek: literal,
+            $key: literal,
+            $wrapped: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let kw = key_wrap($kek);
+                let (key, wrapped) = (decode($key), decode($wrapped));
+
+                assert_eq!(wrapped, kw.wrap_key_padded(&key).unwrap());
+                assert_eq!(key, kw.unwrap_key_padded(&wrapped).unwrap());
+            }
+        };
+    }
+
+    // RFC 5649 section 6
+    kwp_test!(
+        test_kwp_20_byte_key,
+        "5840DF6E 29B02AF1 AB493B70 5BF16EA1 AE8338F4 DCC176A8",
+        "C37B7E64 92584340 BED12207 80894115 5068F738",
+        "138BDEAA 9B8FA7FC 61F97742 E72248EE 5AE6AE53 60D1AE6A 5F54F373 FA543B6A"
+    );
+
+    // Padded to a single semiblock, which is encrypted as one block
+    kwp_test!(
+        test_kwp_7_byte_key,
+        "5840DF6E 29B02AF1 AB493B70 5BF16EA1 AE8338F4 DCC176A8",
+        "466F7250 617369",
+        "AFBEB0F0 7DFBF541 9200F2CC B50BB24F"
+    );
+
+    #[test]
+    fn test_kwp_roundtrip() {
+        let kw = key_wrap("00010203 04050607 08090A0B 0C0D0E0F");
+
+        for len in 1..=40 {
+            let key: Vec<u8> = (0..len as u8).collect();
+            let wrapped = kw.wrap_key_padded(&key).unwrap();
+
+            assert_eq!(0, wrapped.len() % 8);
+            assert!(wrapped.len() >= len + 8 && wrapped.len() < len + 16);
+            assert_eq!(key, kw.unwrap_key_padded(&wrapped).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_kwp_tampered() {
+        let kw = key_wrap("5840DF6E 29B02AF1 AB493B70 5BF16EA1 AE8338F4 DCC176A8");
+
+        for wrapped in [
+            decode("138BDEAA 9B8FA7FC 61F97742 E72248EE 5AE6AE53 60D1AE6A 5F54F373 FA543B6A"),
+            decode("AFBEB0F0 7DFBF541 9200F2CC B50BB24F"),
+        ] {
+            for i in [0, wrapped.len() - 1] {
+                let mut tampered = wrapped.clone();
+                tampered[i] ^= 1;
+                assert!(matches!(kw.unwrap_key_padded(&tampered), Err(BlockCipherError::AuthenticationFailed)));
+            }
+        }
+
+        // A key wrapped without padding carries the wrong initial value
+        let wrapped = kw.wrap_key(&[0x42; 24]).unwrap();
+        assert!(matches!(kw.unwrap_key_padded(&wrapped), Err(BlockCipherError::AuthenticationFailed)));
+    }
+
+    #[test]
+    fn test_kwp_invalid_length() {
+        let kw = key_wrap("00010203 04050607 08090A0B 0C0D0E0F");
+
+        assert!(matches!(kw.wrap_key_padded(&[]), Err(BlockCipherError::InvalidParameter(_))));
+
+        for len in [0, 8, 15, 17] {
+            assert!(matches!(kw.unwrap_key_padded(&vec![0; len]), Err(BlockCipherError::InvalidParameter(_))));
+        }
+    }
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::hash::{sha1::Sha1, sha256::Sha256};
+    use himitsu::kdf::mgf1::mgf1;
+
+    // Examples from https://en.wikipedia.org/wiki/Mask_generation_function
+    #[test]
+    fn test_mgf1_sha1() {
+        assert_eq!(decode("1AC907"), mgf1::<Sha1>(b"foo", 3));
+        assert_eq!(decode("1AC9075CD4"), mgf1::<Sha1>(b"foo", 5));
+        assert_eq!(decode("BC0C655E01"), mgf1::<Sha1>(b"bar", 5));
+        assert_eq!(
+            decode(
+                "BC0C655E 016BC293 1D85A2E6 75181ADC EF7F581F 76DF2739 DA74FAAC 41627BE2 F7F415C8 9E983FD0
+                 CE80CED9 878641CB 4876"
+            ),
+            mgf1::<Sha1>(b"bar", 50)
+        );
+    }
+
+    #[test]
+    fn test_mgf1_sha256() {
+        assert_eq!(
+            decode(
+                "382576A7 841021CC 28FC4C09 48753FB8 312090CE A942EA4C 4E735D10 DC724B15 5F9F6069 F289D61D
+                 ACA0CB81 4502EF04 EAE1"
+            ),
+            mgf1::<Sha256>(b"bar", 50)
+        );
+    }
+
+    // Every mask is a prefix of a longer mask of the same seed
+    #[test]
+    fn test_mgf1_prefix() {
+        let long = mgf1::<Sha256>(b"seed", 100);
+
+        for len in [0, 1, 31, 32, 33, 64, 99] {
+            assert_eq!(&long[..len], &mgf1::<Sha256>(b"seed", len)[..]);
+        }
+    }
+}
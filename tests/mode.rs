@@ -0,0 +1,197 @@
+// Exercises the std::io integration
+#![cfg(feature = "std")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::cipher::block::buffered::{BufferedCipherDecryptionProvider, BufferedCipherEncryptionProvider};
+    use himitsu::cipher::block::primitive::aes::Aes;
+    use himitsu::cipher::mode::{cipher_from_mode, decipher_from_mode, Mode};
+    use himitsu::errors::blockcipher::BlockCipherError;
+    use std::io::Write;
+
+    #[test]
+    fn ecb_takes_no_nonce() {
+        assert!(Mode::Ecb.validate_nonce_len(0, 16).is_ok());
+        assert!(Mode::Ecb.validate_nonce_len(16, 16).is_err());
+    }
+
+    #[test]
+    fn block_sized_nonce() {
+        for mode in [Mode::Cbc, Mode::Cfb, Mode::Ctr] {
+            assert!(mode.validate_nonce_len(16, 16).is_ok());
+            assert!(mode.validate_nonce_len(8, 8).is_ok());
+            assert!(mode.validate_nonce_len(12, 16).is_err());
+            assert!(mode.validate_nonce_len(0, 16).is_err());
+        }
+    }
+
+    #[test]
+    fn gcm_nonce() {
+        assert!(Mode::Gcm.validate_nonce_len(12, 16).is_ok());
+
+        for len in [0, 8, 11, 13, 16] {
+            match Mode::Gcm.validate_nonce_len(len, 16) {
+                Err(BlockCipherError::InvalidIvLength { got, expected: 12 }) => assert_eq!(len, got),
+                other => panic!("unexpected result for {} bytes: {:?}", len, other),
+            }
+        }
+    }
+
+    #[test]
+    fn ccm_nonce() {
+        for len in 7..=13 {
+            assert!(Mode::Ccm.validate_nonce_len(len, 16).is_ok());
+        }
+
+        for len in [0, 6, 14, 16] {
+            assert!(matches!(
+                Mode::Ccm.validate_nonce_len(len, 16),
+                Err(BlockCipherError::InvalidParameter(_))
+            ));
+        }
+
+        assert_eq!(7..=13, Mode::Ccm.nonce_len_range(16));
+        assert!(Mode::Ccm.nonce_len_range(16).contains(&Mode::Ccm.nonce_len(16)));
+    }
+
+    #[test]
+    fn factory_rejects_aead_modes() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let nonce = [0; 12];
+
+        for mode in [Mode::Gcm, Mode::Ccm] {
+            assert!(matches!(
+                cipher_from_mode::<_, 16>(mode, Aes::new(&key).unwrap(), Some(&nonce)),
+                Err(BlockCipherError::InvalidParameter(_))
+            ));
+            assert!(matches!(
+                decipher_from_mode::<_, 16>(mode, Aes::new(&key).unwrap(), Some(&nonce)),
+                Err(BlockCipherError::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn error_reports_lengths() {
+        match Mode::Cbc.validate_nonce_len(12, 16) {
+            Err(BlockCipherError::InvalidIvLength { got, expected }) => {
+                assert_eq!(12, got);
+                assert_eq!(16, expected);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn mode_from_name() {
+        assert_eq!(Some(Mode::Cbc), Mode::from_name("cbc"));
+        assert_eq!(Some(Mode::Ctr), Mode::from_name("CTR"));
+        assert_eq!(Some(Mode::Ofb), Mode::from_name("ofb"));
+        assert_eq!(Some(Mode::Gcm), Mode::from_name("GCM"));
+        assert_eq!(Some(Mode::Ccm), Mode::from_name("ccm"));
+        assert_eq!(None, Mode::from_name("siv"));
+    }
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+    #[test]
+    fn runtime_selected_round_trip() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2");
+
+        let mode = Mode::from_name("cbc").unwrap();
+
+        let mut cipher = cipher_from_mode(mode, Aes::new(&key).unwrap(), Some(&iv)).unwrap().buffered();
+        cipher.write_all(&plaintext).unwrap();
+        let ciphertext: Vec<u8> = cipher.finalize();
+        assert_eq!(expected, ciphertext);
+
+        let mut cipher = decipher_from_mode(mode, Aes::new(&key).unwrap(), Some(&iv)).unwrap().buffered();
+        cipher.write_all(&ciphertext).unwrap();
+        let output: Vec<u8> = cipher.finalize().unwrap();
+        assert_eq!(plaintext, output);
+    }
+
+    #[test]
+    fn every_mode_round_trips() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+
+        for mode in [Mode::Ecb, Mode::Cbc, Mode::Cfb, Mode::Ctr, Mode::Ofb] {
+            let iv = if mode == Mode::Ecb { None } else { Some(iv.as_slice()) };
+
+            let mut cipher = cipher_from_mode(mode, Aes::new(&key).unwrap(), iv).unwrap().buffered();
+            cipher.write_all(&plaintext).unwrap();
+            let ciphertext: Vec<u8> = cipher.finalize();
+            assert_ne!(plaintext, ciphertext);
+
+            let mut cipher = decipher_from_mode(mode, Aes::new(&key).unwrap(), iv).unwrap().buffered();
+            cipher.write_all(&ciphertext).unwrap();
+            let output: Vec<u8> = cipher.finalize().unwrap();
+            assert_eq!(plaintext, output, "{:?}", mode);
+        }
+    }
+
+    #[test]
+    fn factory_requires_iv() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+
+        for mode in [Mode::Cbc, Mode::Cfb, Mode::Ctr, Mode::Ofb] {
+            match cipher_from_mode::<_, 16>(mode, Aes::new(&key).unwrap(), None) {
+                Err(BlockCipherError::InvalidIvLength { got: 0, expected: 16 }) => {}
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("{:?} accepted a missing iv", mode),
+            }
+        }
+
+        assert!(cipher_from_mode::<_, 16>(Mode::Ecb, Aes::new(&key).unwrap(), None).is_ok());
+    }
+
+    // The stream-like modes keep the length of the input through the buffered layer
+    #[test]
+    fn stream_modes_round_trip_partial_blocks() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext: Vec<u8> = (0..45).collect();
+
+        for mode in [Mode::Cfb, Mode::Ctr, Mode::Ofb] {
+            for len in [0, 1, 15, 16, 17, 45] {
+                let mut cipher = cipher_from_mode(mode, Aes::new(&key).unwrap(), Some(&iv)).unwrap().buffered();
+                cipher.write_all(&plaintext[..len]).unwrap();
+                let ciphertext: Vec<u8> = cipher.finalize_partial().unwrap();
+                assert_eq!(len, ciphertext.len(), "{:?}", mode);
+
+                let mut cipher = decipher_from_mode(mode, Aes::new(&key).unwrap(), Some(&iv)).unwrap().buffered();
+                for chunk in ciphertext.chunks(7) {
+                    cipher.write_all(chunk).unwrap();
+                }
+                let output: Vec<u8> = cipher.finalize_partial().unwrap();
+                assert_eq!(plaintext[..len], output[..], "{:?}", mode);
+            }
+        }
+    }
+
+    #[test]
+    fn block_modes_reject_partial_blocks() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode("00010203 04050607 08090A0B 0C0D0E0F");
+
+        let mut cipher = cipher_from_mode(Mode::Cbc, Aes::new(&key).unwrap(), Some(&iv)).unwrap().buffered();
+        cipher.write_all(&[0; 20]).unwrap();
+        assert!(matches!(
+            cipher.finalize_partial::<Vec<u8>>(),
+            Err(BlockCipherError::IncompleteBlock(12))
+        ));
+
+        // Block aligned input has nothing to process at the end
+        let mut cipher = cipher_from_mode(Mode::Ecb, Aes::new(&key).unwrap(), None).unwrap().buffered();
+        cipher.write_all(&[0; 32]).unwrap();
+        assert_eq!(32, cipher.finalize_partial::<Vec<u8>>().unwrap().len());
+    }
+}
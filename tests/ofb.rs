@@ -0,0 +1,58 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::cipher::block::buffered::{BufferedCipherDecryption, BufferedCipherEncryption};
+    use himitsu::cipher::block::primitive::aes::{Aes, AES_BLOCKSIZE};
+    use himitsu::cipher::mode::StreamBlocks;
+    use himitsu::cipher::stream::ofb::OfbCipherProvider;
+    use himitsu::prelude::*;
+
+    // NIST SP 800-38A F.4.1 OFB-AES128
+    const KEY: &str = "2B7E1516 28AED2A6 ABF71588 09CF4F3C";
+    const IV: &str = "00010203 04050607 08090A0B 0C0D0E0F";
+    const PLAINTEXT: &str = "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51
+                             30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710";
+    const CIPHERTEXT: &str = "3B3FD92E B72DAD20 333449F8 E83CFB4A 7789508D 16918F03 F53C52DA C54ED825
+                              9740051E 9C5FECF6 4344F7A8 2260EDCC 304C6528 F659C778 66A510D9 C1D6AE5E";
+
+    #[test]
+    fn test_ofb_aes128_nist() {
+        let (plaintext, expected) = (decode(PLAINTEXT), decode(CIPHERTEXT));
+
+        let mut buf = plaintext.clone();
+        Aes::new(&decode(KEY)).unwrap().with_ofb(decode_into_array(IV)).encrypt(&mut buf);
+        assert_eq!(expected, buf);
+
+        // Uneven pieces decrypt to the same plaintext
+        let mut ofb = Aes::new(&decode(KEY)).unwrap().with_ofb(decode_into_array(IV));
+        for chunk in buf.chunks_mut(5) {
+            ofb.decrypt(chunk);
+        }
+        assert_eq!(plaintext, buf);
+    }
+
+    #[test]
+    fn test_ofb_buffered_partial_block() {
+        let plaintext = decode(PLAINTEXT);
+        let expected = decode(CIPHERTEXT);
+        let len = 50;
+
+        let ofb = Aes::new(&decode(KEY)).unwrap().with_ofb(decode_into_array(IV));
+        // OFB is symmetric, so the direction and the blocksize of the buffered layer have to be named
+        let mut cipher: BufferedCipherEncryption<AES_BLOCKSIZE, _> = BufferedCipherEncryption::new(StreamBlocks::new(ofb));
+        cipher.write_bytes(&plaintext[..len]).unwrap();
+        let ciphertext: Vec<u8> = cipher.finalize_partial().unwrap();
+        assert_eq!(expected[..len], ciphertext[..]);
+
+        let ofb = Aes::new(&decode(KEY)).unwrap().with_ofb(decode_into_array(IV));
+        let mut cipher: BufferedCipherDecryption<AES_BLOCKSIZE, _> = BufferedCipherDecryption::new(StreamBlocks::new(ofb));
+        cipher.write_bytes(&ciphertext).unwrap();
+        let output: Vec<u8> = cipher.finalize_partial().unwrap();
+        assert_eq!(plaintext[..len], output[..]);
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::cipher::block::{
+        cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+        ecb::{EcbDecryptionProvider, EcbEncryptionProvider},
+        primitive::aes::Aes,
+    };
+    use himitsu::errors::blockcipher::{BlockCipherError, PaddingErrorKind};
+    use himitsu::prelude::*;
+
+    const KEY: &str = "2B7E1516 28AED2A6 ABF71588 09CF4F3C";
+    const IV: &str = "00010203 04050607 08090A0B 0C0D0E0F";
+    const PLAINTEXT: &str = "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51";
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+    #[test]
+    fn test_oneshot_ecb() {
+        let plaintext = decode(PLAINTEXT);
+        let expected = decode("3AD77BB4 0D7A3660 A89ECAF3 2466EF97 F5D3D585 03B9699D E785895A 96FDBAAF");
+
+        let ciphertext = Aes::new(&decode(KEY)).unwrap().with_ecb_encryption().encrypt_all(&plaintext).unwrap();
+        assert_eq!(expected, ciphertext);
+
+        let output = Aes::new(&decode(KEY)).unwrap().with_ecb_decryption().decrypt_all(&ciphertext).unwrap();
+        assert_eq!(plaintext, output);
+    }
+
+    #[test]
+    fn test_oneshot_cbc() {
+        let plaintext = decode(PLAINTEXT);
+        let iv = decode_into_array(IV);
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2");
+
+        let ciphertext = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption(iv).encrypt_all(&plaintext).unwrap();
+        assert_eq!(expected, ciphertext);
+
+        let output = Aes::new(&decode(KEY)).unwrap().with_cbc_decryption(iv).decrypt_all(&ciphertext).unwrap();
+        assert_eq!(plaintext, output);
+    }
+
+    // Padded output verified with openssl enc -aes-128-cbc
+    #[test]
+    fn test_oneshot_cbc_padded() {
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57");
+        let iv = decode_into_array(IV);
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 2E013F89 0472D822 17B17F45 F6E7F539");
+
+        let ciphertext = Aes::new(&decode(KEY)).unwrap().with_cbc_encryption(iv).encrypt_all_padded(&plaintext).unwrap();
+        assert_eq!(expected, ciphertext);
+
+        let output = Aes::new(&decode(KEY)).unwrap().with_cbc_decryption(iv).decrypt_all_padded(&ciphertext).unwrap();
+        assert_eq!(plaintext, output);
+    }
+
+    #[test]
+    fn test_oneshot_unaligned() {
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57");
+
+        match Aes::new(&decode(KEY)).unwrap().with_ecb_encryption().encrypt_all(&plaintext) {
+            Err(BlockCipherError::IncompleteBlock(missing)) => assert_eq!(12, missing),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        match Aes::new(&decode(KEY)).unwrap().with_ecb_decryption().decrypt_all(&plaintext) {
+            Err(BlockCipherError::IncompleteBlock(missing)) => assert_eq!(12, missing),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        match Aes::new(&decode(KEY)).unwrap().with_ecb_decryption().decrypt_all_padded(&plaintext) {
+            Err(BlockCipherError::BadPadding { kind }) => assert_eq!(PaddingErrorKind::Length, kind),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_oneshot_empty() {
+        let ciphertext = Aes::new(&decode(KEY)).unwrap().with_ecb_encryption().encrypt_all(&[]).unwrap();
+        assert!(ciphertext.is_empty());
+
+        let ciphertext = Aes::new(&decode(KEY)).unwrap().with_ecb_encryption().encrypt_all_padded(&[]).unwrap();
+        assert_eq!(16, ciphertext.len());
+
+        let output = Aes::new(&decode(KEY)).unwrap().with_ecb_decryption().decrypt_all_padded(&ciphertext).unwrap();
+        assert!(output.is_empty());
+    }
+}
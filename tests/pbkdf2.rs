@@ -0,0 +1,89 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::errors::kdf::KdfError;
+    use himitsu::hash::{sha1::Sha1, sha256::Sha256};
+    use himitsu::kdf::pbkdf2::pbkdf2;
+
+    macro_rules! pbkdf2_test {
+        (
+            $fn_name: ident,
+            $hash: ty,
+            $password: literal,
+            $salt: literal,
+            $iterations: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let expected = decode($expected);
+
+                let dk = pbkdf2::<$hash>($password, $salt, $iterations, expected.len()).unwrap();
+                assert_eq!(expected, dk);
+            }
+        };
+    }
+
+    // Test vectors from RFC 6070
+
+    pbkdf2_test!(
+        test_pbkdf2_sha1_1_iteration,
+        Sha1,
+        b"password",
+        b"salt",
+        1,
+        "0C60C80F 961F0E71 F3A9B524 AF601206 2FE037A6"
+    );
+
+    pbkdf2_test!(
+        test_pbkdf2_sha1_2_iterations,
+        Sha1,
+        b"password",
+        b"salt",
+        2,
+        "EA6C014D C72D6F8C CD1ED92A CE1D41F0 D8DE8957"
+    );
+
+    pbkdf2_test!(
+        test_pbkdf2_sha1_4096_iterations,
+        Sha1,
+        b"password",
+        b"salt",
+        4096,
+        "4B007901 B765489A BEAD49D9 26F721D0 65A429C1"
+    );
+
+    // Output longer than the hash length
+    pbkdf2_test!(
+        test_pbkdf2_sha1_multiple_blocks,
+        Sha1,
+        b"passwordPASSWORDpassword",
+        b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+        4096,
+        "3D2EEC4F E41C849B 80C8D836 62C0E44A 8B291A96 4CF2F070 38"
+    );
+
+    // Test vectors from RFC 7914, section 11
+
+    pbkdf2_test!(
+        test_pbkdf2_sha256_1_iteration,
+        Sha256,
+        b"passwd",
+        b"salt",
+        1,
+        "55AC046E 56E3089F EC1691C2 2544B605 F9418521 6DDE0465 E68B9D57 C20DACBC 49CA9CCC F179B645 991664B3 9D77EF31 7C71B845 B1E30BD5 09112041 D3A19783"
+    );
+
+    #[test]
+    fn test_pbkdf2_zero_iterations() {
+        match pbkdf2::<Sha256>(b"password", b"salt", 0, 32) {
+            Err(KdfError::InvalidParameter(_)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
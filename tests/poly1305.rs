@@ -0,0 +1,82 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::mac::poly1305::Poly1305;
+
+    macro_rules! poly1305_test {
+        (
+            $fn_name: ident,
+            $key: literal,
+            $input: expr,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let key = decode_into_array($key);
+                let input: &[u8] = $input;
+                let expected = decode($expected);
+
+                assert_eq!(expected, Poly1305::mac(&key, input));
+            }
+        };
+    }
+
+    // RFC 8439, 2.5.2
+    poly1305_test!(
+        test_poly1305_rfc8439,
+        "85D6BE78 57556D33 7F4452FE 42D506A8 0103808A FB0DB2FD 4ABFF6AF 4149F51B",
+        b"Cryptographic Forum Research Group",
+        "A8061DC1 305136C6 C22B8BAF 0C0127A9"
+    );
+
+    // RFC 8439, A.3 test vector #1
+    poly1305_test!(
+        test_poly1305_zero_key,
+        "00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000",
+        &[0; 64],
+        "00000000 00000000 00000000 00000000"
+    );
+
+    // RFC 8439, A.3 test vectors #5 and #6, exercising the final reduction modulo 2^130 - 5
+    poly1305_test!(
+        test_poly1305_reduction,
+        "02000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000",
+        &[0xFF; 16],
+        "03000000 00000000 00000000 00000000"
+    );
+
+    poly1305_test!(
+        test_poly1305_tag_wraps,
+        "02000000 00000000 00000000 00000000 FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF",
+        &[0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        "03000000 00000000 00000000 00000000"
+    );
+
+    #[test]
+    fn test_poly1305_incremental_update() {
+        let key = decode_into_array("85D6BE78 57556D33 7F4452FE 42D506A8 0103808A FB0DB2FD 4ABFF6AF 4149F51B");
+        let expected = decode("A8061DC1 305136C6 C22B8BAF 0C0127A9");
+
+        let mut poly = Poly1305::new(&key);
+        for chunk in b"Cryptographic Forum Research Group".chunks(5) {
+            poly.update(chunk);
+        }
+
+        assert_eq!(expected, poly.finalize());
+    }
+
+    #[test]
+    fn test_poly1305_different_messages() {
+        let key = decode_into_array("85D6BE78 57556D33 7F4452FE 42D506A8 0103808A FB0DB2FD 4ABFF6AF 4149F51B");
+
+        assert_ne!(
+            Poly1305::mac(&key, b"Cryptographic Forum Research Group"),
+            Poly1305::mac(&key, b"Cryptographic Forum Research Grouq")
+        );
+    }
+}
@@ -0,0 +1,52 @@
+// Randomness from the operating system is only available with std
+#![cfg(feature = "std")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::cipher::block::{
+        buffered::{BufferedCipherDecryptionProvider, BufferedCipherEncryptionProvider},
+        cbc::{syncronous::CbcEncryption, CbcDecryptionProvider},
+        primitive::aes,
+    };
+    use himitsu::util::random::random_bytes;
+    use std::io::Write;
+
+    #[test]
+    fn test_random_bytes_length() {
+        for n in [0, 1, 16, 1000] {
+            assert_eq!(n, random_bytes(n).len());
+        }
+    }
+
+    // Smoke test only, two successive 128 bit values colliding is practically impossible
+    #[test]
+    fn test_random_bytes_differ() {
+        assert_ne!(random_bytes(16), random_bytes(16));
+    }
+
+    #[test]
+    fn test_cbc_random_iv() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let input = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+
+        let (cipher, iv) = CbcEncryption::with_random_iv(aes::Aes::new(&key).unwrap()).unwrap();
+        let (_, other_iv) = CbcEncryption::with_random_iv(aes::Aes::new(&key).unwrap()).unwrap();
+
+        assert_eq!(16, iv.len());
+        assert_ne!(iv, other_iv);
+
+        let mut cipher = cipher.buffered();
+        cipher.write_all(&input).unwrap();
+        let ciphertext: Vec<u8> = cipher.finalize();
+
+        let mut cipher = aes::Aes::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_all(&ciphertext).unwrap();
+        let output: Vec<u8> = cipher.finalize().unwrap();
+
+        assert_eq!(input, output);
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::cipher::stream::rc4::Rc4;
+    use himitsu::errors::blockcipher::BlockCipherError;
+    use himitsu::prelude::*;
+
+    macro_rules! rc4_test {
+        (
+            $fn_name: ident,
+            $key: expr,
+            $plaintext: expr,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let expected = decode($expected);
+
+                let mut buf = $plaintext.to_vec();
+                Rc4::new($key).unwrap().encrypt(&mut buf);
+                assert_eq!(expected, buf);
+
+                // Feeding the ciphertext in uneven pieces
+                let mut rc4 = Rc4::new($key).unwrap();
+                for chunk in buf.chunks_mut(2) {
+                    rc4.decrypt(chunk);
+                }
+                assert_eq!($plaintext.to_vec(), buf);
+            }
+        };
+    }
+
+    rc4_test!(test_rc4_key, b"Key", b"Plaintext", "BBF316E8 D940AF0A D3");
+    rc4_test!(test_rc4_wiki, b"Wiki", b"pedia", "1021BF04 20");
+    rc4_test!(test_rc4_secret, b"Secret", b"Attack at dawn", "45A01F64 5FC35B38 3552544B 9BF5");
+
+    // RFC 6229, 40 bit key at offset 0
+    rc4_test!(
+        test_rc4_rfc6229_40bit,
+        &[0x01, 0x02, 0x03, 0x04, 0x05],
+        &[0; 16],
+        "B2396305 F03DC027 CCC3524A 0A1118A8"
+    );
+
+    #[test]
+    fn test_rc4_invalid_key_length() {
+        assert!(matches!(
+            Rc4::new(&[]),
+            Err(BlockCipherError::InvalidKeyLength { got: 0, min: 1, max: 256 })
+        ));
+        assert!(matches!(
+            Rc4::new(&[0; 257]),
+            Err(BlockCipherError::InvalidKeyLength { got: 257, .. })
+        ));
+        assert!(Rc4::new(&[0; 256]).is_ok());
+    }
+}
@@ -0,0 +1,128 @@
+// Exercises the std::io integration
+#![cfg(feature = "std")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::cipher::block::{
+        buffered::{
+            BufferedCipherDecryption, BufferedCipherDecryptionProvider, BufferedCipherEncryption,
+            BufferedCipherEncryptionProvider,
+        },
+        cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+        primitive::aes,
+        reader::{Finish, ReadCipher},
+    };
+    use himitsu::cipher::mode::StreamBlocks;
+    use himitsu::cipher::stream::ctr::CtrCipherProvider;
+    use himitsu::errors::blockcipher::BlockCipherError;
+    use std::io::{self, Cursor, Read, Write};
+
+    const KEY: &str = "2B7E1516 28AED2A6 ABF71588 09CF4F3C";
+    const IV: &str = "00010203 04050607 08090A0B 0C0D0E0F";
+
+    fn aes() -> aes::Aes {
+        aes::Aes::new(&decode(KEY)).unwrap()
+    }
+
+    /// Reads everything in small pieces, so output is handed out across refills
+    fn read_in_pieces<R: Read>(mut r: R) -> io::Result<Vec<u8>> {
+        let mut output = Vec::new();
+        let mut piece = [0; 7];
+
+        loop {
+            match r.read(&mut piece)? {
+                0 => return Ok(output),
+                n => output.extend_from_slice(&piece[..n]),
+            }
+        }
+    }
+
+    // Larger than a single chunk read from the source and not block aligned
+    #[test]
+    fn test_read_cipher_cbc_padded_matches_push() {
+        let plaintext: Vec<u8> = (0..10_000u32).map(|i| (i * 7) as u8).collect();
+        let iv = decode_into_array(IV);
+
+        let mut cipher = aes().with_cbc_encryption(iv).buffered();
+        cipher.write_all(&plaintext).unwrap();
+        let expected: Vec<u8> = cipher.finalize_padded().unwrap();
+
+        let cipher = aes().with_cbc_encryption(iv).buffered();
+        let ciphertext = read_in_pieces(ReadCipher::new(Cursor::new(&plaintext), cipher, Finish::Padded)).unwrap();
+        assert_eq!(expected, ciphertext);
+
+        let cipher = aes().with_cbc_decryption(iv).buffered();
+        let mut decrypted = Vec::new();
+        ReadCipher::new(Cursor::new(&ciphertext), cipher, Finish::Padded)
+            .read_to_end(&mut decrypted)
+            .unwrap();
+        assert_eq!(plaintext, decrypted);
+    }
+
+    // NIST SP 800-38A F.2.1
+    #[test]
+    fn test_read_cipher_cbc_exact() {
+        let iv = decode_into_array(IV);
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51");
+        let expected = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2");
+
+        let cipher = aes().with_cbc_encryption(iv).buffered();
+        let mut output = Vec::new();
+        ReadCipher::new(Cursor::new(&plaintext), cipher, Finish::Exact)
+            .read_to_end(&mut output)
+            .unwrap();
+        assert_eq!(expected, output);
+
+        let cipher = aes().with_cbc_decryption(iv).buffered();
+        let output = read_in_pieces(ReadCipher::new(Cursor::new(&expected), cipher, Finish::Exact)).unwrap();
+        assert_eq!(plaintext, output);
+
+        // Without padding the source has to be block aligned
+        let cipher = aes().with_cbc_encryption(iv).buffered();
+        let err = ReadCipher::new(Cursor::new(&plaintext[..20]), cipher, Finish::Exact)
+            .read_to_end(&mut Vec::new())
+            .unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+        assert!(matches!(
+            err.into_inner().unwrap().downcast_ref(),
+            Some(BlockCipherError::IncompleteBlock(12))
+        ));
+    }
+
+    #[test]
+    fn test_read_cipher_cbc_bad_padding() {
+        let iv = decode_into_array(IV);
+        let ciphertext = decode("7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2");
+
+        let cipher = aes().with_cbc_decryption(iv).buffered();
+        let err = read_in_pieces(ReadCipher::new(Cursor::new(&ciphertext), cipher, Finish::Padded)).unwrap_err();
+        assert!(matches!(
+            err.into_inner().unwrap().downcast_ref(),
+            Some(BlockCipherError::BadPadding { .. })
+        ));
+    }
+
+    // NIST SP 800-38A F.5.1, truncated to end inside the third block
+    #[test]
+    fn test_read_cipher_ctr_partial() {
+        let iv = decode_into_array("F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF");
+        let plaintext =
+            decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5");
+        let expected =
+            decode("874D6191 B620E326 1BEF6864 990DB6CE 9806F66B 7970FDFF 8617187B B9FFFDFF 5AE4DF3E DBD5D35E 5B");
+
+        let cipher: BufferedCipherEncryption<{ aes::AES_BLOCKSIZE }, _> =
+            BufferedCipherEncryption::new(StreamBlocks::new(aes().with_ctr(iv)));
+        let output = read_in_pieces(ReadCipher::new(Cursor::new(&plaintext), cipher, Finish::Partial)).unwrap();
+        assert_eq!(expected, output);
+
+        let cipher: BufferedCipherDecryption<{ aes::AES_BLOCKSIZE }, _> =
+            BufferedCipherDecryption::new(StreamBlocks::new(aes().with_ctr(iv)));
+        let output = read_in_pieces(ReadCipher::new(Cursor::new(&expected), cipher, Finish::Partial)).unwrap();
+        assert_eq!(plaintext, output);
+    }
+}
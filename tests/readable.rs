@@ -1,3 +1,6 @@
+// Exercises the std::io integration
+#![cfg(feature = "std")]
+
 #[cfg(test)]
 mod tests {
 
@@ -15,4 +18,121 @@ mod tests {
 
         assert_eq!(data, out);
     }
+
+    #[test]
+    fn test_readable_borrowed_chunks() {
+        let data = [1u8, 2, 3, 4, 5, 6, 7];
+        let mut rdb: Readable<&[u8]> = Readable::new(&data[..]);
+
+        let mut chunk = [0u8; 3];
+        let mut out = Vec::new();
+
+        loop {
+            let read = rdb.read(&mut chunk).unwrap();
+            if read == 0 {
+                break;
+            }
+
+            assert!(read == 3 || read == 1);
+            out.extend_from_slice(&chunk[..read]);
+        }
+
+        assert_eq!(data.to_vec(), out);
+    }
+
+    #[test]
+    fn test_readable_remaining() {
+        let data = vec![1u8, 2, 3, 4, 5];
+        let mut rdb = Readable::new(data.clone());
+        assert_eq!(5, rdb.remaining());
+
+        let mut head = [0u8; 2];
+        rdb.read_exact(&mut head).unwrap();
+        assert_eq!(3, rdb.remaining());
+
+        let mut rest = Vec::new();
+        rdb.read_to_end(&mut rest).unwrap();
+        assert_eq!(0, rdb.remaining());
+    }
+
+    #[test]
+    fn test_readable_into_remaining() {
+        let data = vec![1u8, 2, 3, 4, 5];
+        let mut rdb = Readable::new(data.clone());
+
+        let mut head = [0u8; 2];
+        rdb.read_exact(&mut head).unwrap();
+
+        assert_eq!([1, 2], head);
+        assert_eq!(data[2..].to_vec(), rdb.into_remaining());
+
+        let mut rdb = Readable::new(data.clone());
+        rdb.read_exact(&mut head).unwrap();
+        assert_eq!(data, rdb.into_inner());
+    }
+
+    #[test]
+    fn test_readable_iterator_matches_read_to_end() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        let mut out = Vec::new();
+        Readable::new(data.clone()).read_to_end(&mut out).unwrap();
+
+        let collected: Vec<u8> = Readable::new(data.clone()).collect();
+        assert_eq!(out, collected);
+
+        let odd: Vec<u8> = Readable::new(data).filter(|b| b % 2 == 1).collect();
+        assert_eq!(128, odd.len());
+    }
+
+    #[test]
+    fn test_readable_iterator_shares_cursor() {
+        let data = vec![1u8, 2, 3, 4, 5];
+        let mut rdb = Readable::new(data);
+
+        assert_eq!(Some(1), rdb.next());
+        assert_eq!(4, rdb.len());
+
+        let mut head = [0u8; 2];
+        rdb.read_exact(&mut head).unwrap();
+        assert_eq!([2, 3], head);
+
+        assert_eq!(vec![4, 5], rdb.collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_readable_read_chunks() {
+        let data: Vec<u8> = (0..10).collect();
+        let mut rdb = Readable::new(data.clone());
+
+        let chunks: Vec<&[u8]> = rdb.read_chunks(4).collect();
+        assert_eq!(vec![&data[0..4], &data[4..8], &data[8..]], chunks);
+        assert_eq!(2, chunks.last().unwrap().len());
+        assert_eq!(0, rdb.remaining());
+    }
+
+    #[test]
+    fn test_readable_next_chunk_shares_cursor() {
+        let data = [1u8, 2, 3, 4, 5, 6, 7];
+        let mut rdb = Readable::new(&data[..]);
+
+        assert_eq!(Some(&[1u8, 2, 3][..]), rdb.next_chunk(3));
+
+        let mut byte = [0u8; 1];
+        rdb.read_exact(&mut byte).unwrap();
+        assert_eq!([4], byte);
+
+        // Stopping early leaves the rest unread
+        assert_eq!(Some(&[5u8, 6][..]), rdb.read_chunks(2).next());
+        assert_eq!(1, rdb.remaining());
+
+        assert_eq!(Some(&[7u8][..]), rdb.next_chunk(16));
+        assert_eq!(None, rdb.next_chunk(16));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_readable_zero_chunk_size() {
+        Readable::new(vec![1u8, 2]).next_chunk(0);
+    }
 }
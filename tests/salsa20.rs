@@ -0,0 +1,117 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::cipher::stream::salsa20::Salsa20;
+    use himitsu::prelude::*;
+
+    macro_rules! salsa20_test {
+        (
+            $fn_name: ident,
+            $key: literal,
+            $nonce: literal,
+            $offset: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let key = decode_into_array($key);
+                let nonce = decode_into_array($nonce);
+                let expected = decode($expected);
+
+                // Seeking with the block counter
+                let mut keystream = vec![0; expected.len()];
+                Salsa20::new(&key, &nonce, $offset / 64).encrypt(&mut keystream);
+                assert_eq!(expected, keystream);
+
+                // Running the stream up to the offset in uneven pieces
+                let mut keystream = vec![0; $offset + expected.len()];
+                let mut cipher = Salsa20::new(&key, &nonce, 0);
+                for chunk in keystream.chunks_mut(61) {
+                    cipher.encrypt(chunk);
+                }
+                assert_eq!(expected, &keystream[$offset..]);
+            }
+        };
+    }
+
+    // eSTREAM Salsa20 256 bit key test vectors, set 1 vector 0
+    salsa20_test!(
+        salsa20_set1_vector0_0,
+        "80000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000",
+        "00000000 00000000",
+        0,
+        "E3BE8FDD 8BECA2E3 EA8EF947 5B29A6E7 003951E1 097A5C38 D23B7A5F AD9F6844
+         B22C9755 9E2723C7 CBBD3FE4 FC8D9A07 44652A83 E72A9C46 1876AF4D 7EF1A117"
+    );
+
+    salsa20_test!(
+        salsa20_set1_vector0_192,
+        "80000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000",
+        "00000000 00000000",
+        192,
+        "57BE81F4 7B17D9AE 7C4FF154 29A73E10 ACF250ED 3A90A93C 711308A7 4C6216A9
+         ED84CD12 6DA7F28E 8ABF8BB6 3517E1CA 98E712F4 FB2E1A6A ED9FDC73 291FAA17"
+    );
+
+    // eSTREAM set 6 vector 0
+    salsa20_test!(
+        salsa20_set6_vector0_0,
+        "0053A6F9 4C9FF245 98EB3E91 E4378ADD 3083D629 7CCF2275 C81B6EC1 1467BA0D",
+        "0D74DB42 A91077DE",
+        0,
+        "F5FAD53F 79F9DF58 C4AEA0D0 ED9A9601 F278112C A7180D56 5B420A48 019670EA
+         F24CE493 A86263F6 77B46ACE 1924773D 2BB25571 E1AA8593 758FC382 B1280B71"
+    );
+
+    salsa20_test!(
+        salsa20_set6_vector0_65472,
+        "0053A6F9 4C9FF245 98EB3E91 E4378ADD 3083D629 7CCF2275 C81B6EC1 1467BA0D",
+        "0D74DB42 A91077DE",
+        65472,
+        "B70C5013 9C63332E F6E77AC5 4338A407 9B82BEC9 F9A403DF EA821B83 F7860791
+         650EF1B2 489D0590 B1DE772E EDA4E3BC D60FA7CE 9CD623D9 D2FD5758 B8653E70"
+    );
+
+    salsa20_test!(
+        salsa20_set6_vector0_131008,
+        "0053A6F9 4C9FF245 98EB3E91 E4378ADD 3083D629 7CCF2275 C81B6EC1 1467BA0D",
+        "0D74DB42 A91077DE",
+        131008,
+        "A13FFA12 08F8BF50 900886FA AB40FD10 E8CAA306 E63DF395 36A1564F B760B242
+         A9D6A462 8CDC8787 62834E27 A541DA2A 5E3B3445 989C76F6 11E0FEC6 D91ACACC"
+    );
+
+    // The block counter carries from the low into the high word
+    #[test]
+    fn salsa20_counter_carry() {
+        let key = decode_into_array("80000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000");
+        let nonce = [0; 8];
+
+        let mut keystream = vec![0; 128];
+        Salsa20::new(&key, &nonce, 0xFFFF_FFFF).encrypt(&mut keystream);
+
+        let mut expected = vec![0; 64];
+        Salsa20::new(&key, &nonce, 0x1_0000_0000).encrypt(&mut expected);
+
+        assert_eq!(expected, &keystream[64..]);
+    }
+
+    #[test]
+    fn salsa20_roundtrip() {
+        let key = decode_into_array("0053A6F9 4C9FF245 98EB3E91 E4378ADD 3083D629 7CCF2275 C81B6EC1 1467BA0D");
+        let nonce = decode_into_array("0D74DB42 A91077DE");
+        let plaintext = b"Salsa20 is a stream cipher submitted to eSTREAM by Daniel J. Bernstein.".to_vec();
+
+        let mut data = plaintext.clone();
+        Salsa20::new(&key, &nonce, 0).encrypt(&mut data);
+        assert_ne!(plaintext, data);
+
+        Salsa20::new(&key, &nonce, 0).decrypt(&mut data);
+        assert_eq!(plaintext, data);
+    }
+}
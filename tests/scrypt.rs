@@ -0,0 +1,73 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::errors::kdf::KdfError;
+    use himitsu::kdf::scrypt::scrypt;
+
+    macro_rules! scrypt_test {
+        (
+            $fn_name: ident,
+            $password: literal,
+            $salt: literal,
+            $n: literal,
+            $r: literal,
+            $p: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let expected = decode($expected);
+
+                let dk = scrypt($password, $salt, $n, $r, $p, expected.len()).unwrap();
+                assert_eq!(expected, dk);
+            }
+        };
+    }
+
+    // Test vectors from RFC 7914, section 12
+
+    scrypt_test!(
+        test_scrypt_empty,
+        b"",
+        b"",
+        16,
+        1,
+        1,
+        "77D65762 38657B20 3B19CA42 C18A0497 F16B4844 E3074AE8 DFDFFA3F EDE21442 FCD0069D ED0948F8 326A753A 0FC81F17 E8D3E0FB 2E0D3628 CF35E20C 38D18906"
+    );
+
+    scrypt_test!(
+        test_scrypt_password_nacl,
+        b"password",
+        b"NaCl",
+        1024,
+        8,
+        16,
+        "FDBABE1C 9D347200 7856E719 0D01E9FE 7C6AD7CB C8237830 E7737663 4B373162 2EAF30D9 2E22A388 6FF10927 9D9830DA C727AFB9 4A83EE6D 8360CBDF A2CC0640"
+    );
+
+    scrypt_test!(
+        test_scrypt_pleaseletmein,
+        b"pleaseletmein",
+        b"SodiumChloride",
+        16384,
+        8,
+        1,
+        "7023BDCB 3AFD7348 461C06CD 81FD38EB FDA8FBBA 904F8E3E A9B543F6 545DA1F2 D5432955 613F0FCF 62D49705 242A9AF9 E61E85DC 0D651E40 DFCF017B 45575887"
+    );
+
+    #[test]
+    fn test_scrypt_invalid_parameters() {
+        for (n, r, p) in [(0, 1, 1), (1, 1, 1), (15, 1, 1), (1000, 8, 1), (16, 0, 1), (16, 1, 0)] {
+            match scrypt(b"password", b"salt", n, r, p, 32) {
+                Err(KdfError::InvalidParameter(_)) => {}
+                other => panic!("accepted n = {}, r = {}, p = {}: {:?}", n, r, p, other),
+            }
+        }
+    }
+}
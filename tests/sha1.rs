@@ -0,0 +1,63 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::hash::sha1::Sha1;
+    use himitsu::traits::hash::Hash;
+
+    macro_rules! hash_test {
+        (
+            $fn_name: ident,
+            $hash: ty,
+            $input: expr,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let input: &[u8] = $input;
+                let expected = decode($expected);
+
+                assert_eq!(expected, <$hash>::digest(input));
+            }
+        };
+    }
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+
+    hash_test!(
+        test_sha1_empty,
+        Sha1,
+        b"",
+        "DA39A3EE 5E6B4B0D 3255BFEF 95601890 AFD80709"
+    );
+
+    hash_test!(
+        test_sha1_one_block,
+        Sha1,
+        b"abc",
+        "A9993E36 4706816A BA3E2571 7850C26C 9CD0D89D"
+    );
+
+    hash_test!(
+        test_sha1_two_blocks,
+        Sha1,
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        "84983E44 1C3BD26E BAAE4AA1 F95129E5 E54670F1"
+    );
+
+    #[test]
+    fn test_sha1_million_a() {
+        let expected = decode("34AA973C D4C4DAA4 F61EEB2B DBAD2731 6534016F");
+
+        let mut hash = Sha1::new();
+        for _ in 0..1000 {
+            hash.update(&[b'a'; 1000]);
+        }
+
+        assert_eq!(expected, hash.finalize());
+    }
+}
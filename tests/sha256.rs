@@ -0,0 +1,63 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::hash::sha256::Sha256;
+    use himitsu::traits::hash::Hash;
+
+    macro_rules! hash_test {
+        (
+            $fn_name: ident,
+            $hash: ty,
+            $input: expr,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let input: &[u8] = $input;
+                let expected = decode($expected);
+
+                assert_eq!(expected, <$hash>::digest(input));
+            }
+        };
+    }
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+
+    hash_test!(
+        test_sha256_empty,
+        Sha256,
+        b"",
+        "E3B0C442 98FC1C14 9AFBF4C8 996FB924 27AE41E4 649B934C A495991B 7852B855"
+    );
+
+    hash_test!(
+        test_sha256_one_block,
+        Sha256,
+        b"abc",
+        "BA7816BF 8F01CFEA 414140DE 5DAE2223 B00361A3 96177A9C B410FF61 F20015AD"
+    );
+
+    hash_test!(
+        test_sha256_two_blocks,
+        Sha256,
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        "248D6A61 D20638B8 E5C02693 0C3E6039 A33CE459 64FF2167 F6ECEDD4 19DB06C1"
+    );
+
+    #[test]
+    fn test_sha256_million_a() {
+        let expected = decode("CDC76E5C 9914FB92 81A1C7E2 84D73E67 F1809A48 A497200E 046D39CC C7112CD0");
+
+        let mut hash = Sha256::new();
+        for _ in 0..1000 {
+            hash.update(&[b'a'; 1000]);
+        }
+
+        assert_eq!(expected, hash.finalize());
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::hash::sha3::{Sha3_256, Sha3_512};
+    use himitsu::traits::hash::Hash;
+
+    macro_rules! hash_test {
+        (
+            $fn_name: ident,
+            $hash: ty,
+            $input: expr,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let input: &[u8] = $input;
+                let expected = decode($expected);
+
+                assert_eq!(expected, <$hash>::digest(input));
+            }
+        };
+    }
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+
+    hash_test!(
+        test_sha3_256_empty,
+        Sha3_256,
+        b"",
+        "A7FFC6F8 BF1ED766 51C14756 A061D662 F580FF4D E43B49FA 82D80A4B 80F8434A"
+    );
+
+    hash_test!(
+        test_sha3_256_abc,
+        Sha3_256,
+        b"abc",
+        "3A985DA7 4FE225B2 045C172D 6BD390BD 855F086E 3E9D525B 46BFE245 11431532"
+    );
+
+    // 1600 bits of 0xA3, spanning more than one rate block
+    hash_test!(
+        test_sha3_256_1600_bits,
+        Sha3_256,
+        &[0xa3; 200],
+        "79F38ADE C5C20307 A98EF76E 8324AFBF D46CFD81 B22E3973 C65FA1BD 9DE31787"
+    );
+
+    // The padding fits behind rate - 1 bytes, exactly rate bytes require another block
+    hash_test!(
+        test_sha3_256_rate_minus_one,
+        Sha3_256,
+        &[b'a'; 135],
+        "8094BB53 C44CFB1E 67B7C304 47F9A1C3 3696D246 3ECC1D9C 92538913 392843C9"
+    );
+
+    hash_test!(
+        test_sha3_256_rate,
+        Sha3_256,
+        &[b'a'; 136],
+        "3FC5559F 14DB8E45 3A0A3091 EDBD2BC2 5E11528D 81C66FA5 70A4EFDC C2695EE1"
+    );
+
+    hash_test!(
+        test_sha3_512_empty,
+        Sha3_512,
+        b"",
+        "A69F73CC A23A9AC5 C8B567DC 185A756E 97C98216 4FE25859 E0D1DCC1 475C80A6 15B2123A F1F5F94C 11E3E940 2C3AC558 F500199D 95B6D3E3 01758586 281DCD26"
+    );
+
+    hash_test!(
+        test_sha3_512_abc,
+        Sha3_512,
+        b"abc",
+        "B751850B 1A57168A 5693CD92 4B6B096E 08F62182 7444F70D 884F5D02 40D2712E 10E116E9 192AF3C9 1A7EC576 47E39340 57340B4C F408D5A5 6592F827 4EEC53F0"
+    );
+
+    hash_test!(
+        test_sha3_512_1600_bits,
+        Sha3_512,
+        &[0xa3; 200],
+        "E76DFAD2 2084A8B1 467FCF2F FA58361B EC7628ED F5F3FDC0 E4805DC4 8CAEECA8 1B7C13C3 0ADF52A3 65958473 9A2DF46B E589C51C A1A4A841 6DF6545A 1CE8BA00"
+    );
+
+    hash_test!(
+        test_sha3_512_rate,
+        Sha3_512,
+        &[b'a'; 72],
+        "A8AE722A 78E10CBB C413886C 02EB5B36 9A03F656 0084AFF5 66BD597B B7AD8C1C CD86E812 96852359 BF2FADDB 5153C0A7 44572298 7875E742 87ADAC21 ADEBE952"
+    );
+
+    #[test]
+    fn test_sha3_256_incremental_update() {
+        let mut hash = Sha3_256::new();
+        for chunk in [0xa3u8; 200].chunks(7) {
+            hash.update(chunk);
+        }
+
+        assert_eq!(Sha3_256::digest(&[0xa3; 200]), hash.finalize());
+    }
+}
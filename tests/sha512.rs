@@ -0,0 +1,106 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::hash::sha512::{Sha384, Sha512};
+    use himitsu::traits::hash::Hash;
+
+    macro_rules! hash_test {
+        (
+            $fn_name: ident,
+            $hash: ty,
+            $input: expr,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let input: &[u8] = $input;
+                let expected = decode($expected);
+
+                assert_eq!(expected, <$hash>::digest(input));
+            }
+        };
+    }
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+
+    hash_test!(
+        test_sha512_empty,
+        Sha512,
+        b"",
+        "CF83E135 7EEFB8BD F1542850 D66D8007 D620E405 0B5715DC 83F4A921 D36CE9CE 47D0D13C 5D85F2B0 FF8318D2 877EEC2F 63B931BD 47417A81 A538327A F927DA3E"
+    );
+
+    hash_test!(
+        test_sha512_one_block,
+        Sha512,
+        b"abc",
+        "DDAF35A1 93617ABA CC417349 AE204131 12E6FA4E 89A97EA2 0A9EEEE6 4B55D39A 2192992A 274FC1A8 36BA3C23 A3FEEBBD 454D4423 643CE80E 2A9AC94F A54CA49F"
+    );
+
+    hash_test!(
+        test_sha512_two_blocks,
+        Sha512,
+        b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        "8E959B75 DAE313DA 8CF4F728 14FC143F 8F7779C6 EB9F7FA1 7299AEAD B6889018 501D289E 4900F7E4 331B99DE C4B5433A C7D329EE B6DD2654 5E96E55B 874BE909"
+    );
+
+    // The 128 bit length field fits behind 111 bytes of data, but not behind 112
+    hash_test!(
+        test_sha512_padding_fits,
+        Sha512,
+        &[b'a'; 111],
+        "FA9121C7 B32B9E01 733D034C FC78CBF6 7F926C7E D83E8220 0EF86818 19692176 0B4BEFF4 8404DF81 1B953828 27446167 3C68D04E 297B0EB7 B2B4D60F C6B566A2"
+    );
+
+    hash_test!(
+        test_sha512_padding_spills,
+        Sha512,
+        &[b'a'; 112],
+        "C01D080E FD492776 A1C43BD2 3DD99D0A 2E626D48 1E16782E 75D54C25 03B5DC32 BD05F0F1 BA33E568 B88FD2D9 70929B71 9ECBB152 F58F130A 407C8830 604B70CA"
+    );
+
+    hash_test!(
+        test_sha384_empty,
+        Sha384,
+        b"",
+        "38B060A7 51AC9638 4CD9327E B1B1E36A 21FDB711 14BE0743 4C0CC7BF 63F6E1DA 274EDEBF E76F65FB D51AD2F1 4898B95B"
+    );
+
+    hash_test!(
+        test_sha384_one_block,
+        Sha384,
+        b"abc",
+        "CB00753F 45A35E8B B5A03D69 9AC65007 272C32AB 0EDED163 1A8B605A 43FF5BED 8086072B A1E7CC23 58BAECA1 34C825A7"
+    );
+
+    hash_test!(
+        test_sha384_two_blocks,
+        Sha384,
+        b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        "09330C33 F71147E8 3D192FC7 82CD1B47 53111B17 3B3B05D2 2FA08086 E3B0F712 FCC7C71A 557E2DB9 66C3E9FA 91746039"
+    );
+
+    hash_test!(
+        test_sha384_padding_spills,
+        Sha384,
+        &[b'a'; 112],
+        "187D4E07 CB306103 C69967BF 544D0DFB E9042577 599C73C3 30ABC0CB 64C61236 D5ED565E E19119D8 C31779A3 8F791FCD"
+    );
+
+    #[test]
+    fn test_sha512_million_a() {
+        let expected = decode("E718483D 0CE76964 4E2E42C7 BC15B463 8E1F98B1 3B204428 5632A803 AFA973EB DE0FF244 877EA60A 4CB0432C E577C31B EB009C5C 2C49AA2E 4EADB217 AD8CC09B");
+
+        let mut hash = Sha512::new();
+        for _ in 0..1000 {
+            hash.update(&[b'a'; 1000]);
+        }
+
+        assert_eq!(expected, hash.finalize());
+    }
+}
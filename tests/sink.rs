@@ -0,0 +1,50 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::cipher::block::{
+        buffered::{BufferedCipherDecryptionProvider, BufferedCipherEncryptionProvider},
+        cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+        primitive::aes::Aes,
+    };
+    use himitsu::prelude::*;
+
+    // Example values from [NIST](https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+    const KEY: &str = "2B7E1516 28AED2A6 ABF71588 09CF4F3C";
+    const IV: &str = "00010203 04050607 08090A0B 0C0D0E0F";
+    const PLAINTEXT: &str = "6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51";
+    const CIPHERTEXT: &str = "7649ABAC 8119B246 CEE98E9B 12E9197D 5086CB9B 507219EE 95DB113A 917678B2";
+
+    // Feeding the providers through ByteSink in uneven pieces matches io::Write
+    #[test]
+    fn test_sink_cbc_roundtrip() {
+        let aes = Aes::new(&decode(KEY)).unwrap();
+        let (plaintext, ciphertext) = (decode(PLAINTEXT), decode(CIPHERTEXT));
+
+        let mut enc = aes.clone().with_cbc_encryption(decode_into_array(IV)).buffered();
+        for chunk in plaintext.chunks(5) {
+            enc.write_bytes(chunk).unwrap();
+        }
+        assert_eq!(ciphertext, enc.finalize::<Vec<u8>>());
+
+        let mut dec = aes.with_cbc_decryption(decode_into_array(IV)).buffered();
+        for chunk in ciphertext.chunks(7) {
+            dec.write_bytes(chunk).unwrap();
+        }
+        assert_eq!(plaintext, dec.finalize::<Vec<u8>>().unwrap());
+    }
+
+    #[test]
+    fn test_sink_vec() {
+        let mut sink = Vec::new();
+        sink.write_bytes(b"abc").unwrap();
+        sink.write_bytes(b"").unwrap();
+        sink.write_bytes(b"de").unwrap();
+
+        assert_eq!(b"abcde".to_vec(), sink);
+    }
+}
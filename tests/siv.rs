@@ -0,0 +1,132 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::cipher::aead::bind_length;
+    use himitsu::cipher::aead::siv::AesSiv;
+    use himitsu::errors::blockcipher::BlockCipherError;
+    use himitsu::prelude::*;
+
+    // RFC 5297, A.1 Deterministic Authenticated Encryption Example
+    const DET_KEY: &str = "FFFEFDFC FBFAF9F8 F7F6F5F4 F3F2F1F0 F0F1F2F3 F4F5F6F7 F8F9FAFB FCFDFEFF";
+    const DET_AD: &str = "10111213 14151617 18191A1B 1C1D1E1F 20212223 24252627";
+    const DET_PLAINTEXT: &str = "11223344 55667788 99AABBCC DDEE";
+    const DET_SIV: &str = "85632D07 C6E8F37F 950ACD32 0A2ECC93";
+    const DET_CIPHERTEXT: &str = "40C02B96 90C4DC04 DAEF7F6A FE5C";
+
+    // RFC 5297, A.2 Nonce-Based Authenticated Encryption Example
+    const NONCE_KEY: &str = "7F7E7D7C 7B7A7978 77767574 73727170 40414243 44454647 48494A4B 4C4D4E4F";
+    const NONCE_AD1: &str = "00112233 44556677 8899AABB CCDDEEFF DEADDADA DEADDADA FFEEDDCC BBAA9988 77665544 33221100";
+    const NONCE_AD2: &str = "10203040 50607080 90A0";
+    const NONCE: &str = "09F91102 9D74E35B D84156C5 635688C0";
+    const NONCE_PLAINTEXT: &str = "74686973 20697320 736F6D65 20706C61 696E7465 78742074 6F20656E 63727970 74207573 696E6720 5349562D 414553";
+    const NONCE_SIV: &str = "7BDB6E3B 432667EB 06F4D14B FF2FBD0F";
+    const NONCE_CIPHERTEXT: &str = "CB900F2F DDBE4043 26601965 C889BF17 DBA77CEB 094FA663 B7A3F748 BA8AF829 EA64AD54 4A272E9C 485B62A3 FD5C0D";
+
+    #[test]
+    fn test_siv_rfc5297_deterministic() {
+        let siv = AesSiv::new(&decode(DET_KEY)).unwrap();
+        let ad = decode(DET_AD);
+
+        let (ciphertext, tag) = siv.encrypt_with_components(&[&ad], &decode(DET_PLAINTEXT));
+        assert_eq!(decode(DET_SIV), tag);
+        assert_eq!(decode(DET_CIPHERTEXT), ciphertext);
+
+        let plaintext = siv.decrypt_with_components(&[&ad], &ciphertext, &tag).unwrap();
+        assert_eq!(decode(DET_PLAINTEXT), plaintext);
+    }
+
+    #[test]
+    fn test_siv_rfc5297_nonce_based() {
+        let siv = AesSiv::new(&decode(NONCE_KEY)).unwrap();
+        let (ad1, ad2, nonce) = (decode(NONCE_AD1), decode(NONCE_AD2), decode(NONCE));
+
+        let (ciphertext, tag) = siv.encrypt_with_components(&[&ad1, &ad2, &nonce], &decode(NONCE_PLAINTEXT));
+        assert_eq!(decode(NONCE_SIV), tag);
+        assert_eq!(decode(NONCE_CIPHERTEXT), ciphertext);
+
+        let plaintext = siv.decrypt_with_components(&[&ad1, &ad2, &nonce], &ciphertext, &tag).unwrap();
+        assert_eq!(decode(NONCE_PLAINTEXT), plaintext);
+    }
+
+    #[test]
+    fn test_siv_tampered() {
+        let siv = AesSiv::new(&decode(DET_KEY)).unwrap();
+        let ad = decode(DET_AD);
+        let tag = decode_into_array(DET_SIV);
+
+        let mut ciphertext = decode(DET_CIPHERTEXT);
+        ciphertext[3] ^= 1;
+        assert!(matches!(
+            siv.decrypt_with_components(&[&ad], &ciphertext, &tag),
+            Err(BlockCipherError::AuthenticationFailed)
+        ));
+
+        assert!(matches!(
+            siv.decrypt_with_components(&[], &decode(DET_CIPHERTEXT), &tag),
+            Err(BlockCipherError::AuthenticationFailed)
+        ));
+    }
+
+    // Reusing a nonce with the same message gives the same output, different messages stay independent
+    #[test]
+    fn test_siv_aead_nonce_reuse() {
+        let siv = AesSiv::new(&decode(NONCE_KEY)).unwrap();
+        let nonce = decode_into_array(NONCE);
+        let aad = decode(NONCE_AD1);
+
+        let (first, first_tag) = siv.encrypt(&nonce, &aad, b"attack at dawn");
+        let (second, second_tag) = siv.encrypt(&nonce, &aad, b"attack at dawn");
+        let (other, other_tag) = siv.encrypt(&nonce, &aad, b"attack at dusk");
+
+        assert_eq!((&first, first_tag), (&second, second_tag));
+        assert_ne!(first_tag, other_tag);
+        assert_ne!(first[..10], other[..10]);
+
+        assert_eq!(b"attack at dusk".to_vec(), siv.decrypt(&nonce, &aad, &other, &other_tag).unwrap());
+    }
+
+    // Verified with the AESSIV implementation of the python cryptography package
+    #[test]
+    fn test_siv_empty_plaintext() {
+        let siv = AesSiv::new(&decode(NONCE_KEY)).unwrap();
+        let (ciphertext, tag) = siv.encrypt_with_components(&[&[]], &[]);
+
+        assert!(ciphertext.is_empty());
+        assert_eq!(decode("6810F831 1A59EBFD AF8C3EBE D5065663"), tag);
+    }
+
+    #[test]
+    fn test_siv_invalid_key_length() {
+        for len in [0, 16, 24, 33, 40, 72] {
+            match AesSiv::new(&vec![0; len]) {
+                Err(BlockCipherError::InvalidKeyLength { got, min: 32, max: 64 }) => assert_eq!(len, got),
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("accepted a {} byte key", len),
+            }
+        }
+
+        for len in [32, 48, 64] {
+            assert!(AesSiv::new(&vec![0; len]).is_ok());
+        }
+    }
+
+    // Only the associated data of the Aead interface is bound, the components stay as given
+    #[test]
+    fn test_siv_aead_bind_length() {
+        let siv = AesSiv::new(&decode(NONCE_KEY)).unwrap().with_bind_length(true);
+        let nonce = decode_into_array(NONCE);
+        let aad = decode(NONCE_AD1);
+        let bound = bind_length(&aad, 14);
+
+        let (ciphertext, tag) = siv.encrypt(&nonce, &aad, b"attack at dawn");
+        assert_eq!((ciphertext.clone(), tag), siv.encrypt_with_components(&[&bound, &nonce], b"attack at dawn"));
+
+        assert_eq!(b"attack at dawn".to_vec(), siv.decrypt(&nonce, &aad, &ciphertext, &tag).unwrap());
+    }
+}
+
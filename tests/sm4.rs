@@ -0,0 +1,85 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::{
+        cipher::block::{
+            buffered::{BufferedCipherDecryptionProvider, BufferedCipherEncryptionProvider},
+            cbc::{CbcDecryptionProvider, CbcEncryptionProvider},
+            primitive::sm4::*,
+        },
+        errors::blockcipher::BlockCipherError,
+        traits::cipher::primitive::{BlockCipherPrimitiveDecryption, BlockCipherPrimitiveEncryption},
+    };
+    use himitsu::traits::cipher::sink::ByteSink;
+
+    // Examples from GM/T 0002-2012, Appendix A
+
+    #[test]
+    fn test_sm4_enc_dec() {
+        let key = decode("01234567 89abcdef fedcba98 76543210");
+        let plaintext: Sm4Block = decode_into_array("01234567 89abcdef fedcba98 76543210");
+        let expected: Sm4Block = decode_into_array("681edf34 d206965e 86b3e94f 536e4246");
+
+        let sm4 = Sm4::new(&key).unwrap();
+
+        let mut buf = plaintext;
+        sm4.encrypt(&mut buf);
+        assert_eq!(expected, buf);
+
+        sm4.decrypt(&mut buf);
+        assert_eq!(plaintext, buf);
+    }
+
+    #[test]
+    fn test_sm4_enc_1000000_times() {
+        let key = decode("01234567 89abcdef fedcba98 76543210");
+        let expected: Sm4Block = decode_into_array("595298c7 c6fd271f 0402f804 c33d3f66");
+
+        let sm4 = Sm4::new(&key).unwrap();
+
+        let mut buf: Sm4Block = decode_into_array("01234567 89abcdef fedcba98 76543210");
+        for _ in 0..1_000_000 {
+            sm4.encrypt(&mut buf);
+        }
+
+        assert_eq!(expected, buf);
+    }
+
+    #[test]
+    fn test_sm4_invalid_key_length() {
+        for len in [0, 15, 17, 32] {
+            match Sm4::new(&vec![0; len]) {
+                Err(BlockCipherError::InvalidKeyLength { got, min, max }) => {
+                    assert_eq!(len, got);
+                    assert_eq!(SM4_KEYLEN, min);
+                    assert_eq!(SM4_KEYLEN, max);
+                }
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("accepted a {} byte key", len),
+            }
+        }
+    }
+
+    #[test]
+    fn test_sm4_cbc_round_trip() {
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let iv = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F");
+        let plaintext = decode("6BC1BEE2 2E409F96 E93D7E11 7393172A AE2D8A57 1E03AC9C 9EB76FAC 45AF8E51 30C81C46 A35CE411 E5FBC119 1A0A52EF F69F2445 DF4F9B17 AD2B417B E66C3710");
+        let expected = decode("AC529AF9 89A62FCE 9CDDC5FF B84125CA B168DD69 DB3C0EEA 1AB16DE6 AEA43C59 2C15567B FF8F7074 86C202C7 BE59101F 74A629B3 50CD7E11 BE99998A F5206D6C");
+
+        let mut cipher = Sm4::new(&key).unwrap().with_cbc_encryption(iv).buffered();
+        cipher.write_bytes(&plaintext).unwrap();
+        let ciphertext: Vec<u8> = cipher.finalize();
+        assert_eq!(expected, ciphertext);
+
+        let mut cipher = Sm4::new(&key).unwrap().with_cbc_decryption(iv).buffered();
+        cipher.write_bytes(&ciphertext).unwrap();
+        let output: Vec<u8> = cipher.finalize().unwrap();
+        assert_eq!(plaintext, output);
+    }
+}
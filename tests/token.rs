@@ -0,0 +1,39 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+
+    use himitsu::encode::token;
+
+    // Encode and decode tokens of every padding length
+    #[test]
+    fn round_trip() {
+        let data: Vec<u8> = (0u8..=255).rev().collect();
+
+        for len in 0..20 {
+            let encoded = token::encode(&data[..len]);
+            let decoded = token::decode(&encoded).unwrap();
+
+            assert_eq!(&data[..len], decoded.as_slice());
+        }
+    }
+
+    // Tokens must neither contain padding nor characters that need url escaping
+    #[test]
+    fn no_padding() {
+        let data = [0xFBu8, 0xFF, 0xBF, 0xFB, 0xFF];
+
+        for len in 1..=data.len() {
+            let encoded = token::encode(&data[..len]);
+
+            assert!(!encoded.contains('='));
+            assert!(!encoded.contains('+') && !encoded.contains('/'));
+        }
+    }
+
+    // A single dangling character can never be a valid token
+    #[test]
+    fn invalid_length() {
+        assert!(token::decode("QUJDR").is_err());
+    }
+}
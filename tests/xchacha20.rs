@@ -0,0 +1,62 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::{decode, decode_into_array};
+    use himitsu::cipher::stream::{chacha20::hchacha20, xchacha20::XChaCha20};
+    use himitsu::prelude::*;
+
+    const DHOLE: &[u8] = b"The dhole (pronounced \"dole\") is also known as the Asiatic wild dog, red dog, and whistling dog. It is about the size of a German shepherd but looks more like a long-legged fox. This highly elusive and skilled jumper is classified with wolves, coyotes, jackals, and foxes in the taxonomic family Canidae.";
+
+    const KEY: &str = "80818283 84858687 88898A8B 8C8D8E8F 90919293 94959697 98999A9B 9C9D9E9F";
+    const NONCE: &str = "40414243 44454647 48494A4B 4C4D4E4F 50515253 54555658";
+
+    const CIPHERTEXT: &str = "7D0A2E6B 7F7C65A2 36542630 294E063B 7AB9B555 A5D5149A A21E4AE1 E4FBCE87
+        ECC8E08A 8B5E350A BE622B2F FA617B20 2CFAD720 32A3037E 76FFDCDC 4376EE05
+        3A190D7E 46CA1DE0 41448503 81B9CB29 F0519153 86B8A710 B8AC4D02 7B8B050F
+        7CBA5854 E028D564 E453B8A9 68824173 FC16488B 8970CAC8 28F11AE5 3CABD201
+        12F87107 DF24EE61 83D2274F E4C8B148 5534EF2C 5FBC1EC2 4BFC3663 EFAA08BC
+        047D29D2 5043532D B8391A8A 3D776BF4 372A6955 827CCB0C DD4AF403 A7CE4C63
+        D595C75A 43E045F0 CCE1F29C 8B93BD65 AFC59749 22F214A4 0B7C402C DB91AE73
+        C0B63615 CDAD0480 680F1651 5A7ACE9D 39236464 328A3774 3FFC28F4 DDB324F4
+        D0F5BBDC 270C65B1 749A6EFF F1FBAA09 536175CC D29FB9E6 057B3073 20D31683
+        8A9C71F7 0B5B5907 A66F7EA4 9AADC409";
+
+    // draft-irtf-cfrg-xchacha-03, 2.2.1
+    #[test]
+    fn test_hchacha20() {
+        let key = decode_into_array("00010203 04050607 08090A0B 0C0D0E0F 10111213 14151617 18191A1B 1C1D1E1F");
+        let nonce = decode_into_array("00000009 0000004A 00000000 31415927");
+        let expected = decode("82413B42 27B27BFE D30E4250 8A877D73 A0F9E4D5 8A74A853 C12EC413 26D3ECDC");
+
+        assert_eq!(expected, hchacha20(&key, &nonce));
+    }
+
+    // draft-irtf-cfrg-xchacha-03, A.3.2
+    #[test]
+    fn test_xchacha20_enc() {
+        let (key, nonce) = (decode_into_array(KEY), decode_into_array(NONCE));
+
+        let mut data = DHOLE.to_vec();
+        XChaCha20::new(&key, &nonce, 1).encrypt(&mut data);
+
+        assert_eq!(decode(CIPHERTEXT), data);
+    }
+
+    #[test]
+    fn test_xchacha20_dec_chunked() {
+        let (key, nonce) = (decode_into_array(KEY), decode_into_array(NONCE));
+
+        let mut data = decode(CIPHERTEXT);
+        let mut cipher = XChaCha20::new(&key, &nonce, 1);
+
+        for chunk in data.chunks_mut(7) {
+            cipher.decrypt(chunk);
+        }
+
+        assert_eq!(DHOLE, &data[..]);
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+
+    use himitsu::mem::xor_slices;
+
+    fn xor_naive(dst: &mut [u8], src: &[u8]) -> usize {
+        let len = std::cmp::min(dst.len(), src.len());
+        for i in 0..len {
+            dst[i] ^= src[i];
+        }
+
+        len
+    }
+
+    fn pattern(len: usize, seed: u8) -> Vec<u8> {
+        (0..len).map(|i| (i as u8).wrapping_mul(31) ^ seed).collect()
+    }
+
+    // Sub-word, word and SSE2 sized inputs, starting at unaligned offsets
+    #[test]
+    fn test_xor_slices_matches_naive() {
+        for len in 0..=70 {
+            for offset in 0..4 {
+                let src = pattern(len + offset, 0x5A);
+                let mut dst = pattern(len + offset, 0xC3);
+                let mut exp = dst.clone();
+
+                assert_eq!(xor_naive(&mut exp[offset..], &src[offset..]), len);
+                assert_eq!(xor_slices(&mut dst[offset..], &src[offset..]), len);
+                assert_eq!(exp, dst);
+            }
+        }
+    }
+
+    // Only the common length is xored, the rest of dst is untouched
+    #[test]
+    fn test_xor_slices_unequal_lengths() {
+        for (dst_len, src_len) in [(0, 5), (5, 0), (7, 19), (19, 7), (33, 40), (40, 33)] {
+            let src = pattern(src_len, 0x11);
+            let mut dst = pattern(dst_len, 0xEE);
+            let mut exp = dst.clone();
+
+            assert_eq!(xor_naive(&mut exp, &src), std::cmp::min(dst_len, src_len));
+            assert_eq!(xor_slices(&mut dst, &src), std::cmp::min(dst_len, src_len));
+            assert_eq!(exp, dst);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+
+    use super::common::decode;
+    use himitsu::cipher::block::{
+        primitive::aes,
+        xts::{XtsDecryptionProvider, XtsEncryptionProvider},
+    };
+
+    macro_rules! xts_test {
+        (
+            $fn_name: ident,
+            $cipher: ty,
+            $key1: literal,
+            $key2: literal,
+            $sector: expr,
+            $input: literal,
+            $expected: literal
+        ) => {
+            #[test]
+            fn $fn_name() {
+                let input = decode($input);
+                let (key1, key2) = (decode($key1), decode($key2));
+                let expected = decode($expected);
+
+                let cipher = <$cipher>::new(&key1).unwrap().with_xts_encryption(<$cipher>::new(&key2).unwrap());
+                let mut output = input.clone();
+                cipher.encrypt_sector($sector, &mut output).unwrap();
+
+                assert_eq!(expected, output);
+
+                let cipher = <$cipher>::new(&key1).unwrap().with_xts_decryption(<$cipher>::new(&key2).unwrap());
+                cipher.decrypt_sector($sector, &mut output).unwrap();
+
+                assert_eq!(input, output);
+            }
+        };
+    }
+
+    // Test vectors from IEEE 1619-2007, Annex B
+
+    xts_test!(
+        test_xts_aes128_vector_1,
+        aes::Aes,
+        "00000000 00000000 00000000 00000000",
+        "00000000 00000000 00000000 00000000",
+        0,
+        "00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000",
+        "917CF69E BD68B2EC 9B9FE9A3 EADDA692 CD43D2F5 9598ED85 8C02C265 2FBF922E"
+    );
+
+    xts_test!(
+        test_xts_aes128_vector_2,
+        aes::Aes,
+        "11111111 11111111 11111111 11111111",
+        "22222222 22222222 22222222 22222222",
+        0x3333333333,
+        "44444444 44444444 44444444 44444444 44444444 44444444 44444444 44444444",
+        "C454185E 6A16936E 39334038 ACEF838B FB186FFF 7480ADC4 289382EC D6D394F0"
+    );
+
+    xts_test!(
+        test_xts_aes128_vector_3,
+        aes::Aes,
+        "FFFEFDFC FBFAF9F8 F7F6F5F4 F3F2F1F0",
+        "22222222 22222222 22222222 22222222",
+        0x3333333333,
+        "44444444 44444444 44444444 44444444 44444444 44444444 44444444 44444444",
+        "AF85336B 597AFC1A 900B2EB2 1EC949D2 92DF4C04 7E0B2153 2186A597 1A227A89"
+    );
+
+    // Vectors 15 - 18 cover ciphertext stealing for data units that are not block aligned
+
+    xts_test!(
+        test_xts_aes128_vector_15,
+        aes::Aes,
+        "FFFEFDFC FBFAF9F8 F7F6F5F4 F3F2F1F0",
+        "BFBEBDBC BBBAB9B8 B7B6B5B4 B3B2B1B0",
+        0x123456789A,
+        "00010203 04050607 08090A0B 0C0D0E0F 10",
+        "6C1625DB 4671522D 3D759960 1DE7CA09 ED"
+    );
+
+    xts_test!(
+        test_xts_aes128_vector_16,
+        aes::Aes,
+        "FFFEFDFC FBFAF9F8 F7F6F5F4 F3F2F1F0",
+        "BFBEBDBC BBBAB9B8 B7B6B5B4 B3B2B1B0",
+        0x123456789A,
+        "00010203 04050607 08090A0B 0C0D0E0F 1011",
+        "D069444B 7A7E0CAB 09E24447 D24DEB1F EDBF"
+    );
+
+    xts_test!(
+        test_xts_aes128_vector_17,
+        aes::Aes,
+        "FFFEFDFC FBFAF9F8 F7F6F5F4 F3F2F1F0",
+        "BFBEBDBC BBBAB9B8 B7B6B5B4 B3B2B1B0",
+        0x123456789A,
+        "00010203 04050607 08090A0B 0C0D0E0F 101112",
+        "E5DF1351 C0544BA1 350B3363 CD8EF4BE EDBF9D"
+    );
+
+    xts_test!(
+        test_xts_aes128_vector_18,
+        aes::Aes,
+        "FFFEFDFC FBFAF9F8 F7F6F5F4 F3F2F1F0",
+        "BFBEBDBC BBBAB9B8 B7B6B5B4 B3B2B1B0",
+        0x123456789A,
+        "00010203 04050607 08090A0B 0C0D0E0F 10111213",
+        "9D84C813 F719AA2C 7BE3F661 71C7C5C2 EDBF9DAC"
+    );
+
+    #[test]
+    fn test_xts_data_unit_too_short() {
+        use himitsu::errors::blockcipher::BlockCipherError;
+
+        let key = decode("2B7E1516 28AED2A6 ABF71588 09CF4F3C");
+        let cipher = aes::Aes::new(&key).unwrap().with_xts_encryption(aes::Aes::new(&key).unwrap());
+        let mut data = [0u8; 15];
+
+        match cipher.encrypt_sector(0, &mut data) {
+            Err(BlockCipherError::IncompleteBlock(1)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}